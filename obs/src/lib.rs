@@ -1,6 +1,25 @@
+//! An in-process fake of the parts of libobs that the plugin uses. It allows
+//! the plugin to link on Linux and implements enough of the behavior to load
+//! the plugin in tests, create sources, press hotkeys and render frames.
+//!
+//! Besides the actual OBS functions, there's a few functions prefixed with
+//! `fake_obs_` that allow tests to drive and inspect the fake.
+
+#![allow(clippy::missing_safety_doc)]
+
 use std::{
-    ffi::c_void,
+    cell::Cell,
+    collections::BTreeMap,
+    env,
+    ffi::{CStr, CString, c_void},
+    fmt::Write,
     os::raw::{c_char, c_int, c_longlong},
+    ptr,
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Instant,
 };
 
 #[path = "../../src/ffi_types.rs"]
@@ -8,368 +27,1699 @@ mod ffi_types;
 
 use ffi_types::*;
 
+struct SendPtr<T>(T);
+
+unsafe impl<T> Send for SendPtr<T> {}
+
+static SOURCE_TYPES: Mutex<Vec<SendPtr<obs_source_info>>> = Mutex::new(Vec::new());
+// The hotkeys and drawn textures are kept for each source, so tests that run
+// in parallel don't see each other's.
+static HOTKEYS: Mutex<Vec<SendPtr<Hotkey>>> = Mutex::new(Vec::new());
+static LAST_DRAWN_TEXTURES: Mutex<Vec<SendPtr<(*mut obs_source_t, *mut gs_texture_t)>>> =
+    Mutex::new(Vec::new());
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// Effects, techniques and the profile config are never actually used for
+// anything, so they all point to the same dummy.
+static DUMMY: u8 = 0;
+
+fn dummy<T>() -> *mut T {
+    (&raw const DUMMY).cast_mut().cast()
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> &'a str {
+    if s.is_null() {
+        return "";
+    }
+    unsafe { CStr::from_ptr(s).to_str().unwrap_or_default() }
+}
+
+// Data
+
+struct Data {
+    refs: AtomicUsize,
+    inner: Mutex<DataInner>,
+}
+
+#[derive(Default)]
+struct DataInner {
+    values: BTreeMap<String, Item>,
+    defaults: BTreeMap<String, Item>,
+    json: CString,
+}
+
+enum Item {
+    Bool(bool),
+    Int(i64),
+    String(CString),
+    Obj(*mut obs_data_t),
+    Array(*mut c_void),
+}
+
+impl Item {
+    unsafe fn duplicate(&self) -> Self {
+        unsafe {
+            match self {
+                Item::Bool(v) => Item::Bool(*v),
+                Item::Int(v) => Item::Int(*v),
+                Item::String(v) => Item::String(v.clone()),
+                Item::Obj(v) => {
+                    obs_data_addref(*v);
+                    Item::Obj(*v)
+                }
+                Item::Array(v) => {
+                    obs_data_array_addref(*v);
+                    Item::Array(*v)
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Item {
+    fn drop(&mut self) {
+        unsafe {
+            match self {
+                Item::Obj(v) => obs_data_release(*v),
+                Item::Array(v) => obs_data_array_release(*v),
+                _ => {}
+            }
+        }
+    }
+}
+
+unsafe fn data<'a>(data: *mut obs_data_t) -> &'a Data {
+    unsafe { &*data.cast::<Data>() }
+}
+
+unsafe fn get_item<R>(
+    data_ptr: *mut obs_data_t,
+    name: *const c_char,
+    f: impl FnOnce(Option<&Item>) -> R,
+) -> R {
+    unsafe {
+        let inner = data(data_ptr).inner.lock().unwrap();
+        let name = to_str(name);
+        f(inner.values.get(name).or_else(|| inner.defaults.get(name)))
+    }
+}
+
+unsafe fn set_item(data_ptr: *mut obs_data_t, name: *const c_char, item: Item, default: bool) {
+    unsafe {
+        let mut inner = data(data_ptr).inner.lock().unwrap();
+        let map = if default {
+            &mut inner.defaults
+        } else {
+            &mut inner.values
+        };
+        map.insert(to_str(name).to_owned(), item);
+    }
+}
+
+fn write_json_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+unsafe fn write_json(values: &BTreeMap<String, Item>, out: &mut String) {
+    unsafe {
+        out.push('{');
+        for (i, (key, value)) in values.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            write_json_string(key, out);
+            out.push(':');
+            match value {
+                Item::Bool(v) => {
+                    let _ = write!(out, "{v}");
+                }
+                Item::Int(v) => {
+                    let _ = write!(out, "{v}");
+                }
+                Item::String(v) => write_json_string(&v.to_string_lossy(), out),
+                Item::Obj(v) => write_json(&data(*v).inner.lock().unwrap().values, out),
+                Item::Array(v) => {
+                    out.push('[');
+                    for (i, item) in array(*v).items.lock().unwrap().iter().enumerate() {
+                        if i != 0 {
+                            out.push(',');
+                        }
+                        write_json(&data(item.0).inner.lock().unwrap().values, out);
+                    }
+                    out.push(']');
+                }
+            }
+        }
+        out.push('}');
+    }
+}
+
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_register_source_s(_info: *const obs_source_info, _size: size_t) {
-    panic!()
+pub extern "C" fn obs_data_create() -> *mut obs_data_t {
+    Box::into_raw(Box::new(Data {
+        refs: AtomicUsize::new(1),
+        inner: Mutex::new(DataInner::default()),
+    }))
+    .cast()
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_texture_create(
-    _width: u32,
-    _height: u32,
-    _color_format: gs_color_format,
-    _levels: u32,
-    _data: *mut *const u8,
-    _flags: u32,
-) -> *mut gs_texture_t {
-    panic!()
+pub unsafe extern "C" fn obs_data_addref(data_ptr: *mut obs_data_t) {
+    if !data_ptr.is_null() {
+        unsafe { data(data_ptr).refs.fetch_add(1, Ordering::Relaxed) };
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_enter_graphics() {
-    panic!()
+pub unsafe extern "C" fn obs_data_release(data_ptr: *mut obs_data_t) {
+    unsafe {
+        if !data_ptr.is_null() && data(data_ptr).refs.fetch_sub(1, Ordering::AcqRel) == 1 {
+            drop(Box::from_raw(data_ptr.cast::<Data>()));
+        }
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_leave_graphics() {
-    panic!()
+pub unsafe extern "C" fn obs_data_apply(target: *mut obs_data_t, apply_data: *mut obs_data_t) {
+    unsafe {
+        if target.is_null() || apply_data.is_null() || target == apply_data {
+            return;
+        }
+        let values = data(apply_data)
+            .inner
+            .lock()
+            .unwrap()
+            .values
+            .iter()
+            .map(|(k, v)| (k.clone(), v.duplicate()))
+            .collect::<Vec<_>>();
+        data(target).inner.lock().unwrap().values.extend(values);
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_texture_set_image(
-    _tex: *mut gs_texture_t,
-    _data: *const u8,
-    _linesize: u32,
-    _invert: bool,
+pub unsafe extern "C" fn obs_data_get_string(
+    data: *mut obs_data_t,
+    name: *const c_char,
+) -> *const c_char {
+    unsafe {
+        get_item(data, name, |item| match item {
+            Some(Item::String(v)) => v.as_ptr(),
+            _ => c"".as_ptr(),
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_get_bool(data: *mut obs_data_t, name: *const c_char) -> bool {
+    unsafe { get_item(data, name, |item| matches!(item, Some(Item::Bool(true)))) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_get_int(
+    data: *mut obs_data_t,
+    name: *const c_char,
+) -> c_longlong {
+    unsafe {
+        get_item(data, name, |item| match item {
+            Some(Item::Int(v)) => *v,
+            _ => 0,
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_get_obj(
+    data: *mut obs_data_t,
+    name: *const c_char,
+) -> *mut obs_data_t {
+    unsafe {
+        get_item(data, name, |item| match item {
+            Some(Item::Obj(v)) => {
+                obs_data_addref(*v);
+                *v
+            }
+            _ => ptr::null_mut(),
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_get_array(
+    data: *mut obs_data_t,
+    name: *const c_char,
+) -> *mut c_void {
+    unsafe {
+        get_item(data, name, |item| match item {
+            Some(Item::Array(v)) => {
+                obs_data_array_addref(*v);
+                *v
+            }
+            _ => ptr::null_mut(),
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_get_json(data_ptr: *mut obs_data_t) -> *const c_char {
+    unsafe {
+        let mut json = String::new();
+        let mut inner = data(data_ptr).inner.lock().unwrap();
+        write_json(&inner.values, &mut json);
+        inner.json = CString::new(json).unwrap_or_default();
+        inner.json.as_ptr()
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_bool(data: *mut obs_data_t, name: *const c_char, val: bool) {
+    unsafe { set_item(data, name, Item::Bool(val), false) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_int(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: c_longlong,
 ) {
-    panic!()
+    unsafe { set_item(data, name, Item::Int(val), false) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_hotkey_register_source(
-    _source: *mut obs_source_t,
-    _name: *const c_char,
-    _description: *const c_char,
-    _func: obs_hotkey_func,
-    _data: *mut c_void,
-) -> obs_hotkey_id {
-    panic!()
+pub unsafe extern "C" fn obs_data_set_string(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: *const c_char,
+) {
+    unsafe {
+        let val = CStr::from_ptr(val).to_owned();
+        set_item(data, name, Item::String(val), false)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_obj(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    obj: *mut obs_data_t,
+) {
+    unsafe {
+        obs_data_addref(obj);
+        set_item(data, name, Item::Obj(obj), false)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_array(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    array: *mut c_void,
+) {
+    unsafe {
+        obs_data_array_addref(array);
+        set_item(data, name, Item::Array(array), false)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_default_bool(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: bool,
+) {
+    unsafe { set_item(data, name, Item::Bool(val), true) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_default_int(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: c_longlong,
+) {
+    unsafe { set_item(data, name, Item::Int(val), true) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_set_default_string(
+    data: *mut obs_data_t,
+    name: *const c_char,
+    val: *const c_char,
+) {
+    unsafe {
+        let val = CStr::from_ptr(val).to_owned();
+        set_item(data, name, Item::String(val), true)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_erase(data_ptr: *mut obs_data_t, name: *const c_char) {
+    unsafe {
+        let mut inner = data(data_ptr).inner.lock().unwrap();
+        let name = to_str(name);
+        inner.values.remove(name);
+        inner.defaults.remove(name);
+    }
+}
+
+// Arrays
+
+struct Array {
+    refs: AtomicUsize,
+    items: Mutex<Vec<SendPtr<*mut obs_data_t>>>,
+}
+
+unsafe fn array<'a>(array: *mut c_void) -> &'a Array {
+    unsafe { &*array.cast::<Array>() }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_data_array_create() -> *mut c_void {
+    Box::into_raw(Box::new(Array {
+        refs: AtomicUsize::new(1),
+        items: Mutex::new(Vec::new()),
+    }))
+    .cast()
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_array_addref(array_ptr: *mut c_void) {
+    if !array_ptr.is_null() {
+        unsafe { array(array_ptr).refs.fetch_add(1, Ordering::Relaxed) };
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_array_release(array_ptr: *mut c_void) {
+    unsafe {
+        if !array_ptr.is_null() && array(array_ptr).refs.fetch_sub(1, Ordering::AcqRel) == 1 {
+            let array = Box::from_raw(array_ptr.cast::<Array>());
+            for item in array.items.into_inner().unwrap() {
+                obs_data_release(item.0);
+            }
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_array_count(array_ptr: *mut c_void) -> size_t {
+    if array_ptr.is_null() {
+        return 0;
+    }
+    unsafe { array(array_ptr).items.lock().unwrap().len() }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_array_item(
+    array_ptr: *mut c_void,
+    idx: size_t,
+) -> *mut obs_data_t {
+    if array_ptr.is_null() {
+        return ptr::null_mut();
+    }
+    unsafe {
+        match array(array_ptr).items.lock().unwrap().get(idx) {
+            Some(item) => {
+                obs_data_addref(item.0);
+                item.0
+            }
+            None => ptr::null_mut(),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_data_array_push_back(
+    array_ptr: *mut c_void,
+    obj: *mut obs_data_t,
+) -> size_t {
+    unsafe {
+        obs_data_addref(obj);
+        let mut items = array(array_ptr).items.lock().unwrap();
+        items.push(SendPtr(obj));
+        items.len() - 1
+    }
+}
+
+// Properties
+
+struct Properties {
+    properties: Vec<*mut Property>,
+}
+
+struct Property {
+    name: CString,
+    description: CString,
+    long_description: CString,
+    visible: bool,
+    enabled: bool,
+    parent: *mut obs_properties_t,
+    group: *mut obs_properties_t,
+    clicked: obs_property_clicked_t,
+    modified2: obs_property_modified2_t,
+    modified2_private: *mut c_void,
+    list_items: Vec<(CString, CString)>,
+}
+
+unsafe fn properties<'a>(props: *mut obs_properties_t) -> &'a mut Properties {
+    unsafe { &mut *props.cast::<Properties>() }
+}
+
+unsafe fn property<'a>(prop: *mut obs_property_t) -> &'a mut Property {
+    unsafe { &mut *prop.cast::<Property>() }
+}
+
+unsafe fn add_property(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+) -> *mut obs_property_t {
+    unsafe {
+        let prop = Box::into_raw(Box::new(Property {
+            name: CStr::from_ptr(name).to_owned(),
+            description: if description.is_null() {
+                CString::default()
+            } else {
+                CStr::from_ptr(description).to_owned()
+            },
+            long_description: CString::default(),
+            visible: true,
+            enabled: true,
+            parent: props,
+            group: ptr::null_mut(),
+            clicked: None,
+            modified2: None,
+            modified2_private: ptr::null_mut(),
+            list_items: Vec::new(),
+        }));
+        properties(props).properties.push(prop);
+        prop.cast()
+    }
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn obs_properties_create() -> *mut obs_properties_t {
-    panic!()
+    Box::into_raw(Box::new(Properties {
+        properties: Vec::new(),
+    }))
+    .cast()
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_path(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
-    _description: *const c_char,
+pub unsafe extern "C" fn obs_properties_destroy(props: *mut obs_properties_t) {
+    if props.is_null() {
+        return;
+    }
+    unsafe {
+        let props = Box::from_raw(props.cast::<Properties>());
+        for prop in props.properties {
+            let prop = Box::from_raw(prop);
+            obs_properties_destroy(prop.group);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_properties_get(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+) -> *mut obs_property_t {
+    unsafe {
+        let name = CStr::from_ptr(name);
+        for &prop in &properties(props).properties {
+            if (*prop).name.as_c_str() == name {
+                return prop.cast();
+            }
+            if !(*prop).group.is_null() {
+                let found = obs_properties_get((*prop).group, name.as_ptr());
+                if !found.is_null() {
+                    return found;
+                }
+            }
+        }
+        ptr::null_mut()
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_properties_add_path(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
     _type_: obs_path_type,
     _filter: *const c_char,
     _default_path: *const c_char,
 ) -> *mut obs_property_t {
-    panic!()
+    unsafe { add_property(props, name, description) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_get_string(
-    _data: *mut obs_data_t,
-    _name: *const c_char,
-) -> *const c_char {
-    panic!()
+pub unsafe extern "C" fn obs_properties_add_bool(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+) -> *mut obs_property_t {
+    unsafe { add_property(props, name, description) }
 }
 
-// TODO: This technically should take a varargs ... argument, but that's not stable.
 #[unsafe(no_mangle)]
-pub extern "C" fn blog(_log_level: c_int, _format: *const c_char) {
-    panic!()
+pub unsafe extern "C" fn obs_properties_add_group(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+    _ty: obs_group_type,
+    group: *mut obs_properties_t,
+) -> *mut obs_property_t {
+    unsafe {
+        let prop = add_property(props, name, description);
+        property(prop).group = group;
+        prop
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_bool(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
-    _description: *const c_char,
+pub unsafe extern "C" fn obs_properties_add_int(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+    _min: c_int,
+    _max: c_int,
+    _step: c_int,
 ) -> *mut obs_property_t {
-    panic!()
+    unsafe { add_property(props, name, description) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_get_bool(_data: *mut obs_data_t, _name: *const c_char) -> bool {
-    panic!()
+pub unsafe extern "C" fn obs_properties_add_button(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    text: *const c_char,
+    callback: obs_property_clicked_t,
+) -> *mut obs_property_t {
+    unsafe {
+        let prop = add_property(props, name, text);
+        property(prop).clicked = callback;
+        prop
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_set_default_string(
-    _data: *mut obs_data_t,
-    _name: *const c_char,
-    _val: *const c_char,
-) {
-    panic!()
+pub unsafe extern "C" fn obs_properties_add_text(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+    _text_type: obs_text_type,
+) -> *mut obs_property_t {
+    unsafe { add_property(props, name, description) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_erase(_data: *mut obs_data_t, _name: *const c_char) {
-    panic!()
+pub unsafe extern "C" fn obs_properties_add_list(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+    _combo_type: obs_combo_type,
+    _combo_format: obs_combo_format,
+) -> *mut obs_property_t {
+    unsafe { add_property(props, name, description) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_group(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
-    _description: *const c_char,
-    _ty: obs_group_type,
-    _group: *mut obs_properties_t,
+pub unsafe extern "C" fn obs_properties_add_editable_list(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+    _list_type: obs_editable_list_type,
+    _filter: *const c_char,
+    _default_path: *const c_char,
 ) -> *mut obs_property_t {
-    panic!()
+    unsafe { add_property(props, name, description) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_int(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
-    _description: *const c_char,
-    _min: c_int,
-    _max: c_int,
-    _step: c_int,
+pub unsafe extern "C" fn obs_properties_add_color_alpha(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
+) -> *mut obs_property_t {
+    unsafe { add_property(props, name, description) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_properties_add_font(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    description: *const c_char,
 ) -> *mut obs_property_t {
-    panic!()
+    unsafe { add_property(props, name, description) }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_get_int(_data: *mut obs_data_t, _name: *const c_char) -> c_longlong {
-    panic!()
+pub unsafe extern "C" fn obs_property_list_add_string(
+    prop: *mut obs_property_t,
+    name: *const c_char,
+    val: *const c_char,
+) -> size_t {
+    unsafe {
+        let items = &mut property(prop).list_items;
+        items.push((
+            CStr::from_ptr(name).to_owned(),
+            CStr::from_ptr(val).to_owned(),
+        ));
+        items.len() - 1
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_texture_destroy(_tex: *mut gs_texture_t) {
-    panic!()
+pub unsafe extern "C" fn obs_property_list_clear(prop: *mut obs_property_t) {
+    unsafe { property(prop).list_items.clear() }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_draw_sprite(_tex: *mut gs_texture_t, _flip: u32, _width: u32, _height: u32) {
-    panic!()
+pub unsafe extern "C" fn obs_property_list_item_count(prop: *mut obs_property_t) -> size_t {
+    unsafe { property(prop).list_items.len() }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_effect_get_param_by_name(
-    _effect: *const gs_effect_t,
-    _name: *const c_char,
-) -> *mut gs_eparam_t {
-    panic!()
+pub unsafe extern "C" fn obs_property_list_item_string(
+    prop: *mut obs_property_t,
+    idx: size_t,
+) -> *const c_char {
+    unsafe {
+        property(prop)
+            .list_items
+            .get(idx)
+            .map_or(ptr::null(), |(_, val)| val.as_ptr())
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_effect_get_technique(
-    _effect: *const gs_effect_t,
-    _name: *const c_char,
-) -> *mut gs_technique_t {
-    panic!()
+pub unsafe extern "C" fn obs_property_set_modified_callback2(
+    prop: *mut obs_property_t,
+    modified2_callback: obs_property_modified2_t,
+    private: *mut c_void,
+) {
+    unsafe {
+        let prop = property(prop);
+        prop.modified2 = modified2_callback;
+        prop.modified2_private = private;
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_effect_set_texture(_param: *mut gs_eparam_t, _val: *mut gs_texture_t) {
-    panic!()
+pub unsafe extern "C" fn obs_property_modified(
+    prop: *mut obs_property_t,
+    settings: *mut obs_data_t,
+) -> bool {
+    unsafe {
+        let p = property(prop);
+        match p.modified2 {
+            Some(modified2) => modified2(p.modified2_private, p.parent, prop, settings),
+            None => false,
+        }
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_technique_begin(_technique: *mut gs_technique_t) -> size_t {
-    panic!()
+pub unsafe extern "C" fn obs_property_button_clicked(
+    prop: *mut obs_property_t,
+    obj: *mut c_void,
+) -> bool {
+    unsafe {
+        let p = property(prop);
+        let data = if obj.is_null() {
+            ptr::null_mut()
+        } else {
+            source(obj.cast()).data
+        };
+        match p.clicked {
+            Some(clicked) => clicked(p.parent, prop, data),
+            None => false,
+        }
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_technique_begin_pass(_technique: *mut gs_technique_t, _pass: size_t) -> bool {
-    panic!()
+pub unsafe extern "C" fn obs_property_set_description(
+    prop: *mut obs_property_t,
+    description: *const c_char,
+) {
+    unsafe { property(prop).description = CStr::from_ptr(description).to_owned() }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_technique_end(_technique: *mut gs_technique_t) {
-    panic!()
+pub unsafe extern "C" fn obs_property_description(prop: *mut obs_property_t) -> *const c_char {
+    unsafe { property(prop).description.as_ptr() }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn gs_technique_end_pass(_technique: *mut gs_technique_t) {
-    panic!()
+pub unsafe extern "C" fn obs_property_set_long_description(
+    prop: *mut obs_property_t,
+    long_description: *const c_char,
+) {
+    unsafe { property(prop).long_description = CStr::from_ptr(long_description).to_owned() }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_get_base_effect(_effect: obs_base_effect) -> *mut gs_effect_t {
-    panic!()
+pub unsafe extern "C" fn obs_property_long_description(prop: *mut obs_property_t) -> *const c_char {
+    unsafe { property(prop).long_description.as_ptr() }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_property_set_enabled(prop: *mut obs_property_t, enabled: bool) {
+    unsafe { property(prop).enabled = enabled }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_property_enabled(prop: *mut obs_property_t) -> bool {
+    unsafe { property(prop).enabled }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_property_set_visible(prop: *mut obs_property_t, visible: bool) {
+    unsafe { property(prop).visible = visible }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_set_default_bool(
-    _data: *mut obs_data_t,
+pub unsafe extern "C" fn obs_property_visible(prop: *mut obs_property_t) -> bool {
+    unsafe { property(prop).visible }
+}
+
+// Sources
+
+struct Source {
+    info: obs_source_info,
+    data: *mut c_void,
+    settings: *mut obs_data_t,
+    active: bool,
+    procs: Vec<Proc>,
+    signals: Vec<CString>,
+}
+
+struct Proc {
+    name: String,
+    func: proc_handler_proc_t,
+    data: *mut c_void,
+}
+
+struct Hotkey {
+    id: obs_hotkey_id,
+    source: *mut obs_source_t,
+    name: CString,
+    func: obs_hotkey_func,
+    data: *mut c_void,
+}
+
+unsafe fn source<'a>(source: *mut obs_source_t) -> &'a mut Source {
+    unsafe { &mut *source.cast::<Source>() }
+}
+
+fn find_source_type(id: &CStr) -> Option<obs_source_info> {
+    SOURCE_TYPES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|info| unsafe { CStr::from_ptr(info.0.id) } == id)
+        .map(|info| info.0)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_register_source_s(info: *const obs_source_info, _size: size_t) {
+    unsafe { SOURCE_TYPES.lock().unwrap().push(SendPtr(*info)) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_get_display_name(id: *const c_char) -> *const c_char {
+    unsafe {
+        match find_source_type(CStr::from_ptr(id)) {
+            Some(obs_source_info {
+                get_name: Some(get_name),
+                type_data,
+                ..
+            }) => get_name(type_data),
+            _ => ptr::null(),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_create(
+    id: *const c_char,
     _name: *const c_char,
-    _val: bool,
+    settings: *mut obs_data_t,
+    _hotkey_data: *mut obs_data_t,
+) -> *mut obs_source_t {
+    unsafe {
+        let Some(info) = find_source_type(CStr::from_ptr(id)) else {
+            return ptr::null_mut();
+        };
+
+        let source_settings = obs_data_create();
+        obs_data_apply(source_settings, settings);
+        if let Some(get_defaults) = info.get_defaults {
+            get_defaults(source_settings);
+        }
+
+        let source_ptr = Box::into_raw(Box::new(Source {
+            info,
+            data: ptr::null_mut(),
+            settings: source_settings,
+            active: false,
+            procs: Vec::new(),
+            signals: Vec::new(),
+        }))
+        .cast::<obs_source_t>();
+
+        if let Some(create) = info.create {
+            source(source_ptr).data = create(source_settings, source_ptr);
+        }
+
+        source_ptr
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_release(source_ptr: *mut obs_source_t) {
+    if source_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        HOTKEYS
+            .lock()
+            .unwrap()
+            .retain(|hotkey| hotkey.0.source != source_ptr);
+        LAST_DRAWN_TEXTURES
+            .lock()
+            .unwrap()
+            .retain(|drawn| drawn.0.0 != source_ptr);
+
+        let source = Box::from_raw(source_ptr.cast::<Source>());
+        if let Some(destroy) = source.info.destroy {
+            destroy(source.data);
+        }
+        obs_data_release(source.settings);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_get_settings(source_ptr: *mut obs_source_t) -> *mut obs_data_t {
+    unsafe {
+        let settings = source(source_ptr).settings;
+        obs_data_addref(settings);
+        settings
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_update(
+    source_ptr: *mut obs_source_t,
+    settings: *mut obs_data_t,
 ) {
-    panic!()
+    unsafe {
+        let source = source(source_ptr);
+        obs_data_apply(source.settings, settings);
+        if let Some(update) = source.info.update {
+            update(source.data, source.settings);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_properties(
+    source_ptr: *mut obs_source_t,
+) -> *mut obs_properties_t {
+    unsafe {
+        let source = source(source_ptr);
+        match source.info.get_properties {
+            Some(get_properties) => get_properties(source.data),
+            None => ptr::null_mut(),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_get_width(source_ptr: *mut obs_source_t) -> u32 {
+    unsafe {
+        let source = source(source_ptr);
+        source.info.get_width.map_or(0, |f| f(source.data))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_get_height(source_ptr: *mut obs_source_t) -> u32 {
+    unsafe {
+        let source = source(source_ptr);
+        source.info.get_height.map_or(0, |f| f(source.data))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_video_render(source_ptr: *mut obs_source_t) {
+    unsafe {
+        let source = source(source_ptr);
+        if let Some(video_render) = source.info.video_render {
+            let previous = RENDERING.replace(source_ptr);
+            video_render(source.data, ptr::null_mut());
+            RENDERING.set(previous);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_video_tick(source_ptr: *mut obs_source_t, seconds: f32) {
+    unsafe {
+        let source = source(source_ptr);
+        if let Some(video_tick) = source.info.video_tick {
+            video_tick(source.data, seconds);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_media_get_state(
+    source_ptr: *mut obs_source_t,
+) -> obs_media_state {
+    unsafe {
+        let source = source(source_ptr);
+        source
+            .info
+            .media_get_state
+            .map_or(OBS_MEDIA_STATE_NONE, |f| f(source.data))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_media_get_time(source_ptr: *mut obs_source_t) -> i64 {
+    unsafe {
+        let source = source(source_ptr);
+        source.info.media_get_time.map_or(0, |f| f(source.data))
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_set_default_int(
-    _data: *mut obs_data_t,
+pub unsafe extern "C" fn obs_source_media_play_pause(source_ptr: *mut obs_source_t, pause: bool) {
+    unsafe {
+        let source = source(source_ptr);
+        if let Some(media_play_pause) = source.info.media_play_pause {
+            media_play_pause(source.data, pause);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_media_stop(source_ptr: *mut obs_source_t) {
+    unsafe {
+        let source = source(source_ptr);
+        if let Some(media_stop) = source.info.media_stop {
+            media_stop(source.data);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_source_update_properties(_source: *mut obs_source_t) {}
+
+// There are no scenes, so looking up sources by name never finds anything.
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_get_source_by_name(_name: *const c_char) -> *mut obs_source_t {
+    ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_scene_from_source(_source: *const obs_source_t) -> *mut obs_scene_t {
+    ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_scene_find_source_recursive(
+    _scene: *mut obs_scene_t,
     _name: *const c_char,
-    _val: c_longlong,
+) -> *mut obs_sceneitem_t {
+    ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_sceneitem_set_visible(_item: *mut obs_sceneitem_t, _visible: bool) -> bool {
+    false
+}
+
+// The frontend API is a separate library in OBS. The plugin looks its
+// functions up among the loaded symbols, so the fake can simply provide them
+// as well.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_get_current_scene() -> *mut obs_source_t {
+    ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_set_current_scene(_scene: *mut obs_source_t) {}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+struct FrontendCallback(obs_frontend_event_cb, *mut c_void);
+
+unsafe impl Send for FrontendCallback {}
+
+static FRONTEND_CALLBACKS: Mutex<Vec<FrontendCallback>> = Mutex::new(Vec::new());
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_add_event_callback(
+    callback: obs_frontend_event_cb,
+    private_data: *mut c_void,
 ) {
-    panic!()
+    FRONTEND_CALLBACKS
+        .lock()
+        .unwrap()
+        .push(FrontendCallback(callback, private_data));
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_button(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
-    _text: *const c_char,
-    _callback: obs_property_clicked_t,
-) -> *mut obs_property_t {
-    panic!()
+pub extern "C" fn obs_frontend_remove_event_callback(
+    callback: obs_frontend_event_cb,
+    private_data: *mut c_void,
+) {
+    FRONTEND_CALLBACKS
+        .lock()
+        .unwrap()
+        .retain(|FrontendCallback(c, data)| {
+            c.map(|c| c as usize) != callback.map(|c| c as usize) || *data != private_data
+        });
+}
+
+// The recording starts and stops right away, unlike in OBS, where the events
+// are emitted later on the UI thread.
+fn emit_frontend_event(event: obs_frontend_event) {
+    let callbacks = FRONTEND_CALLBACKS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|FrontendCallback(callback, data)| (*callback, *data as usize))
+        .collect::<Vec<_>>();
+    for (callback, data) in callbacks {
+        if let Some(callback) = callback {
+            unsafe { callback(event, data as *mut c_void) };
+        }
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_text(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
-    _description: *const c_char,
-    _text_type: obs_text_type,
-) -> *mut obs_property_t {
-    panic!()
+pub extern "C" fn obs_frontend_recording_start() {
+    RECORDING.store(true, Ordering::Relaxed);
+    emit_frontend_event(OBS_FRONTEND_EVENT_RECORDING_STARTED);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_recording_stop() {
+    RECORDING.store(false, Ordering::Relaxed);
+    emit_frontend_event(OBS_FRONTEND_EVENT_RECORDING_STOPPED);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_recording_active() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_replay_buffer_save() {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_replay_buffer_active() -> bool {
+    false
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_property_set_modified_callback2(
-    _prop: *mut obs_property_t,
-    _modified2_callback: obs_property_modified2_t,
-    _private: *mut c_void,
+pub extern "C" fn obs_frontend_get_profile_config() -> *mut config_t {
+    dummy()
+}
+
+// Procedures and signals. The handlers of a source are the source itself.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_source_get_proc_handler(source: *const obs_source_t) -> *mut proc_handler_t {
+    source.cast_mut().cast()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_source_get_signal_handler(
+    source: *const obs_source_t,
+) -> *mut signal_handler_t {
+    source.cast_mut().cast()
+}
+
+/// Only the name of the declaration is used, e.g. `get_state` for
+/// `void get_state(out string phase)`.
+fn decl_name(decl: &str) -> &str {
+    let name = decl.split('(').next().unwrap_or_default();
+    name.rsplit(' ').next().unwrap_or_default()
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn proc_handler_add(
+    handler: *mut proc_handler_t,
+    decl_string: *const c_char,
+    func: proc_handler_proc_t,
+    data: *mut c_void,
 ) {
-    panic!()
+    unsafe {
+        let name = decl_name(to_str(decl_string)).to_owned();
+        source(handler.cast()).procs.push(Proc { name, func, data });
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_property_set_description(
-    _prop: *mut obs_property_t,
-    _description: *const c_char,
+pub unsafe extern "C" fn proc_handler_call(
+    handler: *mut proc_handler_t,
+    name: *const c_char,
+    params: *mut calldata_t,
+) -> bool {
+    if handler.is_null() {
+        return false;
+    }
+    unsafe {
+        let name = to_str(name);
+        let Some(proc_) = source(handler.cast())
+            .procs
+            .iter()
+            .find(|proc_| proc_.name == name)
+        else {
+            return false;
+        };
+        if let Some(func) = proc_.func {
+            func(proc_.data, params);
+        }
+        true
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn signal_handler_add(
+    handler: *mut signal_handler_t,
+    signal_decl: *const c_char,
+) -> bool {
+    unsafe {
+        let name = CString::new(decl_name(to_str(signal_decl))).unwrap();
+        source(handler.cast()).signals.push(name);
+        true
+    }
+}
+
+static SIGNALS: Mutex<Vec<(usize, CString)>> = Mutex::new(Vec::new());
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn signal_handler_signal(
+    handler: *mut signal_handler_t,
+    signal: *const c_char,
+    _params: *mut calldata_t,
 ) {
-    panic!()
+    unsafe {
+        let signal = CStr::from_ptr(signal);
+        assert!(
+            source(handler.cast())
+                .signals
+                .iter()
+                .any(|s| **s == *signal),
+            "The signal {signal:?} was never declared.",
+        );
+        SIGNALS
+            .lock()
+            .unwrap()
+            .push((handler as usize, signal.to_owned()));
+    }
 }
 
+/// Returns how often the source emitted the signal.
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_property_set_long_description(
-    _prop: *mut obs_property_t,
-    _long_description: *const c_char,
+pub unsafe extern "C" fn fake_obs_signal_count(
+    source: *mut obs_source_t,
+    signal: *const c_char,
+) -> usize {
+    let signal = unsafe { CStr::from_ptr(signal) };
+    SIGNALS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(handler, s)| *handler == source as usize && **s == *signal)
+        .count()
+}
+
+// Call data isn't stored in its own stack, but in a map next to it, keyed by
+// its address.
+static CALLDATA: Mutex<BTreeMap<(usize, String), Vec<u8>>> = Mutex::new(BTreeMap::new());
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn calldata_set_data(
+    data: *mut calldata_t,
+    name: *const c_char,
+    value: *const c_void,
+    size: size_t,
 ) {
-    panic!()
+    unsafe {
+        let value = std::slice::from_raw_parts(value.cast::<u8>(), size).to_vec();
+        CALLDATA
+            .lock()
+            .unwrap()
+            .insert((data as usize, to_str(name).to_owned()), value);
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_property_set_enabled(_prop: *mut obs_property_t, _enabled: bool) {
-    panic!()
+pub unsafe extern "C" fn calldata_get_data(
+    data: *const calldata_t,
+    name: *const c_char,
+    out: *mut c_void,
+    size: size_t,
+) -> bool {
+    unsafe {
+        let calldata = CALLDATA.lock().unwrap();
+        match calldata.get(&(data as usize, to_str(name).to_owned())) {
+            Some(value) if value.len() == size => {
+                ptr::copy_nonoverlapping(value.as_ptr(), out.cast(), size);
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_property_set_visible(_prop: *mut obs_property_t, _visible: bool) {
-    panic!()
+pub unsafe extern "C" fn calldata_get_string(
+    data: *const calldata_t,
+    name: *const c_char,
+    str_: *mut *const c_char,
+) -> bool {
+    unsafe {
+        let calldata = CALLDATA.lock().unwrap();
+        match calldata.get(&(data as usize, to_str(name).to_owned())) {
+            Some(value) => {
+                *str_ = value.as_ptr().cast();
+                true
+            }
+            None => false,
+        }
+    }
 }
 
+/// Creates call data for calling a procedure. It needs to be freed with
+/// `fake_obs_calldata_free`.
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_get(
-    _props: *mut obs_properties_t,
-    _prop: *const c_char,
-) -> *mut obs_property_t {
-    panic!()
+pub extern "C" fn fake_obs_calldata_create() -> *mut calldata_t {
+    Box::into_raw(Box::new(calldata_t {
+        stack: ptr::null_mut(),
+        size: 0,
+        capacity: 0,
+        fixed: false,
+    }))
 }
 
+/// Forgets all the values of the call data and frees it.
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_module_get_config_path(
-    _module: *mut obs_module_t,
-    _file: *const c_char,
+pub unsafe extern "C" fn fake_obs_calldata_free(data: *mut calldata_t) {
+    CALLDATA
+        .lock()
+        .unwrap()
+        .retain(|(address, _), _| *address != data as usize);
+    unsafe { drop(Box::from_raw(data)) }
+}
+
+// The audio is simply thrown away.
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_source_output_audio(
+    _source: *mut obs_source_t,
+    _audio: *const obs_source_audio,
+) {
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn os_gettime_ns() -> u64 {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_nanos() as u64
+}
+
+// There are no outputs, so there's nothing to call procedures on either.
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_frontend_get_recording_output() -> *mut obs_output_t {
+    ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_output_release(_output: *mut obs_output_t) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_output_get_proc_handler(_output: *const obs_output_t) -> *mut proc_handler_t {
+    ptr::null_mut()
+}
+
+// There's only the single profile config, so the config itself is ignored.
+static CONFIG: Mutex<BTreeMap<(String, String), CString>> = Mutex::new(BTreeMap::new());
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn config_get_string(
+    _config: *mut config_t,
+    section: *const c_char,
+    name: *const c_char,
 ) -> *const c_char {
-    panic!()
+    let key = unsafe { (to_str(section).to_owned(), to_str(name).to_owned()) };
+    CONFIG
+        .lock()
+        .unwrap()
+        .get(&key)
+        .map_or(ptr::null(), |value| value.as_ptr())
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_list(
-    _props: *mut obs_properties_t,
-    _name: *const c_char,
+pub unsafe extern "C" fn config_set_string(
+    _config: *mut config_t,
+    section: *const c_char,
+    name: *const c_char,
+    value: *const c_char,
+) {
+    let key = unsafe { (to_str(section).to_owned(), to_str(name).to_owned()) };
+    let value = unsafe { CStr::from_ptr(value) }.to_owned();
+    CONFIG.lock().unwrap().insert(key, value);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn obs_hotkey_register_source(
+    source: *mut obs_source_t,
+    name: *const c_char,
     _description: *const c_char,
-    _combo_type: obs_combo_type,
-    _combo_format: obs_combo_format,
-) -> *mut obs_property_t {
-    panic!()
+    func: obs_hotkey_func,
+    data: *mut c_void,
+) -> obs_hotkey_id {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    unsafe {
+        HOTKEYS.lock().unwrap().push(SendPtr(Hotkey {
+            id,
+            source,
+            name: CStr::from_ptr(name).to_owned(),
+            func,
+            data,
+        }));
+    }
+    id
 }
 
+/// Activates or deactivates the source, as if it was shown on or hidden from
+/// the program output.
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_properties_add_editable_list(
-    _props: *mut obs_properties_t,
+pub unsafe extern "C" fn fake_obs_source_set_active(source_ptr: *mut obs_source_t, active: bool) {
+    unsafe {
+        let source = source(source_ptr);
+        if source.active == active {
+            return;
+        }
+        source.active = active;
+        let callback = if active {
+            source.info.activate
+        } else {
+            source.info.deactivate
+        };
+        if let Some(callback) = callback {
+            callback(source.data);
+        }
+    }
+}
+
+/// Presses and releases the hotkey with the given name that the source
+/// registered. Returns `false` if there is no such hotkey.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fake_obs_hotkey_press(
+    source: *mut obs_source_t,
+    name: *const c_char,
+) -> bool {
+    unsafe {
+        let name = CStr::from_ptr(name);
+        let Some((id, func, data)) = HOTKEYS
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|hotkey| hotkey.0.source == source && hotkey.0.name.as_c_str() == name)
+            .map(|hotkey| (hotkey.0.id, hotkey.0.func, hotkey.0.data))
+        else {
+            return false;
+        };
+        if let Some(func) = func {
+            func(data, id, ptr::null_mut(), true);
+            func(data, id, ptr::null_mut(), false);
+        }
+        true
+    }
+}
+
+// Graphics
+
+thread_local! {
+    /// The source that is rendering on this thread, so whatever gets drawn
+    /// can be attributed to it.
+    static RENDERING: Cell<*mut obs_source_t> = const { Cell::new(ptr::null_mut()) };
+}
+
+struct Texture {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_enter_graphics() {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_leave_graphics() {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn gs_texture_create(
+    width: u32,
+    height: u32,
+    _color_format: gs_color_format,
+    _levels: u32,
+    _data: *mut *const u8,
+    _flags: u32,
+) -> *mut gs_texture_t {
+    Box::into_raw(Box::new(Texture {
+        width,
+        height,
+        data: vec![0; 4 * width as usize * height as usize],
+    }))
+    .cast()
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_texture_destroy(tex: *mut gs_texture_t) {
+    if tex.is_null() {
+        return;
+    }
+    unsafe {
+        LAST_DRAWN_TEXTURES
+            .lock()
+            .unwrap()
+            .retain(|drawn| drawn.0.1 != tex);
+        drop(Box::from_raw(tex.cast::<Texture>()));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_texture_set_image(
+    tex: *mut gs_texture_t,
+    data: *const u8,
+    linesize: u32,
+    _invert: bool,
+) {
+    unsafe {
+        let tex = &mut *tex.cast::<Texture>();
+        let len = linesize as usize * tex.height as usize;
+        tex.data.clear();
+        tex.data
+            .extend_from_slice(std::slice::from_raw_parts(data, len));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_texture_get_width(tex: *const gs_texture_t) -> u32 {
+    unsafe { (*tex.cast::<Texture>()).width }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_texture_get_height(tex: *const gs_texture_t) -> u32 {
+    unsafe { (*tex.cast::<Texture>()).height }
+}
+
+/// Returns the texture that the source drew last, or null if it didn't draw
+/// anything yet.
+#[unsafe(no_mangle)]
+pub extern "C" fn fake_obs_last_drawn_texture(source: *mut obs_source_t) -> *mut gs_texture_t {
+    LAST_DRAWN_TEXTURES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|drawn| drawn.0.0 == source)
+        .map_or(ptr::null_mut(), |drawn| drawn.0.1)
+}
+
+/// Returns the pixels of the texture along with their length in bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn fake_obs_texture_data(
+    tex: *const gs_texture_t,
+    len: *mut size_t,
+) -> *const u8 {
+    unsafe {
+        let tex = &*tex.cast::<Texture>();
+        *len = tex.data.len();
+        tex.data.as_ptr()
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_draw_sprite(
+    tex: *mut gs_texture_t,
+    _flip: u32,
+    _width: u32,
+    _height: u32,
+) {
+    let source = RENDERING.get();
+    if source.is_null() {
+        return;
+    }
+    let mut last_drawn = LAST_DRAWN_TEXTURES.lock().unwrap();
+    last_drawn.retain(|drawn| drawn.0.0 != source);
+    last_drawn.push(SendPtr((source, tex)));
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn gs_effect_get_param_by_name(
+    _effect: *const gs_effect_t,
     _name: *const c_char,
-    _description: *const c_char,
-    _list_type: obs_editable_list_type,
-    _filter: *const c_char,
-    _default_path: *const c_char,
-) -> *mut obs_property_t {
-    panic!()
+) -> *mut gs_eparam_t {
+    dummy()
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_property_list_add_string(
-    _prop: *mut obs_property_t,
+pub extern "C" fn gs_effect_get_technique(
+    _effect: *const gs_effect_t,
     _name: *const c_char,
-    _val: *const c_char,
-) -> size_t {
-    panic!()
+) -> *mut gs_technique_t {
+    dummy()
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_set_bool(_data: *mut obs_data_t, _name: *const c_char, _val: bool) {
-    panic!()
+pub extern "C" fn gs_effect_set_texture(_param: *mut gs_eparam_t, _val: *mut gs_texture_t) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn gs_technique_begin(_technique: *mut gs_technique_t) -> size_t {
+    1
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_set_string(
-    _data: *mut obs_data_t,
-    _name: *const c_char,
-    _val: *const c_char,
-) {
-    panic!()
+pub extern "C" fn gs_technique_begin_pass(_technique: *mut gs_technique_t, _pass: size_t) -> bool {
+    true
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_get_array(_data: *mut obs_data_t, _name: *const c_char) -> *mut c_void {
-    panic!()
+pub extern "C" fn gs_technique_end(_technique: *mut gs_technique_t) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn gs_technique_end_pass(_technique: *mut gs_technique_t) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_get_base_effect(_effect: obs_base_effect) -> *mut gs_effect_t {
+    dummy()
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_array_count(_array: *mut c_void) -> size_t {
-    panic!()
+pub extern "C" fn gs_effect_create(
+    _effect_string: *const c_char,
+    _filename: *const c_char,
+    _error_string: *mut *mut c_char,
+) -> *mut gs_effect_t {
+    dummy()
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_array_item(_array: *mut c_void, _idx: size_t) -> *mut obs_data_t {
-    panic!()
+pub extern "C" fn gs_effect_destroy(_effect: *mut gs_effect_t) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn gs_effect_set_vec4(_param: *mut gs_eparam_t, _val: *const vec4) {}
+
+// Filters are always rendered directly, as there are no actual render
+// targets.
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_source_process_filter_begin(
+    _filter: *mut obs_source_t,
+    _format: gs_color_format,
+    _allow_direct: obs_allow_direct_render,
+) -> bool {
+    true
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_array_release(_array: *mut c_void) {
-    panic!()
+pub extern "C" fn obs_source_process_filter_end(
+    _filter: *mut obs_source_t,
+    _effect: *mut gs_effect_t,
+    _width: u32,
+    _height: u32,
+) {
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_release(_data: *mut obs_data_t) {
-    panic!()
+pub extern "C" fn obs_source_skip_video_filter(_filter: *mut obs_source_t) {}
+
+// Miscellaneous
+
+// This technically should take a varargs ... argument, but that's not stable.
+// The plugin always logs with a format of `%s` and a single string argument,
+// which is passed the same way as a regular argument on the platforms the
+// fake is used on.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn blog(_log_level: c_int, _format: *const c_char, message: *const c_char) {
+    let message = unsafe { to_str(message) }.to_owned();
+    eprintln!("{message}");
+    LOG.lock().unwrap().push(message);
 }
 
+/// Returns whether any of the messages logged so far contains the needle.
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_data_get_json(_data: *mut obs_data_t) -> *const c_char {
-    panic!()
+pub unsafe extern "C" fn fake_obs_log_contains(needle: *const c_char) -> bool {
+    let needle = unsafe { to_str(needle) };
+    LOG.lock()
+        .unwrap()
+        .iter()
+        .any(|message| message.contains(needle))
 }
 
+// The configuration is stored in the directory specified by the
+// `FAKE_OBS_CONFIG_PATH` environment variable, or a folder in the temporary
+// directory otherwise.
 #[unsafe(no_mangle)]
-pub extern "C" fn obs_source_update_properties(_source: *mut obs_source_t) {
-    panic!()
+pub unsafe extern "C" fn obs_module_get_config_path(
+    _module: *mut obs_module_t,
+    file: *const c_char,
+) -> *const c_char {
+    let dir = env::var_os("FAKE_OBS_CONFIG_PATH")
+        .map(Into::into)
+        .unwrap_or_else(|| env::temp_dir().join("fake-obs-config"));
+    let path = dir.join(unsafe { to_str(file) });
+    // Just like in OBS, the caller owns the string.
+    CString::new(path.to_string_lossy().into_owned())
+        .unwrap_or_default()
+        .into_raw()
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn obs_get_locale() -> *const c_char {
-    panic!()
+    c"en-US".as_ptr()
 }
@@ -0,0 +1,553 @@
+use std::{
+    ffi::{c_void, CStr},
+    fs, io, mem,
+    os::raw::c_char,
+    path::{Path, PathBuf},
+    ptr,
+    sync::{
+        atomic::{self, AtomicBool},
+        mpsc::{Receiver, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use livesplit_core::event::Event;
+use log::warn;
+
+use crate::{
+    add_loaded_splits_list,
+    ffi::{
+        obs_data_get_int, obs_data_get_string, obs_data_set_default_int, obs_data_t,
+        obs_properties_add_int, obs_properties_add_path, obs_properties_create, obs_properties_t,
+        obs_register_source_s, obs_source_audio, obs_source_info, obs_source_output_audio,
+        obs_source_t, os_gettime_ns, AUDIO_FORMAT_FLOAT, OBS_COMBO_TYPE_EDITABLE,
+        OBS_ICON_TYPE_AUDIO_OUTPUT, OBS_PATH_FILE, OBS_SOURCE_AUDIO, OBS_SOURCE_TYPE_INPUT,
+        SPEAKERS_STEREO,
+    },
+    get_global_timer,
+    localization::{lang, Text},
+    timer_events::TimerEvent,
+    GlobalTimer, UnsafeMultiThread,
+};
+
+const SETTINGS_SPLITS_PATH: *const c_char = cstr!(c"splits_path");
+const SETTINGS_VOLUME: *const c_char = cstr!(c"volume");
+
+/// The number of frames that are handed to OBS at once.
+const CHUNK_FRAMES: usize = 1024;
+
+/// A source that plays sounds when something happens in a timer, like
+/// LiveSplit does. The sounds are its audio output, so their volume can be
+/// adjusted in the mixer and they can be left out of recordings like any other
+/// audio source.
+struct State {
+    global_timer: Arc<GlobalTimer>,
+    source: *mut obs_source_t,
+    cues: Arc<Mutex<Cues>>,
+    player: Option<Player>,
+}
+
+/// The thread that plays the sounds for the events of the current timer.
+struct Player {
+    stopped: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct Cues {
+    sounds: [Option<Arc<Sound>>; Cue::ALL.len()],
+    volume: f32,
+}
+
+#[derive(Copy, Clone)]
+enum Cue {
+    Split,
+    Gold,
+    Behind,
+    PersonalBest,
+    Undo,
+    Reset,
+}
+
+/// A decoded sound as interleaved stereo samples.
+struct Sound {
+    samples: Vec<f32>,
+    sample_rate: u32,
+}
+
+struct Settings {
+    splits_path: PathBuf,
+    cues: Cues,
+}
+
+impl Cue {
+    const ALL: [Self; 6] = [
+        Self::Split,
+        Self::Gold,
+        Self::Behind,
+        Self::PersonalBest,
+        Self::Undo,
+        Self::Reset,
+    ];
+
+    fn key(self) -> *const c_char {
+        match self {
+            Self::Split => cstr!(c"sound_split"),
+            Self::Gold => cstr!(c"sound_gold"),
+            Self::Behind => cstr!(c"sound_behind"),
+            Self::PersonalBest => cstr!(c"sound_personal_best"),
+            Self::Undo => cstr!(c"sound_undo"),
+            Self::Reset => cstr!(c"sound_reset"),
+        }
+    }
+
+    fn description(self) -> Text {
+        match self {
+            Self::Split => Text::CueSplit,
+            Self::Gold => Text::CueGold,
+            Self::Behind => Text::CueBehind,
+            Self::PersonalBest => Text::CuePersonalBest,
+            Self::Undo => Text::CueUndo,
+            Self::Reset => Text::CueReset,
+        }
+    }
+
+    /// The cues for the event, from the most to the least specific one. The
+    /// split sound is used for any split that doesn't have a more specific
+    /// sound configured.
+    fn for_event(event: &TimerEvent) -> Vec<Self> {
+        match event.event {
+            Event::Splitted | Event::Finished => {
+                let Some(split) = &event.split else {
+                    return vec![Self::Split];
+                };
+                let mut cues = Vec::new();
+                if split.is_personal_best {
+                    cues.push(Self::PersonalBest);
+                }
+                if split.is_gold {
+                    cues.push(Self::Gold);
+                }
+                if split.is_behind {
+                    cues.push(Self::Behind);
+                }
+                cues.push(Self::Split);
+                cues
+            }
+            Event::SplitUndone => vec![Self::Undo],
+            Event::Reset => vec![Self::Reset],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Cues {
+    fn sound_for(&self, event: &TimerEvent) -> Option<Arc<Sound>> {
+        Cue::for_event(event)
+            .into_iter()
+            .find_map(|cue| self.sounds[cue as usize].clone())
+    }
+}
+
+impl Sound {
+    fn load(path: &Path) -> io::Result<Self> {
+        decode_wav(&fs::read(path)?)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unsupported WAV file"))
+    }
+}
+
+/// Decodes a WAV file with integer or floating point samples. Any channels
+/// beyond the first two are dropped and mono is played on both sides.
+fn decode_wav(data: &[u8]) -> Option<Sound> {
+    if data.get(..4)? != b"RIFF" || data.get(8..12)? != b"WAVE" {
+        return None;
+    }
+
+    let mut format = None;
+    let mut samples = None;
+    let mut rest = &data[12..];
+    while rest.len() >= 8 {
+        let id = &rest[..4];
+        let len = u32::from_le_bytes(rest[4..8].try_into().ok()?) as usize;
+        let end = len.checked_add(8)?;
+        let chunk = rest.get(8..end)?;
+        match id {
+            b"fmt " => format = Some(chunk),
+            b"data" => samples = Some(chunk),
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        rest = end
+            .checked_add(len & 1)
+            .and_then(|end| rest.get(end..))
+            .unwrap_or_default();
+    }
+
+    let format = format?;
+    let samples = samples?;
+    let u16_at = |i: usize| Some(u16::from_le_bytes(format.get(i..i + 2)?.try_into().ok()?));
+
+    let mut tag = u16_at(0)?;
+    let channels = usize::from(u16_at(2)?);
+    let sample_rate = u32::from_le_bytes(format.get(4..8)?.try_into().ok()?);
+    let bits = u16_at(14)?;
+    // WAVE_FORMAT_EXTENSIBLE stores the actual format in its sub format.
+    if tag == 0xFFFE {
+        tag = u16_at(24)?;
+    }
+
+    let bytes = usize::from(bits / 8);
+    if channels == 0 || sample_rate == 0 || bytes == 0 {
+        return None;
+    }
+    let decode = |s: &[u8]| -> Option<f32> {
+        Some(match (tag, bits) {
+            (1, 8) => (f32::from(s[0]) - 128.0) / 128.0,
+            (1, 16) => f32::from(i16::from_le_bytes([s[0], s[1]])) / 32768.0,
+            (1, 24) => (i32::from_le_bytes([0, s[0], s[1], s[2]]) >> 8) as f32 / 8388608.0,
+            (1, 32) => i32::from_le_bytes([s[0], s[1], s[2], s[3]]) as f32 / 2147483648.0,
+            (3, 32) => f32::from_le_bytes([s[0], s[1], s[2], s[3]]),
+            (3, 64) => f64::from_le_bytes(s.try_into().ok()?) as f32,
+            _ => return None,
+        })
+    };
+
+    let mut stereo = Vec::with_capacity(2 * samples.len() / (bytes * channels));
+    for frame in samples.chunks_exact(bytes * channels) {
+        let left = decode(&frame[..bytes])?;
+        let right = match channels {
+            1 => left,
+            _ => decode(&frame[bytes..2 * bytes])?,
+        };
+        stereo.extend([left, right]);
+    }
+
+    Some(Sound {
+        samples: stereo,
+        sample_rate,
+    })
+}
+
+impl Player {
+    fn start(
+        global_timer: &GlobalTimer,
+        source: *mut obs_source_t,
+        cues: Arc<Mutex<Cues>>,
+    ) -> Self {
+        let receiver = global_timer.timer.events.subscribe();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let source = UnsafeMultiThread(source);
+        let thread = thread::spawn({
+            let stopped = stopped.clone();
+            move || {
+                let source = source;
+                play(receiver, cues, source.0, &stopped)
+            }
+        });
+        Self { stopped, thread }
+    }
+
+    fn stop(self) {
+        self.stopped.store(true, atomic::Ordering::Relaxed);
+        let _ = self.thread.join();
+    }
+}
+
+/// Plays the sounds in real time, a chunk at a time, so a new event can cut
+/// off the sound that is currently playing.
+fn play(
+    receiver: Receiver<Arc<TimerEvent>>,
+    cues: Arc<Mutex<Cues>>,
+    source: *mut obs_source_t,
+    stopped: &AtomicBool,
+) {
+    let mut playing: Option<(Arc<Sound>, usize, u64, Instant)> = None;
+    let mut buffer = Vec::new();
+
+    while !stopped.load(atomic::Ordering::Relaxed) {
+        let event = if playing.is_some() {
+            receiver.try_recv().ok()
+        } else {
+            match receiver.recv_timeout(Duration::from_millis(100)) {
+                Ok(event) => Some(event),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        };
+
+        if let Some(event) = event {
+            if let Some(sound) = cues.lock().unwrap().sound_for(&event) {
+                playing = Some((sound, 0, unsafe { os_gettime_ns() }, Instant::now()));
+            }
+        }
+
+        let Some((sound, position, timestamp, start)) = &mut playing else {
+            continue;
+        };
+
+        let end = (*position + 2 * CHUNK_FRAMES).min(sound.samples.len());
+        let volume = cues.lock().unwrap().volume;
+        buffer.clear();
+        buffer.extend(sound.samples[*position..end].iter().map(|s| s * volume));
+
+        let frames = (end - *position) / 2;
+        let played = Duration::from_secs_f64(*position as f64 / 2.0 / sound.sample_rate as f64);
+        let audio = obs_source_audio {
+            data: [
+                buffer.as_ptr().cast(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+            ],
+            frames: frames as u32,
+            speakers: SPEAKERS_STEREO,
+            format: AUDIO_FORMAT_FLOAT,
+            samples_per_sec: sound.sample_rate,
+            timestamp: *timestamp + played.as_nanos() as u64,
+        };
+        unsafe { obs_source_output_audio(source, &audio) };
+
+        *position = end;
+        let played = Duration::from_secs_f64(end as f64 / 2.0 / sound.sample_rate as f64);
+        if end == sound.samples.len() {
+            playing = None;
+        } else {
+            // Stay a little ahead of the playback, so OBS never runs dry.
+            let ahead = Duration::from_millis(20);
+            if let Some(wait) = played.checked_sub(start.elapsed() + ahead) {
+                thread::sleep(wait);
+            }
+        }
+    }
+}
+
+unsafe fn parse_settings(settings: *mut obs_data_t) -> Settings {
+    unsafe {
+        let splits_path =
+            CStr::from_ptr(obs_data_get_string(settings, SETTINGS_SPLITS_PATH).cast());
+        let splits_path = PathBuf::from(splits_path.to_string_lossy().into_owned());
+
+        let mut cues = Cues {
+            volume: obs_data_get_int(settings, SETTINGS_VOLUME) as f32 / 100.0,
+            ..Default::default()
+        };
+        for cue in Cue::ALL {
+            let path = CStr::from_ptr(obs_data_get_string(settings, cue.key()).cast());
+            if path.is_empty() {
+                continue;
+            }
+            let path = PathBuf::from(path.to_string_lossy().into_owned());
+            match Sound::load(&path) {
+                Ok(sound) => cues.sounds[cue as usize] = Some(Arc::new(sound)),
+                Err(e) => warn!("Couldn't load the sound `{}`: {e}", path.display()),
+            }
+        }
+
+        Settings { splits_path, cues }
+    }
+}
+
+impl State {
+    fn new(Settings { splits_path, cues }: Settings, source: *mut obs_source_t) -> Self {
+        let global_timer = get_global_timer(splits_path);
+        let cues = Arc::new(Mutex::new(cues));
+        let player = Player::start(&global_timer, source, cues.clone());
+        Self {
+            global_timer,
+            source,
+            cues,
+            player: Some(player),
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        if let Some(player) = self.player.take() {
+            player.stop();
+        }
+    }
+}
+
+unsafe extern "C" fn get_name(_: *mut c_void) -> *const c_char {
+    Text::AudioCuesSourceName.resolve(lang())
+}
+
+unsafe extern "C" fn create(settings: *mut obs_data_t, source: *mut obs_source_t) -> *mut c_void {
+    unsafe {
+        Box::into_raw(Box::new(Mutex::new(State::new(
+            parse_settings(settings),
+            source,
+        ))))
+        .cast()
+    }
+}
+
+unsafe extern "C" fn destroy(data: *mut c_void) {
+    unsafe {
+        drop(Box::<Mutex<State>>::from_raw(data.cast()));
+    }
+}
+
+unsafe extern "C" fn get_properties(_: *mut c_void) -> *mut obs_properties_t {
+    unsafe {
+        let lang = lang();
+
+        let props = obs_properties_create();
+        add_loaded_splits_list(props, SETTINGS_SPLITS_PATH, OBS_COMBO_TYPE_EDITABLE);
+        obs_properties_add_int(
+            props,
+            SETTINGS_VOLUME,
+            Text::PropertyVolume.resolve(lang),
+            0,
+            100,
+            1,
+        );
+        for cue in Cue::ALL {
+            obs_properties_add_path(
+                props,
+                cue.key(),
+                cue.description().resolve(lang),
+                OBS_PATH_FILE,
+                Text::PropertySoundFilter.resolve(lang),
+                ptr::null(),
+            );
+        }
+        props
+    }
+}
+
+unsafe extern "C" fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_int(settings, SETTINGS_VOLUME, 100);
+    }
+}
+
+unsafe extern "C" fn update(data: *mut c_void, settings: *mut obs_data_t) {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        let settings = parse_settings(settings);
+
+        *state.cues.lock().unwrap() = settings.cues;
+
+        let global_timer = get_global_timer(settings.splits_path);
+        if !Arc::ptr_eq(&global_timer, &state.global_timer) {
+            if let Some(player) = state.player.take() {
+                player.stop();
+            }
+            state.player = Some(Player::start(
+                &global_timer,
+                state.source,
+                state.cues.clone(),
+            ));
+            state.global_timer = global_timer;
+        }
+    }
+}
+
+pub fn register() {
+    static SOURCE_INFO: UnsafeMultiThread<obs_source_info> = UnsafeMultiThread(obs_source_info {
+        id: cstr!(c"livesplit-one-audio-cues"),
+        type_: OBS_SOURCE_TYPE_INPUT,
+        output_flags: OBS_SOURCE_AUDIO,
+        get_name: Some(get_name),
+        create: Some(create),
+        destroy: Some(destroy),
+        get_width: None,
+        get_height: None,
+        video_render: None,
+        mouse_wheel: None,
+        get_properties: Some(get_properties),
+        get_defaults: Some(get_defaults),
+        update: Some(update),
+        icon_type: OBS_ICON_TYPE_AUDIO_OUTPUT,
+        activate: None,
+        deactivate: None,
+        show: None,
+        hide: None,
+        video_tick: None,
+        filter_video: None,
+        filter_audio: None,
+        enum_active_sources: None,
+        save: None,
+        load: None,
+        mouse_click: None,
+        mouse_move: None,
+        focus: None,
+        key_click: None,
+        filter_remove: None,
+        type_data: ptr::null_mut(),
+        free_type_data: None,
+        audio_render: None,
+        enum_all_sources: None,
+        transition_start: None,
+        transition_stop: None,
+        get_defaults2: None,
+        get_properties2: None,
+        audio_mix: None,
+        media_play_pause: None,
+        media_restart: None,
+        media_stop: None,
+        media_next: None,
+        media_previous: None,
+        media_get_duration: None,
+        media_get_time: None,
+        media_set_time: None,
+        media_get_state: None,
+        version: 0,
+        unversioned_id: ptr::null(),
+    });
+
+    let source_info: &obs_source_info = &SOURCE_INFO.0;
+
+    unsafe {
+        obs_register_source_s(source_info, mem::size_of_val(source_info) as _);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_wav;
+
+    fn wav(channels: u16, sample_rate: u32, data_len: u32, samples: &[i16]) -> Vec<u8> {
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF\0\0\0\0WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&channels.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * 2 * u32::from(channels)).to_le_bytes());
+        wav.extend_from_slice(&(2 * channels).to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+        wav
+    }
+
+    #[test]
+    fn decodes_mono_as_stereo() {
+        let sound = decode_wav(&wav(1, 44100, 4, &[16384, -16384])).unwrap();
+        assert_eq!(sound.sample_rate, 44100);
+        assert_eq!(sound.samples, [0.5, 0.5, -0.5, -0.5]);
+    }
+
+    #[test]
+    fn rejects_invalid_formats() {
+        assert!(decode_wav(&wav(0, 44100, 4, &[0, 0])).is_none());
+        assert!(decode_wav(&wav(1, 0, 4, &[0, 0])).is_none());
+    }
+
+    #[test]
+    fn rejects_chunks_that_are_longer_than_the_file() {
+        assert!(decode_wav(&wav(1, 44100, u32::MAX, &[0, 0])).is_none());
+    }
+}
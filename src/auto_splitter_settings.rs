@@ -0,0 +1,238 @@
+//! Imports and exports the settings of an auto splitter in the format that
+//! LiveSplit stores them in as part of the splits, so they can be shared
+//! between sources, runners and LiveSplit itself.
+
+use std::{fmt::Write, fs, path::Path, sync::Arc};
+
+use anyhow::{bail, Context, Result};
+use livesplit_core::auto_splitting::settings::{List, Map, Value};
+use quick_xml::{de, escape::escape};
+use serde_derive::Deserialize;
+
+/// The settings on their own, as they are exported.
+#[derive(Deserialize)]
+struct AutoSplitterSettings {
+    #[serde(rename = "CustomSettings")]
+    custom_settings: Option<Settings>,
+}
+
+/// The splits the settings are part of in LiveSplit.
+#[derive(Deserialize)]
+struct Run {
+    #[serde(rename = "AutoSplitterSettings")]
+    auto_splitter_settings: Option<AutoSplitterSettings>,
+}
+
+#[derive(Deserialize)]
+struct Settings {
+    #[serde(rename = "Setting", default)]
+    settings: Vec<Setting>,
+}
+
+#[derive(Deserialize)]
+struct Setting {
+    #[serde(rename = "@id")]
+    id: Option<String>,
+    #[serde(rename = "@type")]
+    kind: String,
+    #[serde(rename = "@value")]
+    value: Option<String>,
+    #[serde(rename = "Setting", default)]
+    settings: Vec<Setting>,
+}
+
+/// Writes the settings to the file. The path of the auto splitter is included
+/// the way LiveSplit does, but isn't needed for importing them again.
+pub fn export(path: &Path, map: &Map, script_path: Option<&Path>) -> Result<()> {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<AutoSplitterSettings>\n  <Version>1.0</Version>\n",
+    );
+    if let Some(script_path) = script_path {
+        let _ = writeln!(
+            xml,
+            "  <ScriptPath>{}</ScriptPath>",
+            escape(&*script_path.to_string_lossy()),
+        );
+    }
+    xml.push_str("  <CustomSettings>\n");
+    for (key, value) in map.iter() {
+        write_setting(&mut xml, Some(&key.to_string()), value, 2);
+    }
+    xml.push_str("  </CustomSettings>\n</AutoSplitterSettings>\n");
+
+    fs::write(path, xml).context("Failed writing the file.")
+}
+
+fn write_setting(xml: &mut String, id: Option<&str>, value: &Value, depth: usize) {
+    let (kind, value) = match value {
+        Value::Bool(value) => ("bool", Some(if *value { "True" } else { "False" }.into())),
+        Value::I64(value) => ("i64", Some(value.to_string())),
+        Value::F64(value) => ("f64", Some(value.to_string())),
+        Value::String(value) => ("string", Some(value.to_string())),
+        Value::Map(_) => ("map", None),
+        Value::List(_) => ("list", None),
+        _ => return,
+    };
+
+    let indentation = "  ".repeat(depth);
+    let _ = write!(xml, "{indentation}<Setting");
+    if let Some(id) = id {
+        let _ = write!(xml, " id=\"{}\"", escape(id));
+    }
+    let _ = write!(xml, " type=\"{kind}\"");
+
+    if let Some(value) = value {
+        let _ = writeln!(xml, " value=\"{}\" />", escape(&*value));
+        return;
+    }
+
+    xml.push_str(">\n");
+    match value {
+        Value::Map(map) => {
+            for (key, value) in map.iter() {
+                write_setting(xml, Some(&key.to_string()), value, depth + 1);
+            }
+        }
+        Value::List(list) => {
+            for value in list.iter() {
+                write_setting(xml, None, value, depth + 1);
+            }
+        }
+        _ => {}
+    }
+    let _ = writeln!(xml, "{indentation}</Setting>");
+}
+
+/// Reads the settings from either a file that was exported or the splits of
+/// LiveSplit.
+pub fn import(path: &Path) -> Result<Map> {
+    let xml = fs::read_to_string(path).context("Failed reading the file.")?;
+
+    let settings = match de::from_str::<AutoSplitterSettings>(&xml) {
+        Ok(AutoSplitterSettings {
+            custom_settings: Some(settings),
+        }) => settings,
+        _ => de::from_str::<Run>(&xml)
+            .ok()
+            .and_then(|run| run.auto_splitter_settings?.custom_settings)
+            .context("The file doesn't contain any auto splitter settings.")?,
+    };
+
+    let mut map = Map::new();
+    for setting in settings.settings {
+        let Some(id) = setting.id.clone() else {
+            continue;
+        };
+        map.insert(Arc::from(id), parse_setting(setting)?);
+    }
+    Ok(map)
+}
+
+fn parse_setting(setting: Setting) -> Result<Value> {
+    let value = || {
+        setting
+            .value
+            .as_deref()
+            .context("The setting doesn't have a value.")
+    };
+
+    Ok(match &*setting.kind {
+        "bool" => Value::Bool(value()?.eq_ignore_ascii_case("true")),
+        "i64" => Value::I64(value()?.parse().context("Failed parsing the integer.")?),
+        "f64" => Value::F64(value()?.parse().context("Failed parsing the number.")?),
+        "string" => Value::String(Arc::from(value()?)),
+        "map" => {
+            let mut map = Map::new();
+            for setting in setting.settings {
+                let Some(id) = setting.id.clone() else {
+                    continue;
+                };
+                map.insert(Arc::from(id), parse_setting(setting)?);
+            }
+            Value::Map(map)
+        }
+        "list" => {
+            let mut list = List::new();
+            for setting in setting.settings {
+                list.push(parse_setting(setting)?);
+            }
+            Value::List(list)
+        }
+        kind => bail!("The type `{kind}` of the setting is not supported."),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, sync::Arc};
+
+    use livesplit_core::auto_splitting::settings::{List, Map, Value};
+
+    use super::{export, import};
+
+    #[test]
+    fn exported_settings_can_be_imported_again() {
+        let mut inner = Map::new();
+        inner.insert(Arc::from("speed"), Value::F64(1.5));
+        let mut list = List::new();
+        list.push(Value::String(Arc::from("a \"quoted\" <value>")));
+        list.push(Value::I64(-3));
+
+        let mut map = Map::new();
+        map.insert(Arc::from("start"), Value::Bool(true));
+        map.insert(Arc::from("split_on_boss"), Value::Bool(false));
+        map.insert(Arc::from("category"), Value::String(Arc::from("Any%")));
+        map.insert(Arc::from("inner"), Value::Map(inner));
+        map.insert(Arc::from("list"), Value::List(list));
+
+        let path = env::temp_dir().join("obs-livesplit-one-exported-settings.xml");
+        export(&path, &map, None).unwrap();
+        let imported = import(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported.get("start").and_then(Value::to_bool), Some(true));
+        assert_eq!(
+            imported.get("split_on_boss").and_then(Value::to_bool),
+            Some(false),
+        );
+        assert!(matches!(imported.get("category"), Some(Value::String(s)) if &**s == "Any%"));
+        let Some(Value::Map(inner)) = imported.get("inner") else {
+            panic!("The map is missing.");
+        };
+        assert!(matches!(inner.get("speed"), Some(Value::F64(speed)) if *speed == 1.5));
+        let Some(Value::List(list)) = imported.get("list") else {
+            panic!("The list is missing.");
+        };
+        let values = list.iter().collect::<Vec<_>>();
+        assert!(matches!(values[0], Value::String(s) if &**s == "a \"quoted\" <value>"));
+        assert!(matches!(values[1], Value::I64(-3)));
+    }
+
+    #[test]
+    fn imports_the_settings_of_livesplit_splits() {
+        let path = env::temp_dir().join("obs-livesplit-one-splits.lss");
+        fs::write(
+            &path,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Run version="1.8.0">
+  <GameName>Game</GameName>
+  <CategoryName>Any%</CategoryName>
+  <Segments />
+  <AutoSplitterSettings>
+    <Version>1.0</Version>
+    <ScriptPath>C:\auto_splitter.wasm</ScriptPath>
+    <CustomSettings>
+      <Setting id="start" type="bool" value="False" />
+      <Setting id="route" type="string" value="Glitchless" />
+    </CustomSettings>
+  </AutoSplitterSettings>
+</Run>"#,
+        )
+        .unwrap();
+        let imported = import(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported.get("start").and_then(Value::to_bool), Some(false));
+        assert!(matches!(imported.get("route"), Some(Value::String(s)) if &**s == "Glitchless"));
+    }
+}
@@ -0,0 +1,131 @@
+//! Keeps track of what the auto splitters report while they run, so it can be
+//! shown in the properties rather than only in the OBS log.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    ffi::CStr,
+    fmt::Write,
+    sync::{Arc, Mutex, Weak},
+};
+
+use livesplit_core::Lang;
+use log::Record;
+
+use crate::localization::Text;
+
+/// The target that the messages of the auto splitters and their runtime are
+/// logged with.
+const LOG_TARGET: &str = "Auto Splitter";
+const MAX_LOG_LINES: usize = 20;
+
+// What the runtime logs when the process or the tick rate changes. The auto
+// splitters log with the same target, so only these exact messages count.
+const ATTACHED_PREFIX: &str = "Attached to a new process: ";
+const DETACHED: &str = "Detached from a process.";
+const TICK_RATE_PREFIX: &str = "New Tick Rate: ";
+
+thread_local! {
+    /// The log of the timer that was last used on this thread. Every auto
+    /// splitter runs on a thread of its own and uses the timer of its splits,
+    /// so that's the log its messages belong to.
+    static CURRENT_LOG: RefCell<Weak<Mutex<RuntimeLog>>> = const { RefCell::new(Weak::new()) };
+}
+
+/// What the auto splitter of a timer reported while it ran.
+#[derive(Default)]
+pub struct RuntimeLog {
+    lines: VecDeque<String>,
+    attached_process: Option<String>,
+    tick_rate: Option<String>,
+}
+
+impl RuntimeLog {
+    /// Forgets what the previous auto splitter reported about its process
+    /// and tick rate, as a newly loaded one starts from scratch.
+    pub fn reset(&mut self) {
+        self.attached_process = None;
+        self.tick_rate = None;
+    }
+}
+
+/// Marks the log as the one that the messages logged on the current thread
+/// belong to. This needs to be called whenever the timer is used.
+pub fn enter(log: &Arc<Mutex<RuntimeLog>>) {
+    CURRENT_LOG.with_borrow_mut(|current| {
+        if current.as_ptr() != Arc::as_ptr(log) {
+            *current = Arc::downgrade(log);
+        }
+    });
+}
+
+/// Keeps the message if it's from an auto splitter or its runtime. Messages
+/// logged before the auto splitter used its timer for the first time can't
+/// be attributed to it, so they only end up in the OBS log.
+pub fn capture(record: &Record) {
+    if record.target() != LOG_TARGET {
+        return;
+    }
+    let Some(log) = CURRENT_LOG.with_borrow(Weak::upgrade) else {
+        return;
+    };
+
+    let message = record.args().to_string();
+    let mut log = log.lock().unwrap();
+
+    // The process and the tick rate can't be queried, but the runtime reports
+    // whenever they change.
+    if message == DETACHED {
+        log.attached_process = None;
+    } else if let Some(process) = message.strip_prefix(ATTACHED_PREFIX) {
+        log.attached_process = Some(process.to_owned());
+    } else if let Some(tick_rate) = message.strip_prefix(TICK_RATE_PREFIX) {
+        log.tick_rate = Some(tick_rate.to_owned());
+    }
+
+    if log.lines.len() == MAX_LOG_LINES {
+        log.lines.pop_front();
+    }
+    log.lines
+        .push_back(format!("[{}] {message}", record.level()));
+}
+
+/// Describes the state of the auto splitter, followed by its most recent
+/// messages.
+pub fn describe(is_loaded: bool, last_error: Option<&str>, log: &RuntimeLog, lang: Lang) -> String {
+    let text = |text: Text| unsafe { CStr::from_ptr(text.resolve(lang)).to_string_lossy() };
+    let unknown = text(Text::AutoSplitterStatusUnknown);
+
+    let mut description = String::from(if is_loaded {
+        text(Text::AutoSplitterStatusLoaded)
+    } else {
+        text(Text::AutoSplitterStatusNotLoaded)
+    });
+    if is_loaded {
+        let _ = write!(
+            description,
+            "\n{}: {}\n{}: {}",
+            text(Text::AutoSplitterStatusProcess),
+            log.attached_process.as_deref().unwrap_or(&unknown),
+            text(Text::AutoSplitterStatusTickRate),
+            log.tick_rate.as_deref().unwrap_or(&unknown),
+        );
+    }
+    if let Some(last_error) = last_error {
+        let _ = write!(
+            description,
+            "\n{}: {last_error}",
+            text(Text::AutoSplitterStatusLastError),
+        );
+    }
+
+    if !log.lines.is_empty() {
+        description.push('\n');
+        for line in &log.lines {
+            description.push('\n');
+            description.push_str(line);
+        }
+    }
+
+    description
+}
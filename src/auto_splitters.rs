@@ -1,23 +1,49 @@
-use anyhow::{format_err, Context, Error, Result};
-use livesplit_core::util::PopulateString;
+use anyhow::{bail, format_err, Context, Error, Result};
 use log::{error, info, warn};
 use quick_xml::de;
-use reqwest::{blocking::Client, Url};
-use serde_derive::Deserialize;
+use reqwest::{
+    blocking::Client,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode, Url,
+};
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::{
+    collections::{BTreeMap, BTreeSet},
     ffi::CStr,
     fs,
     path::{Path, PathBuf},
     str,
     sync::{
         atomic::{self},
-        OnceLock,
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex, OnceLock,
     },
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use crate::ffi::obs_module_get_config_path;
 
-const LIST_FILE_NAME: &str = "LiveSplit.AutoSplitters.xml";
+const OFFICIAL_LIST_URL: &str = "https://raw.githubusercontent.com/LiveSplit/LiveSplit.AutoSplitters/master/LiveSplit.AutoSplitters.xml";
+/// Where the lists of auto splitters are loaded from.
+const LIST_SOURCES_FILE_NAME: &str = "lists.txt";
+/// The folder the lists that were downloaded are cached in.
+const LIST_CACHE_FOLDER_NAME: &str = "lists";
+const CHOICES_FILE_NAME: &str = "choices.json";
+/// The splits whose auto splitters were activated.
+const ACTIVATIONS_FILE_NAME: &str = "activated.json";
+const DOWNLOADS_FILE_NAME: &str = "downloads.json";
+/// The hashes of all the files that were ever used, so files that change
+/// unexpectedly are noticed.
+const LOCK_FILE_NAME: &str = "lock.json";
+/// How similar a game name needs to be to the name of the splits to be
+/// considered a match at all.
+const MIN_SIMILARITY: f64 = 0.7;
+/// How well a game name needs to match the name of the splits for its auto
+/// splitter to be used without the user choosing it.
+const CERTAIN_MATCH: f64 = 1.0;
+const LIST_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
 pub fn get_module_config_path() -> &'static PathBuf {
     static OBS_MODULE_CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
@@ -39,12 +65,19 @@ pub fn get_module_config_path() -> &'static PathBuf {
     })
 }
 
-pub static LIST: OnceLock<List> = OnceLock::new();
+static LIST: Mutex<Option<Arc<List>>> = Mutex::new(None);
 
-pub fn get_list() -> &'static List {
-    static EMPTY: List = List::empty();
+/// The thread that refreshes the list, along with the sender to request a
+/// refresh right away. Dropping the sender stops the thread.
+static REFRESH_THREAD: Mutex<Option<(Sender<()>, JoinHandle<()>)>> = Mutex::new(None);
 
-    LIST.get().unwrap_or(&EMPTY)
+/// Returns the list that is currently loaded. It may get replaced at any
+/// time, so it shouldn't be held onto for long.
+pub fn get_list() -> Arc<List> {
+    LIST.lock()
+        .unwrap()
+        .get_or_insert_with(|| Arc::new(List::empty()))
+        .clone()
 }
 
 pub fn get_downloader() -> &'static Downloader {
@@ -65,7 +98,6 @@ pub struct Downloader {
 
 pub struct List {
     inner: ListInner,
-    source: String,
 }
 
 #[derive(Deserialize)]
@@ -88,6 +120,10 @@ pub struct AutoSplitter {
     pub description: String,
     #[serde(rename = "Website")]
     pub website: Option<String>,
+    /// The SHA-256 hash of the WebAssembly module. The official list doesn't
+    /// have any, but custom lists may.
+    #[serde(rename = "SHA256")]
+    sha256: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -96,6 +132,38 @@ struct Games {
     games: Vec<String>,
 }
 
+/// An auto splitter that may belong to the game of the splits.
+pub struct Candidate<'a> {
+    /// The name of the game in the list that matched.
+    pub game: &'a str,
+    pub auto_splitter: &'a AutoSplitter,
+    /// 2 for an exact match, 1 for a match after normalizing both names and
+    /// less for names that are merely similar.
+    pub score: f64,
+}
+
+/// Where a file that was downloaded came from and which version of it is on
+/// disk, so it only needs to be downloaded again if it changed, and can still
+/// be used while offline.
+#[derive(Clone, Serialize, Deserialize)]
+struct Download {
+    file_name: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    sha256: String,
+    /// The seconds since the Unix epoch.
+    downloaded_at: u64,
+}
+
+enum Fetched {
+    NotModified,
+    Modified {
+        bytes: Vec<u8>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+}
+
 #[derive(Deserialize)]
 struct Urls {
     #[serde(rename = "URL")]
@@ -108,69 +176,381 @@ impl List {
             inner: ListInner {
                 auto_splitters: Vec::new(),
             },
-            source: String::new(),
         }
     }
 
-    pub fn save_to_disk(&self, folder: &Path) -> Result<()> {
-        fs::write(folder.join(LIST_FILE_NAME), &self.source).map_err(Into::into)
+    pub fn get_for_game(&self, game_name: &str) -> Option<&AutoSplitter> {
+        self.inner
+            .auto_splitters
+            .iter()
+            .find(|x| x.games.games.iter().any(|g| g == game_name))
+    }
+
+    /// The auto splitters that can be used, as they run on the auto splitting
+    /// runtime.
+    pub fn runtime_auto_splitters(&self) -> impl Iterator<Item = &AutoSplitter> {
+        self.inner
+            .auto_splitters
+            .iter()
+            .filter(|auto_splitter| auto_splitter.is_using_auto_splitting_runtime())
     }
 
-    pub fn get_website_for_game(&self, game_name: &str) -> Option<&str> {
-        self.get_for_game(game_name)?.website.as_deref()
+    /// Finds the auto splitter for the game of the splits. The game the user
+    /// chose takes precedence over the best match. Games that are merely
+    /// similar are never used on their own, the user needs to choose them.
+    pub fn find(&self, game_name: &str, choice: Option<&str>) -> Option<&AutoSplitter> {
+        choice
+            .and_then(|choice| self.get_for_game(choice))
+            .or_else(|| {
+                let candidate = self.candidates_for_game(game_name).into_iter().next()?;
+                (candidate.score >= CERTAIN_MATCH).then_some(candidate.auto_splitter)
+            })
     }
 
-    pub fn get_for_game(&self, game_name: &str) -> Option<&AutoSplitter> {
-        self.inner
+    /// Ranks the auto splitters by how well any of the names of their games
+    /// match the name of the game of the splits. Differences in casing,
+    /// punctuation and whitespace are ignored entirely and small typos are
+    /// tolerated.
+    pub fn candidates_for_game(&self, game_name: &str) -> Vec<Candidate<'_>> {
+        let normalized_name = normalize(game_name);
+        if normalized_name.is_empty() {
+            return Vec::new();
+        }
+
+        let mut candidates = self
+            .inner
             .auto_splitters
             .iter()
-            .find(|x| x.games.games.iter().any(|g| g == game_name))
+            .filter_map(|auto_splitter| {
+                auto_splitter
+                    .games
+                    .games
+                    .iter()
+                    .map(|game| {
+                        let score = if game == game_name {
+                            2.0
+                        } else {
+                            similarity(&normalized_name, &normalize(game))
+                        };
+                        Candidate {
+                            game,
+                            auto_splitter,
+                            score,
+                        }
+                    })
+                    .max_by(|a, b| a.score.total_cmp(&b.score))
+            })
+            .filter(|candidate| candidate.score >= MIN_SIMILARITY)
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates
+    }
+}
+
+/// Lowercases the name and reduces it to words that consist of letters and
+/// digits, separated by single spaces.
+fn normalize(name: &str) -> String {
+    name.replace('&', " and ")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// How similar the normalized names are, from 0 to 1. This is the best of the
+/// edit distance relative to the longer name and the share of words that both
+/// names have in common.
+fn similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+
+    let a_chars = a.chars().collect::<Vec<_>>();
+    let b_chars = b.chars().collect::<Vec<_>>();
+    let max_len = a_chars.len().max(b_chars.len());
+    if max_len == 0 {
+        return 0.0;
+    }
+
+    // Levenshtein distance with a single row.
+    let mut row = (0..=b_chars.len()).collect::<Vec<_>>();
+    for (i, a_char) in a_chars.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    let edit_similarity = 1.0 - row[b_chars.len()] as f64 / max_len as f64;
+
+    let a_words = a.split(' ').collect::<Vec<_>>();
+    let b_words = b.split(' ').collect::<Vec<_>>();
+    let shared = a_words.iter().filter(|word| b_words.contains(word)).count();
+    let word_similarity = shared as f64 / a_words.len().max(b_words.len()) as f64;
+
+    // Neither measure can reach 1 for names that differ, as only equal names
+    // are certain matches.
+    edit_similarity.max(word_similarity).min(0.99)
+}
+
+/// Returns the name of the game the user chose the auto splitter of for the
+/// splits.
+pub fn get_choice(splits_path: &Path) -> Option<String> {
+    load_choices().remove(&*splits_path.to_string_lossy())
+}
+
+/// Remembers the name of the game the user chose the auto splitter of for the
+/// splits, or forgets the choice.
+pub fn set_choice(splits_path: &Path, game: Option<&str>) {
+    let mut choices = load_choices();
+    let key = splits_path.to_string_lossy().into_owned();
+    match game {
+        Some(game) => choices.insert(key, game.to_owned()),
+        None => choices.remove(&key),
+    };
+
+    let path = get_path().join(CHOICES_FILE_NAME);
+    if let Err(e) = serde_json::to_vec_pretty(&choices)
+        .map_err(Error::from)
+        .and_then(|json| fs::write(&path, json).map_err(Into::into))
+    {
+        error!("Failed saving the chosen auto splitters: {e:?}");
+    }
+}
+
+/// Returns whether the user activated the auto splitter for the splits, so it
+/// can be activated again when the splits are loaded the next time.
+pub fn is_activated(splits_path: &Path) -> bool {
+    load_activations().contains(&*splits_path.to_string_lossy())
+}
+
+/// Remembers whether the user activated the auto splitter for the splits.
+pub fn set_activated(splits_path: &Path, is_activated: bool) {
+    let mut activations = load_activations();
+    let key = splits_path.to_string_lossy().into_owned();
+    let changed = if is_activated {
+        activations.insert(key)
+    } else {
+        activations.remove(&key)
+    };
+    if !changed {
+        return;
+    }
+
+    let path = get_path().join(ACTIVATIONS_FILE_NAME);
+    if let Err(e) = serde_json::to_vec_pretty(&activations)
+        .map_err(Error::from)
+        .and_then(|json| fs::write(&path, json).map_err(Into::into))
+    {
+        error!("Failed saving the activated auto splitters: {e:?}");
+    }
+}
+
+fn load_activations() -> BTreeSet<String> {
+    fs::read(get_path().join(ACTIVATIONS_FILE_NAME))
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Returns where the lists of auto splitters are loaded from. Every line is a
+/// separate list. Alternative locations of the same list, like mirrors, are
+/// separated by `|`. A location is either a URL, a file or a folder of files
+/// that each contain a part of the list.
+pub fn get_list_sources() -> String {
+    fs::read_to_string(get_path().join(LIST_SOURCES_FILE_NAME))
+        .unwrap_or_else(|_| OFFICIAL_LIST_URL.to_owned())
+}
+
+/// Changes where the lists of auto splitters are loaded from. The change only
+/// applies the next time the list is refreshed.
+pub fn set_list_sources(sources: &str) {
+    if let Err(e) = fs::write(get_path().join(LIST_SOURCES_FILE_NAME), sources) {
+        error!("Failed saving the auto splitter lists: {e}");
     }
 }
 
+/// Splits the sources into the lists and their alternative locations.
+/// Empty lines and lines starting with `#` are ignored.
+fn parse_list_sources(sources: &str) -> Vec<Vec<&str>> {
+    sources
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.split('|')
+                .map(str::trim)
+                .filter(|location| !location.is_empty())
+                .collect()
+        })
+        .collect()
+}
+
+fn load_choices() -> BTreeMap<String, String> {
+    fs::read(get_path().join(CHOICES_FILE_NAME))
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .unwrap_or_default()
+}
+
 impl Downloader {
     pub fn new() -> Self {
         Self {
-            client: Client::builder()
-                .use_rustls_tls()
-                .http2_prior_knowledge()
-                .build()
-                .unwrap(),
+            // Mirrors of the list aren't necessarily served over HTTP/2, so
+            // it's negotiated rather than assumed.
+            client: Client::builder().use_rustls_tls().build().unwrap(),
         }
     }
 
-    pub fn download_list(&self, folder: &Path) -> Result<List, [Error; 2]> {
-        let from_github_error = match get_list_from_github(&self.client) {
-            Ok(list) => return Ok(list),
-            Err(e) => e,
-        };
+    /// Loads all the lists and merges them into one. The auto splitters of
+    /// lists further down take precedence over the ones above them, so a list
+    /// can replace auto splitters of the official list. Lists that can't be
+    /// loaded are skipped, unless none of them can be loaded at all.
+    pub fn download_list(&self, sources: &str, folder: &Path) -> Result<List> {
+        let sources = parse_list_sources(sources);
+        let mut auto_splitters = Vec::new();
+        let mut any_loaded = sources.is_empty();
+
+        for locations in sources.iter().rev() {
+            match self.load_list(locations, folder) {
+                Ok(list) => {
+                    auto_splitters.extend(list.auto_splitters);
+                    any_loaded = true;
+                }
+                Err(e) => error!(
+                    "{:?}",
+                    e.context(format!(
+                        "Failed loading the auto splitter list `{}`.",
+                        locations.join(" | "),
+                    ))
+                ),
+            }
+        }
+
+        if !any_loaded {
+            bail!("None of the auto splitter lists could be loaded.");
+        }
+
+        Ok(List {
+            inner: ListInner { auto_splitters },
+        })
+    }
+
+    /// Loads the list from the first of its locations that works. If none of
+    /// them do, the version that was cached the last time is used.
+    fn load_list(&self, locations: &[&str], folder: &Path) -> Result<ListInner> {
+        let cache_path = folder
+            .join(LIST_CACHE_FOLDER_NAME)
+            .join(format!("{}.xml", sha256(locations.join("|").as_bytes())));
+
+        for location in locations {
+            match self.load_list_from(location, &cache_path) {
+                Ok(list) => return Ok(list),
+                Err(e) => warn!("Failed loading the auto splitter list from `{location}`: {e:?}"),
+            }
+        }
+
+        let source = fs::read_to_string(&cache_path)
+            .context("The list couldn't be loaded from any of its locations and isn't cached.")?;
+        warn!(
+            "Using the cached version of the auto splitter list `{}`.",
+            locations.join(" | "),
+        );
+        parse_list(&source)
+    }
+
+    fn load_list_from(&self, location: &str, cache_path: &Path) -> Result<ListInner> {
+        let path = match Url::parse(location) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {
+                let source = self
+                    .client
+                    .get(url)
+                    .send()
+                    .context("Failed sending the request.")?
+                    .error_for_status()
+                    .context("The response was unsuccessful.")?
+                    .text()
+                    .context("Failed receiving the body as text.")?;
+
+                let list = parse_list(&source)?;
+
+                if let Err(e) = cache_path
+                    .parent()
+                    .map_or(Ok(()), fs::create_dir_all)
+                    .and_then(|_| fs::write(cache_path, &source))
+                {
+                    warn!("Failed caching the auto splitter list: {e}");
+                }
 
-        let from_file_error = match get_list_from_file(folder) {
-            Ok(list) => {
-                warn!("Failed downloading the auto splitters list from GitHub. Using the cached version. Error: {from_github_error:?}");
                 return Ok(list);
             }
-            Err(e) => e,
+            Ok(url) if url.scheme() == "file" => url
+                .to_file_path()
+                .map_err(|_| format_err!("The URL is not a valid file path."))?,
+            // Windows paths look like URLs with the drive letter as the scheme.
+            _ => PathBuf::from(location),
         };
 
-        Err([from_github_error, from_file_error])
+        if !path.is_dir() {
+            let source = fs::read_to_string(&path).context("Failed reading the file.")?;
+            return parse_list(&source);
+        }
+
+        // The parts of the list are merged in reverse order of their names,
+        // so that, just like with separate lists, later ones take precedence.
+        let mut file_paths = fs::read_dir(&path)
+            .context("Failed reading the folder.")?
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"))
+            })
+            .collect::<Vec<_>>();
+        file_paths.sort_unstable();
+
+        let mut auto_splitters = Vec::new();
+        for file_path in file_paths.iter().rev() {
+            let source = fs::read_to_string(file_path)
+                .with_context(|| format!("Failed reading `{}`.", file_path.display()))?;
+            let part = parse_list(&source)
+                .with_context(|| format!("Failed parsing `{}`.", file_path.display()))?;
+            auto_splitters.extend(part.auto_splitters);
+        }
+
+        Ok(ListInner { auto_splitters })
     }
 
     pub fn download_for_game(
         &self,
         list: &List,
         game_name: &str,
+        choice: Option<&str>,
         folder: &Path,
+        pin_mismatches: &mut Vec<PinMismatch>,
     ) -> Option<PathBuf> {
-        self.download(list.get_for_game(game_name)?, folder)
+        self.download(list.find(game_name, choice)?, folder, pin_mismatches)
     }
 
-    pub fn download(&self, auto_splitter: &AutoSplitter, folder: &Path) -> Option<PathBuf> {
+    pub fn download(
+        &self,
+        auto_splitter: &AutoSplitter,
+        folder: &Path,
+        pin_mismatches: &mut Vec<PinMismatch>,
+    ) -> Option<PathBuf> {
         let mut file_paths = Vec::new();
 
         for url in &auto_splitter.urls.urls {
+            let list_sha256 = auto_splitter
+                .sha256
+                .as_deref()
+                .filter(|_| is_module_url(url));
+
             if let Err(e) = self
-                .download_file(url, folder, &mut file_paths)
+                .download_file(url, list_sha256, folder, &mut file_paths, pin_mismatches)
                 .with_context(|| format_err!("Failed downloading `{url}`."))
             {
                 error!("{e:#?}");
@@ -182,69 +562,252 @@ impl Downloader {
             .find(|path| path.extension().is_some_and(|e| e == "wasm"))
     }
 
-    fn download_file(&self, url: &str, folder: &Path, file_paths: &mut Vec<PathBuf>) -> Result<()> {
-        let url = Url::parse(url).context("Failed parsing the URL.")?;
+    /// Downloads the file, unless the cached version is still up to date. The
+    /// file must match the hash from the list or, if there is none, the hash
+    /// of the version that was used the first time. Files that differ from
+    /// that version are reported, so the user can accept them.
+    fn download_file(
+        &self,
+        url: &str,
+        list_sha256: Option<&str>,
+        folder: &Path,
+        file_paths: &mut Vec<PathBuf>,
+        pin_mismatches: &mut Vec<PinMismatch>,
+    ) -> Result<()> {
+        let parsed_url = Url::parse(url).context("Failed parsing the URL.")?;
 
-        let file_name = url
+        let file_name = parsed_url
             .path_segments()
             .and_then(|mut s| s.next_back())
             .context("There is no file name in the URL.")?;
 
-        let file_name = percent_encoding::percent_decode_str(file_name).decode_utf8_lossy();
-        let file_path = folder.join(file_name.as_str());
+        let file_name = percent_encoding::percent_decode_str(file_name)
+            .decode_utf8_lossy()
+            .into_owned();
+        let file_path = folder.join(&file_name);
 
-        let bytes = self
-            .client
+        let mut pins = load_pins(folder);
+        let expected = list_sha256
+            .map(|hash| hash.to_ascii_lowercase())
+            .or_else(|| pins.get(url).cloned());
+
+        // The cached version is only used if the file is still exactly the
+        // one that was downloaded and it's the expected one.
+        let mut downloads = load_downloads(folder);
+        let cached = downloads
             .get(url)
-            .send()
-            .context("Failed sending the request.")?
-            .error_for_status()
-            .context("The response is unsuccessful.")?
-            .bytes()
-            .context("Failed receiving the response.")?;
+            .filter(|download| {
+                download.file_name == file_name
+                    && expected
+                        .as_ref()
+                        .is_none_or(|hash| *hash == download.sha256)
+                    && fs::read(&file_path).is_ok_and(|bytes| sha256(&bytes) == download.sha256)
+            })
+            .cloned();
 
-        fs::write(&file_path, bytes).context("Failed writing the file.")?;
+        match self.fetch(parsed_url, cached.as_ref()) {
+            Ok(Fetched::NotModified) => {
+                if cached.is_none() {
+                    bail!("The server claims that the file didn't change, but it isn't cached.");
+                }
+                info!("`{url}` didn't change. Using the cached file.");
+            }
+            Ok(Fetched::Modified {
+                bytes,
+                etag,
+                last_modified,
+            }) => {
+                let hash = sha256(&bytes);
+                if let Some(expected) = expected.as_ref().filter(|expected| **expected != hash) {
+                    // Only the version that was used the first time can be
+                    // replaced. The hash from the list is always the one to
+                    // use.
+                    if list_sha256.is_none() {
+                        pin_mismatches.push(PinMismatch {
+                            url: url.to_owned(),
+                            sha256: hash.clone(),
+                        });
+                    }
+                    match &cached {
+                        Some(_) => warn!(
+                            "`{url}` changed unexpectedly. Its SHA-256 hash is {hash} instead of {expected}. Keeping the previous version.",
+                        ),
+                        None => bail!(
+                            "The SHA-256 hash of the file is {hash} instead of {expected}. Refusing to use it.",
+                        ),
+                    }
+                    file_paths.push(file_path);
+                    return Ok(());
+                }
+
+                fs::write(&file_path, &bytes).context("Failed writing the file.")?;
+
+                downloads.insert(
+                    url.to_owned(),
+                    Download {
+                        file_name,
+                        etag,
+                        last_modified,
+                        sha256: hash,
+                        downloaded_at: SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap_or_default()
+                            .as_secs(),
+                    },
+                );
+                if let Err(e) = save_downloads(folder, &downloads) {
+                    warn!("Failed saving the information about the downloads: {e:?}");
+                }
+            }
+            Err(e) => match &cached {
+                Some(cached) => warn!(
+                    "Failed downloading `{url}`. Using the version that was cached {} seconds after the Unix epoch. Error: {e:?}",
+                    cached.downloaded_at,
+                ),
+                None => return Err(e),
+            },
+        }
+
+        // Whatever version is used from now on is the one that is expected in
+        // the future.
+        if let Some(download) = downloads.get(url) {
+            if pins.get(url) != Some(&download.sha256) {
+                pins.insert(url.to_owned(), download.sha256.clone());
+                if let Err(e) = save_pins(folder, &pins) {
+                    warn!("Failed saving the hashes of the downloads: {e:?}");
+                }
+            }
+        }
 
         file_paths.push(file_path);
 
         Ok(())
     }
+
+    /// Requests the file, unless the cached version is still the same.
+    fn fetch(&self, url: Url, cached: Option<&Download>) -> Result<Fetched> {
+        let mut request = self.client.get(url);
+        if let Some(cached) = cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request.send().context("Failed sending the request.")?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(Fetched::NotModified);
+        }
+
+        let response = response
+            .error_for_status()
+            .context("The response is unsuccessful.")?;
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+        let (etag, last_modified) = (header(ETAG), header(LAST_MODIFIED));
+
+        let bytes = response
+            .bytes()
+            .context("Failed receiving the response.")?
+            .to_vec();
+
+        Ok(Fetched::Modified {
+            bytes,
+            etag,
+            last_modified,
+        })
+    }
 }
 
-impl AutoSplitter {
-    pub fn is_using_auto_splitting_runtime(&self) -> bool {
-        self.script_type
-            .as_ref()
-            .is_some_and(|t| t == "AutoSplittingRuntime")
+/// A downloaded file that differs from the version that was used the first
+/// time.
+#[derive(Clone)]
+pub struct PinMismatch {
+    pub url: String,
+    pub sha256: String,
+}
+
+impl PinMismatch {
+    /// Expects the new version from now on, so the next download uses it.
+    pub fn accept(&self, folder: &Path) -> Result<()> {
+        let mut pins = load_pins(folder);
+        pins.insert(self.url.clone(), self.sha256.clone());
+        save_pins(folder, &pins)
     }
 }
 
-fn get_list_from_github(client: &Client) -> Result<List> {
-    let url = "https://raw.githubusercontent.com/LiveSplit/LiveSplit.AutoSplitters/master/LiveSplit.AutoSplitters.xml";
+fn sha256(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Whether the URL refers to the WebAssembly module of an auto splitter.
+fn is_module_url(url: &str) -> bool {
+    Url::parse(url).is_ok_and(|url| url.path().ends_with(".wasm"))
+}
 
-    let source = client
-        .get(url)
-        .send()
-        .context("Failed sending the request.")?
-        .error_for_status()
-        .context("The response was unsuccessful.")?
-        .text()
-        .context("Failed receiving the body as text.")?;
+/// The SHA-256 hash the WebAssembly module of the auto splitter needs to have,
+/// if it's known yet.
+pub fn module_hash(auto_splitter: &AutoSplitter, folder: &Path) -> Option<String> {
+    if let Some(hash) = &auto_splitter.sha256 {
+        return Some(hash.to_ascii_lowercase());
+    }
+    let url = auto_splitter
+        .urls
+        .urls
+        .iter()
+        .find(|url| is_module_url(url))?;
+    load_pins(folder).remove(url)
+}
 
-    Ok(List {
-        inner: de::from_str(&source).context("Failed parsing the list.")?,
-        source,
-    })
+fn load_pins(folder: &Path) -> BTreeMap<String, String> {
+    fs::read(folder.join(LOCK_FILE_NAME))
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .unwrap_or_default()
 }
 
-fn get_list_from_file(folder: &Path) -> Result<List> {
-    let source =
-        fs::read_to_string(folder.join(LIST_FILE_NAME)).context("Failed reading the file.")?;
+fn save_pins(folder: &Path, pins: &BTreeMap<String, String>) -> Result<()> {
+    let json = serde_json::to_vec_pretty(pins)?;
+    fs::write(folder.join(LOCK_FILE_NAME), json)?;
+    Ok(())
+}
 
-    Ok(List {
-        inner: de::from_str(&source).context("Failed parsing the list.")?,
-        source,
-    })
+fn load_downloads(folder: &Path) -> BTreeMap<String, Download> {
+    fs::read(folder.join(DOWNLOADS_FILE_NAME))
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_downloads(folder: &Path, downloads: &BTreeMap<String, Download>) -> Result<()> {
+    let json = serde_json::to_vec_pretty(downloads)?;
+    fs::write(folder.join(DOWNLOADS_FILE_NAME), json)?;
+    Ok(())
+}
+
+impl AutoSplitter {
+    /// The name of the game the auto splitter is primarily listed for.
+    pub fn game(&self) -> Option<&str> {
+        self.games.games.first().map(String::as_str)
+    }
+
+    pub fn is_using_auto_splitting_runtime(&self) -> bool {
+        self.script_type
+            .as_ref()
+            .is_some_and(|t| t == "AutoSplittingRuntime")
+    }
+}
+
+fn parse_list(source: &str) -> Result<ListInner> {
+    de::from_str(source).context("Failed parsing the list.")
 }
 
 pub fn set_up() {
@@ -256,27 +819,354 @@ pub fn set_up() {
         error!("{:?}", e);
     }
 
-    match get_downloader().download_list(get_module_config_path()) {
-        Ok(list) => {
-            if let Err(e) = list
-                .save_to_disk(auto_splitters_path)
-                .context("Failed saving the list of auto splitters.")
-            {
-                error!("{:?}", e);
-            }
+    refresh_list();
 
-            let _ = LIST.set(list);
+    let (sender, receiver) = mpsc::channel();
+    let thread = thread::spawn(move || loop {
+        match receiver.recv_timeout(LIST_REFRESH_INTERVAL) {
+            Ok(()) | Err(RecvTimeoutError::Timeout) => refresh_list(),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    });
+    *REFRESH_THREAD.lock().unwrap() = Some((sender, thread));
+}
+
+/// Stops refreshing the list and waits for a refresh that is still in
+/// progress, so the thread is gone before the module is unloaded.
+pub fn shut_down() {
+    let Some((sender, thread)) = REFRESH_THREAD.lock().unwrap().take() else {
+        return;
+    };
+    drop(sender);
+    let _ = thread.join();
+}
+
+/// Refreshes the list in the background. The sources notice once the list got
+/// replaced.
+pub fn request_list_refresh() {
+    if let Some((sender, _)) = &*REFRESH_THREAD.lock().unwrap() {
+        let _ = sender.send(());
+    }
+}
+
+/// Loads the lists of auto splitters again and replaces the one that is
+/// currently loaded. Lists that can't be downloaded are loaded from the cache
+/// instead.
+fn refresh_list() {
+    match get_downloader().download_list(&get_list_sources(), get_path()) {
+        Ok(list) => {
+            *LIST.lock().unwrap() = Some(Arc::new(list));
             info!("Auto splitter list loaded.");
         }
-        Err([from_github, from_file]) => {
-            error!(
-                "{:?}",
-                from_github.context("Failed downloading the list of auto splitters.")
-            );
-            error!(
-                "{:?}",
-                from_file.context("Failed loading the list of auto splitters from the cache.")
-            );
+        Err(e) => error!(
+            "{:?}",
+            e.context("Failed loading the list of auto splitters.")
+        ),
+    }
+}
+
+// The downloads are tested against a tiny HTTP server that serves a single
+// file and honors `If-None-Match`, so no network access is needed.
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        path::PathBuf,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use reqwest::blocking::Client;
+
+    use super::{
+        load_downloads, load_pins, normalize, save_pins, similarity, Downloader, MIN_SIMILARITY,
+    };
+
+    const BODY: &[u8] = b"\0asm fake auto splitter";
+    const ETAG: &str = "\"v1\"";
+
+    /// Serves the body until it handled the given number of requests and
+    /// counts how often it actually sent the body.
+    fn serve(requests: usize) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/auto%20splitter.wasm",
+            listener.local_addr().unwrap()
+        );
+        let bodies_sent = Arc::new(AtomicUsize::new(0));
+        let counter = bodies_sent.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut is_cached = false;
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    let line = line.to_ascii_lowercase();
+                    is_cached |= line.starts_with("if-none-match:") && line.contains("v1");
+                }
+
+                if is_cached {
+                    write!(
+                        stream,
+                        "HTTP/1.1 304 Not Modified\r\nConnection: close\r\n\r\n"
+                    )
+                    .unwrap();
+                } else {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nETag: {ETAG}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                        BODY.len(),
+                    )
+                    .unwrap();
+                    stream.write_all(BODY).unwrap();
+                }
+            }
+        });
+
+        (url, bodies_sent)
+    }
+
+    fn folder(name: &str) -> PathBuf {
+        let folder = env::temp_dir().join(format!("obs-livesplit-one-{name}"));
+        let _ = fs::remove_dir_all(&folder);
+        fs::create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    fn downloader() -> Downloader {
+        // The stand-in only speaks HTTP/1.1 and must not be reached through
+        // a proxy.
+        Downloader {
+            client: Client::builder().no_proxy().build().unwrap(),
         }
     }
+
+    #[test]
+    fn reuses_unchanged_downloads() {
+        let folder = folder("reuses-unchanged-downloads");
+        let (url, bodies_sent) = serve(2);
+        let downloader = downloader();
+        let path = folder.join("auto splitter.wasm");
+
+        let mut file_paths = Vec::new();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+        assert_eq!(file_paths, [path.clone()]);
+        assert_eq!(fs::read(&path).unwrap(), BODY);
+
+        let download = &load_downloads(&folder)[&url];
+        assert_eq!(download.etag.as_deref(), Some(ETAG));
+        assert_eq!(download.sha256, super::sha256(BODY));
+
+        file_paths.clear();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+        assert_eq!(file_paths, [path]);
+        assert_eq!(bodies_sent.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn uses_cached_downloads_while_offline() {
+        let folder = folder("uses-cached-downloads-while-offline");
+        let (url, _) = serve(1);
+        let downloader = downloader();
+
+        let mut file_paths = Vec::new();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+
+        // The server is gone after the first request.
+        file_paths.clear();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+        assert_eq!(file_paths, [folder.join("auto splitter.wasm")]);
+    }
+
+    #[test]
+    fn downloads_again_if_the_cached_file_changed() {
+        let folder = folder("downloads-again-if-the-cached-file-changed");
+        let (url, bodies_sent) = serve(2);
+        let downloader = downloader();
+
+        let mut file_paths = Vec::new();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+        fs::write(&file_paths[0], b"tampered").unwrap();
+
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+        assert_eq!(fs::read(&file_paths[0]).unwrap(), BODY);
+        assert_eq!(bodies_sent.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn refuses_modules_with_unexpected_hashes() {
+        let folder = folder("refuses-modules-with-unexpected-hashes");
+        let (url, _) = serve(2);
+        let downloader = downloader();
+
+        let mut file_paths = Vec::new();
+        let unexpected = "0".repeat(64);
+        assert!(downloader
+            .download_file(
+                &url,
+                Some(&unexpected),
+                &folder,
+                &mut file_paths,
+                &mut Vec::new()
+            )
+            .is_err());
+        assert!(file_paths.is_empty());
+        assert!(!folder.join("auto splitter.wasm").exists());
+
+        downloader
+            .download_file(
+                &url,
+                Some(&super::sha256(BODY)),
+                &folder,
+                &mut file_paths,
+                &mut Vec::new(),
+            )
+            .unwrap();
+        assert_eq!(load_pins(&folder)[&url], super::sha256(BODY));
+    }
+
+    #[test]
+    fn refuses_modules_that_differ_from_the_pinned_version() {
+        let folder = folder("refuses-modules-that-differ-from-the-pinned-version");
+        let (url, _) = serve(3);
+        let downloader = downloader();
+
+        let mut file_paths = Vec::new();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+
+        // Pretend the pinned version is a different one than what the server
+        // is serving now.
+        let pinned = "0".repeat(64);
+        save_pins(&folder, &[(url.clone(), pinned.clone())].into()).unwrap();
+
+        file_paths.clear();
+        let mut pin_mismatches = Vec::new();
+        assert!(downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut pin_mismatches)
+            .is_err());
+        assert!(file_paths.is_empty());
+        assert_eq!(load_pins(&folder)[&url], pinned);
+
+        // Once the user accepts the new version, it gets used.
+        assert_eq!(pin_mismatches.len(), 1);
+        assert_eq!(pin_mismatches[0].sha256, super::sha256(BODY));
+        pin_mismatches[0].accept(&folder).unwrap();
+        downloader
+            .download_file(&url, None, &folder, &mut file_paths, &mut Vec::new())
+            .unwrap();
+        assert_eq!(file_paths, [folder.join("auto splitter.wasm")]);
+    }
+
+    #[test]
+    fn normalizes_names() {
+        assert_eq!(
+            normalize("  The Legend of Zelda: Ocarina of Time "),
+            "the legend of zelda ocarina of time"
+        );
+        assert_eq!(normalize("Banjo-Kazooie"), "banjo kazooie");
+        assert_eq!(normalize("Ratchet & Clank"), "ratchet and clank");
+        assert_eq!(normalize("ÖKAMI HD"), "ökami hd");
+        assert_eq!(normalize("?!"), "");
+    }
+
+    #[test]
+    fn rates_the_similarity_of_names() {
+        assert_eq!(similarity("celeste", "celeste"), 1.0);
+        assert_eq!(similarity("", "celeste"), 0.0);
+
+        // A single typo is tolerated.
+        let typo = similarity("super mario odysey", "super mario odyssey");
+        assert!((MIN_SIMILARITY..1.0).contains(&typo), "{typo}");
+
+        // Sharing most of the words is enough, even if the order differs.
+        let words = similarity("hollow knight silksong", "silksong hollow knight");
+        assert!((MIN_SIMILARITY..1.0).contains(&words), "{words}");
+
+        // Names that differ are never certain matches.
+        assert!(similarity("portal", "portal 2") < 1.0);
+        assert!(similarity("portal", "celeste") < MIN_SIMILARITY);
+    }
+
+    fn list(description: &str, games: &[&str]) -> String {
+        let games = games
+            .iter()
+            .map(|game| format!("<Game>{game}</Game>"))
+            .collect::<String>();
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<AutoSplitters>
+  <AutoSplitter>
+    <Games>{games}</Games>
+    <URLs><URL>https://example.com/auto_splitter.wasm</URL></URLs>
+    <Type>Script</Type>
+    <ScriptType>AutoSplittingRuntime</ScriptType>
+    <Description>{description}</Description>
+  </AutoSplitter>
+</AutoSplitters>"
+        )
+    }
+
+    #[test]
+    fn merges_lists_with_later_ones_taking_precedence() {
+        let folder = folder("merges-lists-with-later-ones-taking-precedence");
+        let official = folder.join("official.xml");
+        fs::write(&official, list("Official", &["Foo", "Bar"])).unwrap();
+        let parts = folder.join("parts");
+        fs::create_dir_all(&parts).unwrap();
+        fs::write(parts.join("a.xml"), list("Part A", &["Bar"])).unwrap();
+        fs::write(parts.join("b.xml"), list("Part B", &["Bar"])).unwrap();
+
+        // The first location of the official list is unreachable, the second
+        // list doesn't exist at all.
+        let sources = format!(
+            "http://127.0.0.1:1/list.xml | {}\n# Comment\n{}\n{}",
+            official.display(),
+            folder.join("missing.xml").display(),
+            parts.display(),
+        );
+        let list = downloader().download_list(&sources, &folder).unwrap();
+
+        assert_eq!(list.get_for_game("Foo").unwrap().description, "Official");
+        assert_eq!(list.get_for_game("Bar").unwrap().description, "Part B");
+    }
+
+    #[test]
+    fn only_uses_certain_matches_without_a_choice() {
+        let folder = folder("only-uses-certain-matches-without-a-choice");
+        let path = folder.join("list.xml");
+        fs::write(&path, list("Celeste", &["Celeste"])).unwrap();
+        let list = downloader()
+            .download_list(&path.display().to_string(), &folder)
+            .unwrap();
+
+        assert!(list.find("Celeste", None).is_some());
+        assert!(list.find("CELESTE!", None).is_some());
+        assert!(list.find("Celest", None).is_none());
+        assert!(list.find("Celest", Some("Celeste")).is_some());
+        assert_eq!(list.candidates_for_game("Celest").len(), 1);
+    }
 }
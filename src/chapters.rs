@@ -0,0 +1,308 @@
+use std::{
+    ffi::{c_void, CStr, CString},
+    fmt::Write,
+    fs,
+    os::raw::c_char,
+    path::{Path, PathBuf},
+    ptr,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use livesplit_core::{
+    event::Event,
+    timing::formatter::{Regular, TimeFormatter},
+};
+use log::warn;
+
+use crate::{
+    event_worker::EventHandler,
+    ffi::{
+        calldata_init_fixed, calldata_set_data, obs_data_get_bool, obs_data_get_string,
+        obs_data_set_default_bool, obs_data_t, obs_frontend_add_event_callback, obs_frontend_event,
+        obs_frontend_get_recording_output, obs_frontend_remove_event_callback,
+        obs_output_get_proc_handler, obs_output_release, obs_properties_add_bool,
+        obs_properties_add_group, obs_properties_add_path, obs_properties_create, obs_properties_t,
+        proc_handler_call, OBS_FRONTEND_EVENT_RECORDING_STARTED,
+        OBS_FRONTEND_EVENT_RECORDING_STOPPED, OBS_GROUP_CHECKABLE, OBS_PATH_DIRECTORY,
+    },
+    localization::{lang, Text},
+    timer_events::TimerEvent,
+    InnerTimer,
+};
+
+const SETTINGS_CHAPTERS: *const c_char = cstr!(c"chapters");
+const SETTINGS_CHAPTER_MARKERS: *const c_char = cstr!(c"chapter_markers");
+const SETTINGS_CHAPTERS_DIRECTORY: *const c_char = cstr!(c"chapters_directory");
+
+/// Marks the splits of an attempt as chapters, according to the options of a
+/// source. They are added to the recording, if the output supports chapters,
+/// and written to a file in the FFmpeg metadata format, so they can be added
+/// to any video afterwards. The chapters are relative to the start of the
+/// recording, so they are only added while OBS is recording.
+pub struct Chapters {
+    options: ChapterOptions,
+    attempt: Attempt,
+}
+
+#[derive(Clone)]
+pub struct ChapterOptions {
+    add_markers: bool,
+    directory: Option<PathBuf>,
+}
+
+struct Chapter {
+    /// The index of the split that ended the chapter.
+    split_index: usize,
+    title: String,
+    start: Duration,
+    end: Duration,
+}
+
+/// The chapters of the attempt that is currently in progress.
+#[derive(Default)]
+struct Attempt {
+    number: u32,
+    start: Option<SystemTime>,
+    /// When the recording that the chapters are part of started.
+    recording_start: Option<SystemTime>,
+    chapters: Vec<Chapter>,
+}
+
+/// When the recording that is currently in progress started.
+static RECORDING_START: Mutex<Option<SystemTime>> = Mutex::new(None);
+
+pub unsafe fn register() {
+    unsafe { obs_frontend_add_event_callback(Some(on_frontend_event), ptr::null_mut()) }
+}
+
+pub unsafe fn unregister() {
+    unsafe { obs_frontend_remove_event_callback(Some(on_frontend_event), ptr::null_mut()) }
+}
+
+unsafe extern "C" fn on_frontend_event(event: obs_frontend_event, _: *mut c_void) {
+    match event {
+        OBS_FRONTEND_EVENT_RECORDING_STARTED => {
+            *RECORDING_START.lock().unwrap() = Some(SystemTime::now());
+        }
+        OBS_FRONTEND_EVENT_RECORDING_STOPPED => *RECORDING_START.lock().unwrap() = None,
+        _ => {}
+    }
+}
+
+impl EventHandler for Chapters {
+    type Options = ChapterOptions;
+
+    fn new(options: ChapterOptions, _: &Arc<InnerTimer>) -> Self {
+        Self {
+            options,
+            attempt: Attempt::default(),
+        }
+    }
+
+    fn set_options(&mut self, options: ChapterOptions) {
+        self.options = options;
+    }
+
+    fn handle_event(&mut self, event: &TimerEvent) {
+        let (options, attempt) = (&self.options, &mut self.attempt);
+
+        match event.event {
+            Event::Started => {
+                *attempt = Attempt {
+                    number: event.attempt,
+                    start: Some(event.time),
+                    recording_start: None,
+                    chapters: Vec::new(),
+                };
+            }
+            Event::Splitted | Event::Finished => {
+                let (Some(start), Some(split)) = (attempt.start, &event.split) else {
+                    return;
+                };
+                let Some(recording_start) = *RECORDING_START.lock().unwrap() else {
+                    return;
+                };
+
+                // The chapters of a previous recording don't fit this one.
+                if attempt.recording_start != Some(recording_start) {
+                    attempt.recording_start = Some(recording_start);
+                    attempt.chapters.clear();
+                }
+
+                // The first chapter starts with the attempt, unless the
+                // recording started later than that.
+                let chapter_start = attempt.chapters.last().map_or_else(
+                    || start.duration_since(recording_start).unwrap_or_default(),
+                    |chapter| chapter.end,
+                );
+                let end = event
+                    .time
+                    .duration_since(recording_start)
+                    .unwrap_or_default();
+                attempt.chapters.push(Chapter {
+                    split_index: split.index,
+                    title: split.name.clone(),
+                    start: chapter_start,
+                    end,
+                });
+
+                if options.add_markers {
+                    let time = Regular::new().format(split.split_time);
+                    unsafe { add_marker(&format!("{} {time}", split.name)) };
+                }
+                if let Some(directory) = &options.directory {
+                    attempt.save(directory);
+                }
+            }
+            Event::SplitUndone => {
+                // The split may have happened before the current recording
+                // started or may have been skipped, so it has no chapter to
+                // remove.
+                let Some(split) = &event.split else {
+                    return;
+                };
+                if attempt.recording_start != *RECORDING_START.lock().unwrap()
+                    || attempt
+                        .chapters
+                        .last()
+                        .is_none_or(|chapter| chapter.split_index != split.index)
+                {
+                    return;
+                }
+                attempt.chapters.pop();
+                if let Some(directory) = &options.directory {
+                    attempt.save(directory);
+                }
+            }
+            Event::Reset => *attempt = Attempt::default(),
+            _ => {}
+        }
+    }
+}
+
+impl Attempt {
+    // The file gets rewritten on every split, so nothing is lost if OBS
+    // closes in the middle of an attempt.
+    fn save(&self, directory: &Path) {
+        if self.start.is_none() {
+            return;
+        }
+
+        let mut metadata = String::from(";FFMETADATA1\n");
+        for chapter in &self.chapters {
+            let _ = write!(
+                metadata,
+                "\n[CHAPTER]\nTIMEBASE=1/1000\nSTART={}\nEND={}\ntitle={}\n",
+                chapter.start.as_millis(),
+                chapter.end.as_millis(),
+                escape(&chapter.title),
+            );
+        }
+
+        let path = directory.join(format!("attempt_{}.ffmetadata", self.number));
+        if let Err(e) = fs::create_dir_all(directory).and_then(|_| fs::write(&path, metadata)) {
+            warn!("Couldn't write the chapters to `{}`: {e}", path.display());
+        }
+    }
+}
+
+/// Escapes the characters that have a special meaning in FFmpeg metadata.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '=' | ';' | '#' | '\\' | '\n') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Adds a chapter marker to the recording that is currently in progress. Only
+/// some outputs, such as the hybrid MP4 output, support this. Every other
+/// output simply doesn't know the procedure.
+unsafe fn add_marker(name: &str) {
+    unsafe {
+        let Ok(name) = CString::new(name) else {
+            return;
+        };
+
+        let output = obs_frontend_get_recording_output();
+        if output.is_null() {
+            return;
+        }
+
+        let mut stack = [0u8; 512];
+        let mut data = calldata_init_fixed(&mut stack);
+        // Strings are stored including their nul terminator.
+        let bytes = name.as_bytes_with_nul();
+        calldata_set_data(
+            &mut data,
+            cstr!(c"chapter_name"),
+            bytes.as_ptr().cast(),
+            bytes.len(),
+        );
+        proc_handler_call(
+            obs_output_get_proc_handler(output),
+            cstr!(c"add_chapter"),
+            &mut data,
+        );
+
+        obs_output_release(output);
+    }
+}
+
+impl ChapterOptions {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Option<Self> {
+        unsafe {
+            if !obs_data_get_bool(settings, SETTINGS_CHAPTERS) {
+                return None;
+            }
+
+            let directory =
+                CStr::from_ptr(obs_data_get_string(settings, SETTINGS_CHAPTERS_DIRECTORY).cast());
+
+            Some(Self {
+                add_markers: obs_data_get_bool(settings, SETTINGS_CHAPTER_MARKERS),
+                directory: (!directory.is_empty())
+                    .then(|| PathBuf::from(directory.to_string_lossy().into_owned())),
+            })
+        }
+    }
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        obs_properties_add_bool(
+            group,
+            SETTINGS_CHAPTER_MARKERS,
+            Text::PropertyChapterMarkers.resolve(lang),
+        );
+        obs_properties_add_path(
+            group,
+            SETTINGS_CHAPTERS_DIRECTORY,
+            Text::PropertyChaptersDirectory.resolve(lang),
+            OBS_PATH_DIRECTORY,
+            std::ptr::null(),
+            std::ptr::null(),
+        );
+        obs_properties_add_group(
+            props,
+            SETTINGS_CHAPTERS,
+            Text::PropertyChapters.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_bool(settings, SETTINGS_CHAPTERS, false);
+        obs_data_set_default_bool(settings, SETTINGS_CHAPTER_MARKERS, true);
+    }
+}
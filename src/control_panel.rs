@@ -0,0 +1,554 @@
+use std::{
+    ffi::{c_void, CStr, CString},
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    os::raw::c_char,
+    ptr,
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc, Mutex, Weak,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use livesplit_core::{
+    event::{CommandSink, TimerQuery},
+    timing::formatter::{Delta, Regular, TimeFormatter},
+    Timer, TimerPhase, TimingMethod,
+};
+use log::{debug, info, warn};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    ffi::{
+        obs_data_get_bool, obs_data_get_int, obs_data_get_string, obs_data_set_default_bool,
+        obs_data_set_default_int, obs_data_set_string, obs_data_t, obs_properties_add_bool,
+        obs_properties_add_group, obs_properties_add_int, obs_properties_add_text,
+        obs_properties_create, obs_properties_get, obs_properties_t, obs_property_set_description,
+        obs_property_set_modified_callback2, obs_property_t, OBS_GROUP_CHECKABLE, OBS_TEXT_DEFAULT,
+        OBS_TEXT_INFO,
+    },
+    localization::{lang, Text},
+    GlobalTimer, TIMERS,
+};
+
+const SETTINGS_CONTROL_PANEL: *const c_char = cstr!(c"control_panel");
+const SETTINGS_CONTROL_PANEL_PORT: *const c_char = cstr!(c"control_panel_port");
+const SETTINGS_CONTROL_PANEL_REMOTE: *const c_char = cstr!(c"control_panel_remote");
+const SETTINGS_CONTROL_PANEL_TOKEN: *const c_char = cstr!(c"control_panel_token");
+const SETTINGS_CONTROL_PANEL_ADDRESS: *const c_char = cstr!(c"control_panel_address");
+
+const PAGE: &str = include_str!("control_panel.html");
+
+/// Serves a small web page with buttons for all the commands of the timers
+/// and the splits of the current attempt. It can be added to OBS as a custom
+/// browser dock or opened in the browser of another computer. The page offers
+/// all the timers that are loaded, no matter which source enabled it. It's
+/// only served under a secret token, so that other websites and other users
+/// can't control the timers.
+pub struct ControlPanel {
+    options: ControlPanelOptions,
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ControlPanelOptions {
+    port: u16,
+    allow_remote: bool,
+    token: String,
+}
+
+static PANELS: Mutex<Vec<Weak<ControlPanel>>> = Mutex::new(Vec::new());
+
+impl ControlPanel {
+    /// Starts serving the control panel. Sources that enable it with the same
+    /// options share the server, which runs until the last of them is gone.
+    pub fn get(options: ControlPanelOptions) -> Option<Arc<Self>> {
+        let mut panels = PANELS.lock().unwrap();
+        panels.retain(|panel| panel.strong_count() > 0);
+        if let Some(panel) = panels
+            .iter()
+            .filter_map(|panel| panel.upgrade())
+            .find(|panel| panel.options == options)
+        {
+            return Some(panel);
+        }
+
+        let address = if options.allow_remote {
+            Ipv4Addr::UNSPECIFIED
+        } else {
+            Ipv4Addr::LOCALHOST
+        };
+        let listener = match TcpListener::bind((address, options.port))
+            .and_then(|listener| listener.set_nonblocking(true).map(|_| listener))
+        {
+            Ok(listener) => listener,
+            Err(e) => {
+                warn!(
+                    "Couldn't serve the control panel on port {}: {e}",
+                    options.port
+                );
+                return None;
+            }
+        };
+        // The token is left out, as the log is often shared publicly.
+        info!("Serving the control panel on {address}:{}.", options.port);
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread = thread::spawn({
+            let (stopped, options) = (stopped.clone(), options.clone());
+            move || serve(listener, &options, &stopped)
+        });
+        let panel = Arc::new(Self {
+            options,
+            stopped,
+            thread: Some(thread),
+        });
+        panels.push(Arc::downgrade(&panel));
+        Some(panel)
+    }
+
+    /// Switches the source's control panel over to the new options. The
+    /// previous panel is stopped first, so its port is free again if no
+    /// other source uses it.
+    pub fn update(panel: &mut Option<Arc<Self>>, options: Option<ControlPanelOptions>) {
+        if panel.as_ref().map(|panel| &panel.options) == options.as_ref() {
+            return;
+        }
+        *panel = None;
+        *panel = options.and_then(Self::get);
+    }
+}
+
+impl Drop for ControlPanel {
+    fn drop(&mut self) {
+        self.stopped.store(true, atomic::Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+// Polls for connections until the last source that uses the control panel is
+// gone. Each connection is handled on its own thread, so a slow client
+// doesn't hold up the others.
+fn serve(listener: TcpListener, options: &ControlPanelOptions, stopped: &AtomicBool) {
+    while !stopped.load(atomic::Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let options = options.clone();
+                thread::spawn(move || {
+                    if let Err(e) = handle(stream, &options) {
+                        debug!("Control panel request failed: {e}");
+                    }
+                });
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(50));
+            }
+            Err(e) => {
+                warn!("Control panel stopped: {e}");
+                return;
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct StateRequest {
+    timer: String,
+}
+
+#[derive(Deserialize)]
+struct CommandRequest {
+    timer: String,
+    command: String,
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct TimerState {
+    phase: &'static str,
+    current_time: String,
+    current_split_index: Option<usize>,
+    comparison: String,
+    comparisons: Vec<String>,
+    timing_method: &'static str,
+    splits: Vec<SplitState>,
+}
+
+#[derive(Serialize)]
+struct SplitState {
+    name: String,
+    /// The split time of the current attempt, or the comparison's if the
+    /// segment hasn't been split yet.
+    time: String,
+    delta: String,
+}
+
+fn handle(stream: TcpStream, options: &ControlPanelOptions) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    let (mut content_length, mut is_json) = (0, false);
+    let (mut host, mut origin) = (None, None);
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("content-type") {
+                is_json = value.starts_with("application/json");
+            } else if name.eq_ignore_ascii_case("host") {
+                host = Some(value.to_owned());
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.to_owned());
+            }
+        }
+    }
+    let mut body = vec![0; content_length.min(64 * 1024)];
+    reader.read_exact(&mut body)?;
+
+    let mut stream = reader.into_inner();
+
+    if !is_allowed(options, host.as_deref(), origin.as_deref()) {
+        return respond(&mut stream, "403 Forbidden", "text/plain", b"");
+    }
+
+    // Other websites can't send JSON to the control panel without asking for
+    // permission first, which it never grants.
+    if method == "POST" && !is_json {
+        return respond(&mut stream, "415 Unsupported Media Type", "text/plain", b"");
+    }
+
+    // Everything is served below the token, so the page can refer to the
+    // rest with relative paths.
+    let Some(path) = path
+        .strip_prefix('/')
+        .and_then(|path| path.strip_prefix(options.token.as_str()))
+    else {
+        return respond(&mut stream, "404 Not Found", "text/plain", b"");
+    };
+
+    match (method, path) {
+        ("GET", "/") => respond(&mut stream, "200 OK", "text/html", PAGE.as_bytes()),
+        ("GET", "/timers") => {
+            let timers = timers()
+                .iter()
+                .map(|timer| timer.timer.path.to_string_lossy().into_owned())
+                .collect::<Vec<_>>();
+            respond_json(&mut stream, &timers)
+        }
+        ("POST", "/state") => {
+            let Some((timer, _)) = parse_timer::<StateRequest>(&body, |r| &r.timer) else {
+                return respond(&mut stream, "404 Not Found", "text/plain", b"");
+            };
+            let state = timer_state(&timer.timer.get_timer());
+            respond_json(&mut stream, &state)
+        }
+        ("POST", "/command") => {
+            let Some((timer, request)) = parse_timer::<CommandRequest>(&body, |r| &r.timer) else {
+                return respond(&mut stream, "404 Not Found", "text/plain", b"");
+            };
+            if execute(&timer, &request.command, &request.value) {
+                respond(&mut stream, "204 No Content", "text/plain", b"")
+            } else {
+                respond(&mut stream, "400 Bad Request", "text/plain", b"")
+            }
+        }
+        _ => respond(&mut stream, "404 Not Found", "text/plain", b""),
+    }
+}
+
+/// Only the page itself may use the control panel. Without access from other
+/// computers, the host also needs to be the local one, so websites can't get
+/// around this by pointing their own domain at the local address.
+fn is_allowed(options: &ControlPanelOptions, host: Option<&str>, origin: Option<&str>) -> bool {
+    let Some(host) = host else {
+        return false;
+    };
+    if !options.allow_remote {
+        let port = options.port.to_string();
+        let is_local = ["localhost", "127.0.0.1"].into_iter().any(|local| {
+            host.strip_prefix(local).is_some_and(|rest| {
+                rest.is_empty() || rest.strip_prefix(':') == Some(port.as_str())
+            })
+        });
+        if !is_local {
+            return false;
+        }
+    }
+    origin.is_none_or(|origin| origin.strip_prefix("http://") == Some(host))
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}; charset=utf-8\r\n\
+         Content-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        body.len(),
+    )?;
+    stream.write_all(body)
+}
+
+fn respond_json(stream: &mut TcpStream, value: &impl serde::Serialize) -> io::Result<()> {
+    let body = serde_json::to_vec(value).unwrap_or_default();
+    respond(stream, "200 OK", "application/json", &body)
+}
+
+fn timers() -> Vec<Arc<GlobalTimer>> {
+    let mut timers = TIMERS.lock().unwrap();
+    timers.retain(|timer| timer.strong_count() > 0);
+    timers.iter().filter_map(|timer| timer.upgrade()).collect()
+}
+
+/// Parses the request and looks up the timer it refers to by its splits path.
+fn parse_timer<T: serde::de::DeserializeOwned>(
+    body: &[u8],
+    path: impl FnOnce(&T) -> &String,
+) -> Option<(Arc<GlobalTimer>, T)> {
+    let request: T = serde_json::from_slice(body).ok()?;
+    let path = path(&request);
+    let timer = timers()
+        .into_iter()
+        .find(|timer| timer.timer.path.to_string_lossy() == path.as_str())?;
+    Some((timer, request))
+}
+
+fn timer_state(timer: &Timer) -> TimerState {
+    let method = timer.current_timing_method();
+    let comparison = timer.current_comparison();
+    let split_count = match timer.current_phase() {
+        TimerPhase::NotRunning => 0,
+        _ => timer.current_split_index().unwrap_or(timer.run().len()),
+    };
+
+    let splits = timer
+        .run()
+        .segments()
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let comparison_time = segment.comparison(comparison)[method];
+            if index < split_count {
+                let split_time = segment.split_time()[method];
+                let delta = split_time.zip(comparison_time).map(|(a, b)| a - b);
+                SplitState {
+                    name: segment.name().to_owned(),
+                    time: Regular::new().format(split_time).to_string(),
+                    delta: Delta::with_decimal_dropping().format(delta).to_string(),
+                }
+            } else {
+                SplitState {
+                    name: segment.name().to_owned(),
+                    time: Regular::new().format(comparison_time).to_string(),
+                    delta: String::new(),
+                }
+            }
+        })
+        .collect();
+
+    TimerState {
+        phase: match timer.current_phase() {
+            TimerPhase::NotRunning => "not_running",
+            TimerPhase::Running => "running",
+            TimerPhase::Ended => "ended",
+            TimerPhase::Paused => "paused",
+        },
+        current_time: Regular::new()
+            .format(timer.snapshot().current_time()[method])
+            .to_string(),
+        current_split_index: timer.current_split_index(),
+        comparison: comparison.to_owned(),
+        comparisons: timer
+            .run()
+            .comparisons()
+            .map(|comparison| comparison.to_owned())
+            .collect(),
+        timing_method: match method {
+            TimingMethod::RealTime => "real_time",
+            TimingMethod::GameTime => "game_time",
+        },
+        splits,
+    }
+}
+
+/// Executes one of the commands of the page. Returns whether the command
+/// exists.
+fn execute(global_timer: &GlobalTimer, command: &str, value: &str) -> bool {
+    let timer = &global_timer.timer;
+    match command {
+        "start" => drop(timer.start()),
+        "split" => drop(timer.split_or_start()),
+        "undo" => drop(timer.undo_split()),
+        "skip" => drop(timer.skip_split()),
+        "pause" => drop(timer.toggle_pause_or_start()),
+        "undo_all_pauses" => drop(timer.undo_all_pauses()),
+        "reset" => drop(timer.reset(None)),
+        "set_comparison" => drop(timer.set_current_comparison(value.to_owned().into())),
+        "set_timing_method" => drop(timer.set_current_timing_method(match value {
+            "real_time" => TimingMethod::RealTime,
+            "game_time" => TimingMethod::GameTime,
+            _ => return false,
+        })),
+        _ => return false,
+    }
+    true
+}
+
+impl ControlPanelOptions {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Option<Self> {
+        unsafe {
+            if !obs_data_get_bool(settings, SETTINGS_CONTROL_PANEL) {
+                return None;
+            }
+
+            Some(Self {
+                port: obs_data_get_int(settings, SETTINGS_CONTROL_PANEL_PORT)
+                    .try_into()
+                    .ok()?,
+                allow_remote: obs_data_get_bool(settings, SETTINGS_CONTROL_PANEL_REMOTE),
+                token: token(settings)?,
+            })
+        }
+    }
+}
+
+/// Returns the token of the control panel. It's generated the first time the
+/// control panel is enabled and then stays the same, so the address of the
+/// dock keeps working.
+unsafe fn token(settings: *mut obs_data_t) -> Option<String> {
+    unsafe {
+        let token =
+            CStr::from_ptr(obs_data_get_string(settings, SETTINGS_CONTROL_PANEL_TOKEN).cast())
+                .to_string_lossy()
+                .trim()
+                .to_owned();
+        if !token.is_empty() {
+            return Some(token);
+        }
+
+        let token = generate_token()?;
+        if let Ok(value) = CString::new(token.as_str()) {
+            obs_data_set_string(settings, SETTINGS_CONTROL_PANEL_TOKEN, value.as_ptr());
+        }
+        Some(token)
+    }
+}
+
+/// Generates a token from the random number generator of the operating
+/// system, so it can't be guessed.
+fn generate_token() -> Option<String> {
+    let mut bytes = [0; 16];
+    if let Err(e) = getrandom::fill(&mut bytes) {
+        warn!("Couldn't generate a token for the control panel: {e}");
+        return None;
+    }
+    let mut token = String::new();
+    for byte in bytes {
+        let _ = write!(token, "{byte:02x}");
+    }
+    Some(token)
+}
+
+// Shows the address that the control panel can be added as a browser dock
+// with, so it doesn't need to be put together by hand.
+unsafe extern "C" fn update_address(
+    _data: *mut c_void,
+    props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    settings: *mut obs_data_t,
+) -> bool {
+    unsafe {
+        let port = obs_data_get_int(settings, SETTINGS_CONTROL_PANEL_PORT);
+        let address = match obs_data_get_bool(settings, SETTINGS_CONTROL_PANEL)
+            .then(|| token(settings))
+            .flatten()
+        {
+            Some(token) => format!("http://localhost:{port}/{token}/"),
+            None => String::new(),
+        };
+        if let Ok(address) = CString::new(address) {
+            obs_property_set_description(
+                obs_properties_get(props, SETTINGS_CONTROL_PANEL_ADDRESS),
+                address.as_ptr(),
+            );
+        }
+        true
+    }
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        obs_properties_add_int(
+            group,
+            SETTINGS_CONTROL_PANEL_PORT,
+            Text::PropertyPort.resolve(lang),
+            1024,
+            65535,
+            1,
+        );
+        obs_properties_add_bool(
+            group,
+            SETTINGS_CONTROL_PANEL_REMOTE,
+            Text::PropertyAllowOtherComputers.resolve(lang),
+        );
+        obs_properties_add_text(
+            group,
+            SETTINGS_CONTROL_PANEL_TOKEN,
+            Text::PropertyAccessToken.resolve(lang),
+            OBS_TEXT_DEFAULT,
+        );
+        obs_properties_add_text(
+            group,
+            SETTINGS_CONTROL_PANEL_ADDRESS,
+            Text::PropertyControlPanelAddress.resolve(lang),
+            OBS_TEXT_INFO,
+        );
+        let enabled = obs_properties_add_group(
+            props,
+            SETTINGS_CONTROL_PANEL,
+            Text::PropertyControlPanel.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+
+        for prop in [
+            enabled,
+            obs_properties_get(group, SETTINGS_CONTROL_PANEL_PORT),
+            obs_properties_get(group, SETTINGS_CONTROL_PANEL_TOKEN),
+        ] {
+            obs_property_set_modified_callback2(prop, Some(update_address), ptr::null_mut());
+        }
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_bool(settings, SETTINGS_CONTROL_PANEL, false);
+        obs_data_set_default_int(settings, SETTINGS_CONTROL_PANEL_PORT, 16835);
+        obs_data_set_default_bool(settings, SETTINGS_CONTROL_PANEL_REMOTE, false);
+    }
+}
@@ -0,0 +1,211 @@
+use std::os::raw::c_char;
+
+use crate::{
+    ffi::{
+        obs_data_get_bool, obs_data_get_int, obs_data_set_default_int, obs_data_t,
+        obs_properties_add_color_alpha, obs_properties_add_group, obs_properties_add_int,
+        obs_properties_create, obs_properties_t, OBS_GROUP_CHECKABLE,
+    },
+    localization::{lang, Text},
+};
+
+const SETTINGS_DROP_SHADOW: *const c_char = cstr!(c"drop_shadow");
+const SETTINGS_DROP_SHADOW_COLOR: *const c_char = cstr!(c"drop_shadow_color");
+const SETTINGS_DROP_SHADOW_OFFSET_X: *const c_char = cstr!(c"drop_shadow_offset_x");
+const SETTINGS_DROP_SHADOW_OFFSET_Y: *const c_char = cstr!(c"drop_shadow_offset_y");
+const SETTINGS_DROP_SHADOW_BLUR_RADIUS: *const c_char = cstr!(c"drop_shadow_blur_radius");
+
+/// A drop shadow that gets composited underneath the rendered layout. It is
+/// based on the alpha channel of the rendered frame, so it works for any
+/// layout, as long as its background is at least partially transparent.
+pub struct DropShadow {
+    color: [u8; 4],
+    offset_x: i32,
+    offset_y: i32,
+    blur_radius: usize,
+    alpha: Vec<u8>,
+    scratch: Vec<u8>,
+    prefix_sums: Vec<u32>,
+}
+
+impl DropShadow {
+    pub fn new(color: [u8; 4], offset_x: i32, offset_y: i32, blur_radius: usize) -> Self {
+        Self {
+            color,
+            offset_x,
+            offset_y,
+            blur_radius,
+            alpha: Vec::new(),
+            scratch: Vec::new(),
+            prefix_sums: Vec::new(),
+        }
+    }
+
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Option<Self> {
+        unsafe {
+            if !obs_data_get_bool(settings, SETTINGS_DROP_SHADOW) {
+                return None;
+            }
+
+            // OBS stores colors as 0xAABBGGRR.
+            let color =
+                (obs_data_get_int(settings, SETTINGS_DROP_SHADOW_COLOR) as u32).to_le_bytes();
+
+            Some(Self::new(
+                color,
+                obs_data_get_int(settings, SETTINGS_DROP_SHADOW_OFFSET_X) as i32,
+                obs_data_get_int(settings, SETTINGS_DROP_SHADOW_OFFSET_Y) as i32,
+                obs_data_get_int(settings, SETTINGS_DROP_SHADOW_BLUR_RADIUS).max(0) as usize,
+            ))
+        }
+    }
+
+    /// Composites the drop shadow underneath the premultiplied RGBA image in
+    /// `src` and stores the result in `dst`.
+    pub fn apply(&mut self, src: &[u8], dst: &mut Vec<u8>, width: usize, height: usize) {
+        let len = width * height;
+
+        self.alpha.clear();
+        self.alpha.resize(len, 0);
+
+        for y in 0..height {
+            let Some(src_y) = checked_offset(y, self.offset_y, height) else {
+                continue;
+            };
+            for x in 0..width {
+                let Some(src_x) = checked_offset(x, self.offset_x, width) else {
+                    continue;
+                };
+                self.alpha[y * width + x] = src[4 * (src_y * width + src_x) + 3];
+            }
+        }
+
+        if self.blur_radius > 0 {
+            self.scratch.clear();
+            self.scratch.resize(len, 0);
+
+            blur(
+                &self.alpha,
+                &mut self.scratch,
+                &mut self.prefix_sums,
+                height,
+                width,
+                |line, i| line * width + i,
+                self.blur_radius,
+            );
+            blur(
+                &self.scratch,
+                &mut self.alpha,
+                &mut self.prefix_sums,
+                width,
+                height,
+                |line, i| i * width + line,
+                self.blur_radius,
+            );
+        }
+
+        let [r, g, b, a] = self.color.map(u32::from);
+
+        dst.clear();
+        dst.extend_from_slice(&src[..4 * len]);
+
+        for (pixel, &alpha) in dst.chunks_exact_mut(4).zip(&self.alpha) {
+            let shadow_alpha = alpha as u32 * a / 255;
+            let uncovered = 255 - pixel[3] as u32;
+            let coverage = shadow_alpha * uncovered / 255;
+
+            pixel[0] = (pixel[0] as u32 + r * coverage / 255) as u8;
+            pixel[1] = (pixel[1] as u32 + g * coverage / 255) as u8;
+            pixel[2] = (pixel[2] as u32 + b * coverage / 255) as u8;
+            pixel[3] = (pixel[3] as u32 + coverage) as u8;
+        }
+    }
+}
+
+fn checked_offset(pos: usize, offset: i32, len: usize) -> Option<usize> {
+    let pos = pos as i64 - offset as i64;
+    (0..len as i64).contains(&pos).then_some(pos as usize)
+}
+
+// A box blur along a single axis. Pixels outside of the image are treated as
+// fully transparent.
+fn blur(
+    src: &[u8],
+    dst: &mut [u8],
+    prefix_sums: &mut Vec<u32>,
+    line_count: usize,
+    line_len: usize,
+    index: impl Fn(usize, usize) -> usize,
+    radius: usize,
+) {
+    let window = 2 * radius as u32 + 1;
+
+    for line in 0..line_count {
+        prefix_sums.clear();
+        prefix_sums.push(0);
+        let mut sum = 0;
+        for i in 0..line_len {
+            sum += src[index(line, i)] as u32;
+            prefix_sums.push(sum);
+        }
+
+        for i in 0..line_len {
+            let start = i.saturating_sub(radius);
+            let end = (i + radius + 1).min(line_len);
+            dst[index(line, i)] = ((prefix_sums[end] - prefix_sums[start]) / window) as u8;
+        }
+    }
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        obs_properties_add_color_alpha(
+            group,
+            SETTINGS_DROP_SHADOW_COLOR,
+            Text::PropertyColor.resolve(lang),
+        );
+        obs_properties_add_int(
+            group,
+            SETTINGS_DROP_SHADOW_OFFSET_X,
+            Text::PropertyHorizontalOffset.resolve(lang),
+            -100,
+            100,
+            1,
+        );
+        obs_properties_add_int(
+            group,
+            SETTINGS_DROP_SHADOW_OFFSET_Y,
+            Text::PropertyVerticalOffset.resolve(lang),
+            -100,
+            100,
+            1,
+        );
+        obs_properties_add_int(
+            group,
+            SETTINGS_DROP_SHADOW_BLUR_RADIUS,
+            Text::PropertyBlurRadius.resolve(lang),
+            0,
+            50,
+            1,
+        );
+        obs_properties_add_group(
+            props,
+            SETTINGS_DROP_SHADOW,
+            Text::PropertyDropShadow.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_int(settings, SETTINGS_DROP_SHADOW_COLOR, 0xA0000000);
+        obs_data_set_default_int(settings, SETTINGS_DROP_SHADOW_OFFSET_X, 2);
+        obs_data_set_default_int(settings, SETTINGS_DROP_SHADOW_OFFSET_Y, 2);
+        obs_data_set_default_int(settings, SETTINGS_DROP_SHADOW_BLUR_RADIUS, 2);
+    }
+}
@@ -0,0 +1,61 @@
+use std::{
+    sync::{mpsc::Receiver, Arc, Mutex, MutexGuard, Weak},
+    thread,
+};
+
+use crate::{timer_events::TimerEvent, InnerTimer};
+
+/// Something that reacts to the events of a timer with the options of a
+/// single source.
+pub trait EventHandler: Send + 'static {
+    type Options;
+
+    fn new(options: Self::Options, timer: &Arc<InnerTimer>) -> Self;
+    fn set_options(&mut self, options: Self::Options);
+    fn handle_event(&mut self, event: &TimerEvent);
+}
+
+/// Hands the events of a timer to a handler on a separate thread, so OBS is
+/// never called into while the timer is locked. Each source has its own
+/// worker, so sources that use the same splits don't overwrite each other's
+/// options. The thread stops once the worker is dropped.
+pub struct EventWorker<H> {
+    handler: Arc<Mutex<H>>,
+}
+
+impl<H: EventHandler> EventWorker<H> {
+    fn spawn(handler: H, timer: &InnerTimer) -> Self {
+        let handler = Arc::new(Mutex::new(handler));
+        let receiver = timer.events.subscribe();
+        let weak_handler = Arc::downgrade(&handler);
+        thread::spawn(move || run(receiver, weak_handler));
+        Self { handler }
+    }
+
+    /// Starts the worker once the source has options and stops it once it no
+    /// longer has any. The worker needs to be dropped beforehand if the
+    /// source switches to a different timer.
+    pub fn update(worker: &mut Option<Self>, options: Option<H::Options>, timer: &Arc<InnerTimer>) {
+        match (worker.as_ref(), options) {
+            (Some(worker), Some(options)) => worker.handler().set_options(options),
+            (None, Some(options)) => *worker = Some(Self::spawn(H::new(options, timer), timer)),
+            (_, None) => *worker = None,
+        }
+    }
+
+    fn handler(&self) -> MutexGuard<'_, H> {
+        self.handler.lock().unwrap()
+    }
+}
+
+// Runs until either the worker is gone or the timer and with it all the
+// senders of its events are. A worker that is gone is only noticed with the
+// next event.
+fn run<H: EventHandler>(receiver: Receiver<Arc<TimerEvent>>, handler: Weak<Mutex<H>>) {
+    for event in receiver {
+        let Some(handler) = handler.upgrade() else {
+            break;
+        };
+        handler.lock().unwrap().handle_event(&event);
+    }
+}
@@ -10,7 +10,10 @@
 
 use std::{
     ffi::c_void,
+    mem,
     os::raw::{c_char, c_int, c_longlong},
+    ptr,
+    sync::OnceLock,
 };
 
 pub use crate::ffi_types::*;
@@ -68,7 +71,6 @@ unsafe extern "C" {
         description: *const c_char,
     ) -> *mut obs_property_t;
     pub fn obs_data_get_bool(data: *mut obs_data_t, name: *const c_char) -> bool;
-    #[cfg(feature = "auto-splitting")]
     pub fn obs_data_set_default_string(
         data: *mut obs_data_t,
         name: *const c_char,
@@ -76,7 +78,6 @@ unsafe extern "C" {
     );
     #[cfg(feature = "auto-splitting")]
     pub fn obs_data_erase(data: *mut obs_data_t, name: *const c_char);
-    #[cfg(feature = "auto-splitting")]
     pub fn obs_properties_add_group(
         props: *mut obs_properties_t,
         name: *const c_char,
@@ -90,7 +91,6 @@ unsafe extern "C" {
         description: *const c_char,
         text_type: obs_text_type,
     ) -> *mut obs_property_t;
-    #[cfg(feature = "auto-splitting")]
     pub fn obs_properties_add_list(
         props: *mut obs_properties_t,
         name: *const c_char,
@@ -98,6 +98,16 @@ unsafe extern "C" {
         combo_type: obs_combo_type,
         combo_format: obs_combo_format,
     ) -> *mut obs_property_t;
+    pub fn obs_properties_add_color_alpha(
+        props: *mut obs_properties_t,
+        name: *const c_char,
+        description: *const c_char,
+    ) -> *mut obs_property_t;
+    pub fn obs_properties_add_font(
+        props: *mut obs_properties_t,
+        name: *const c_char,
+        description: *const c_char,
+    ) -> *mut obs_property_t;
     pub fn obs_properties_add_editable_list(
         props: *mut obs_properties_t,
         name: *const c_char,
@@ -106,12 +116,12 @@ unsafe extern "C" {
         filter: *const c_char,
         default_path: *const c_char,
     ) -> *mut obs_property_t;
-    #[cfg(feature = "auto-splitting")]
     pub fn obs_property_list_add_string(
         prop: *mut obs_property_t,
         name: *const c_char,
         val: *const c_char,
     ) -> size_t;
+    pub fn obs_property_list_clear(prop: *mut obs_property_t);
     pub fn obs_properties_add_int(
         props: *mut obs_properties_t,
         name: *const c_char,
@@ -121,6 +131,7 @@ unsafe extern "C" {
         step: c_int,
     ) -> *mut obs_property_t;
     pub fn obs_data_get_int(data: *mut obs_data_t, name: *const c_char) -> c_longlong;
+    pub fn obs_data_get_obj(data: *mut obs_data_t, name: *const c_char) -> *mut obs_data_t;
     pub fn gs_texture_destroy(tex: *mut gs_texture_t);
     pub fn gs_draw_sprite(tex: *mut gs_texture_t, flip: u32, width: u32, height: u32);
     pub fn gs_effect_get_param_by_name(
@@ -137,6 +148,25 @@ unsafe extern "C" {
     pub fn gs_technique_end(technique: *mut gs_technique_t);
     pub fn gs_technique_end_pass(technique: *mut gs_technique_t);
     pub fn obs_get_base_effect(effect: obs_base_effect) -> *mut gs_effect_t;
+    pub fn gs_effect_create(
+        effect_string: *const c_char,
+        filename: *const c_char,
+        error_string: *mut *mut c_char,
+    ) -> *mut gs_effect_t;
+    pub fn gs_effect_destroy(effect: *mut gs_effect_t);
+    pub fn gs_effect_set_vec4(param: *mut gs_eparam_t, val: *const vec4);
+    pub fn obs_source_process_filter_begin(
+        filter: *mut obs_source_t,
+        format: gs_color_format,
+        allow_direct: obs_allow_direct_render,
+    ) -> bool;
+    pub fn obs_source_process_filter_end(
+        filter: *mut obs_source_t,
+        effect: *mut gs_effect_t,
+        width: u32,
+        height: u32,
+    );
+    pub fn obs_source_skip_video_filter(filter: *mut obs_source_t);
     pub fn obs_data_set_default_bool(data: *mut obs_data_t, name: *const c_char, val: bool);
     pub fn obs_data_set_default_int(data: *mut obs_data_t, name: *const c_char, val: c_longlong);
     pub fn obs_properties_add_button(
@@ -150,7 +180,6 @@ unsafe extern "C" {
         modified2_callback: obs_property_modified2_t,
         private: *mut c_void,
     );
-    #[cfg(feature = "auto-splitting")]
     pub fn obs_property_set_description(prop: *mut obs_property_t, description: *const c_char);
     #[cfg(feature = "auto-splitting")]
     pub fn obs_property_set_long_description(
@@ -184,4 +213,160 @@ unsafe extern "C" {
     pub fn obs_source_update_properties(source: *mut obs_source_t);
 
     pub fn obs_get_locale() -> *const c_char;
+
+    pub fn obs_get_source_by_name(name: *const c_char) -> *mut obs_source_t;
+    pub fn obs_source_release(source: *mut obs_source_t);
+    pub fn obs_scene_from_source(source: *const obs_source_t) -> *mut obs_scene_t;
+    pub fn obs_scene_find_source_recursive(
+        scene: *mut obs_scene_t,
+        name: *const c_char,
+    ) -> *mut obs_sceneitem_t;
+    pub fn obs_sceneitem_set_visible(item: *mut obs_sceneitem_t, visible: bool) -> bool;
+
+    pub fn obs_source_output_audio(source: *mut obs_source_t, audio: *const obs_source_audio);
+    pub fn os_gettime_ns() -> u64;
+
+    pub fn obs_output_release(output: *mut obs_output_t);
+    pub fn obs_output_get_proc_handler(output: *const obs_output_t) -> *mut proc_handler_t;
+    pub fn proc_handler_call(
+        handler: *mut proc_handler_t,
+        name: *const c_char,
+        params: *mut calldata_t,
+    ) -> bool;
+    pub fn proc_handler_add(
+        handler: *mut proc_handler_t,
+        decl_string: *const c_char,
+        proc_: proc_handler_proc_t,
+        data: *mut c_void,
+    );
+    pub fn obs_source_get_proc_handler(source: *const obs_source_t) -> *mut proc_handler_t;
+    pub fn obs_source_get_signal_handler(source: *const obs_source_t) -> *mut signal_handler_t;
+    pub fn signal_handler_add(handler: *mut signal_handler_t, signal_decl: *const c_char) -> bool;
+    pub fn signal_handler_signal(
+        handler: *mut signal_handler_t,
+        signal: *const c_char,
+        params: *mut calldata_t,
+    );
+    pub fn calldata_get_data(
+        data: *const calldata_t,
+        name: *const c_char,
+        out: *mut c_void,
+        size: size_t,
+    ) -> bool;
+    pub fn calldata_get_string(
+        data: *const calldata_t,
+        name: *const c_char,
+        str_: *mut *const c_char,
+    ) -> bool;
+    pub fn calldata_set_data(
+        data: *mut calldata_t,
+        name: *const c_char,
+        value: *const c_void,
+        size: size_t,
+    );
+
+    pub fn config_get_string(
+        config: *mut config_t,
+        section: *const c_char,
+        name: *const c_char,
+    ) -> *const c_char;
+    pub fn config_set_string(
+        config: *mut config_t,
+        section: *const c_char,
+        name: *const c_char,
+        value: *const c_char,
+    );
+}
+
+/// Sets up call data that stores its values in the given buffer, like the
+/// inline `calldata_init_fixed` from `callback/calldata.h`, which libobs
+/// doesn't export. Such call data never needs to be freed.
+pub fn calldata_init_fixed(stack: &mut [u8]) -> calldata_t {
+    calldata_t {
+        stack: stack.as_mut_ptr(),
+        size: 0,
+        capacity: stack.len(),
+        fixed: true,
+    }
+}
+
+// The frontend API is a separate library that is only there when the plugin
+// is loaded by the OBS application itself, rather than by some other program
+// that uses libobs. So instead of linking against it, its functions are looked
+// up the first time they are called. Without it, they do nothing and return
+// the given default.
+macro_rules! frontend_api {
+    ($(pub fn $name:ident($($arg:ident: $arg_ty:ty),*) $(-> $ret:ty)? = $default:expr;)*) => {
+        $(
+            pub unsafe fn $name($($arg: $arg_ty),*) $(-> $ret)? {
+                type Function = unsafe extern "C" fn($($arg_ty),*) $(-> $ret)?;
+                static FUNCTION: OnceLock<Option<Function>> = OnceLock::new();
+
+                let function = FUNCTION.get_or_init(|| unsafe {
+                    let address =
+                        find_frontend_api_function(concat!(stringify!($name), "\0").as_ptr().cast());
+                    (!address.is_null()).then(|| mem::transmute::<*mut c_void, Function>(address))
+                });
+                match function {
+                    Some(function) => unsafe { function($($arg),*) },
+                    None => $default,
+                }
+            }
+        )*
+    };
+}
+
+frontend_api! {
+    pub fn obs_frontend_get_current_scene() -> *mut obs_source_t = ptr::null_mut();
+    pub fn obs_frontend_set_current_scene(scene: *mut obs_source_t) = ();
+
+    pub fn obs_frontend_recording_start() = ();
+    pub fn obs_frontend_recording_stop() = ();
+    pub fn obs_frontend_recording_active() -> bool = false;
+    pub fn obs_frontend_replay_buffer_save() = ();
+    pub fn obs_frontend_replay_buffer_active() -> bool = false;
+    pub fn obs_frontend_get_profile_config() -> *mut config_t = ptr::null_mut();
+    pub fn obs_frontend_get_recording_output() -> *mut obs_output_t = ptr::null_mut();
+
+    pub fn obs_frontend_add_event_callback(
+        callback: obs_frontend_event_cb,
+        private_data: *mut c_void
+    ) = ();
+    pub fn obs_frontend_remove_event_callback(
+        callback: obs_frontend_event_cb,
+        private_data: *mut c_void
+    ) = ();
+}
+
+// The OBS application itself links against the frontend API, so it's already
+// loaded and its functions can be found among the global symbols.
+#[cfg(unix)]
+unsafe fn find_frontend_api_function(name: *const c_char) -> *mut c_void {
+    unsafe extern "C" {
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+
+    #[cfg(target_os = "macos")]
+    const RTLD_DEFAULT: *mut c_void = -2isize as *mut c_void;
+    #[cfg(not(target_os = "macos"))]
+    const RTLD_DEFAULT: *mut c_void = ptr::null_mut();
+
+    unsafe { dlsym(RTLD_DEFAULT, name) }
+}
+
+#[cfg(windows)]
+unsafe fn find_frontend_api_function(name: *const c_char) -> *mut c_void {
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetModuleHandleA(module_name: *const c_char) -> *mut c_void;
+        fn GetProcAddress(module: *mut c_void, proc_name: *const c_char) -> *mut c_void;
+    }
+
+    unsafe {
+        let module = GetModuleHandleA(cstr!(c"obs-frontend-api.dll"));
+        if module.is_null() {
+            return ptr::null_mut();
+        }
+        GetProcAddress(module, name)
+    }
 }
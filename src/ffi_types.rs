@@ -36,6 +36,19 @@ pub struct gs_texture {
     _unused: [u8; 0],
 }
 
+#[repr(C, align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct vec4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+pub type obs_allow_direct_render = u32;
+pub const OBS_NO_DIRECT_RENDERING: obs_allow_direct_render = 0;
+pub const OBS_ALLOW_DIRECT_RENDERING: obs_allow_direct_render = 1;
+
 pub type obs_base_effect = u32;
 pub const OBS_EFFECT_PREMULTIPLIED_ALPHA: obs_base_effect = 7;
 
@@ -68,6 +81,51 @@ pub const OBS_COMBO_INVALID: obs_group_type = 0;
 pub const OBS_GROUP_NORMAL: obs_group_type = 1;
 pub const OBS_GROUP_CHECKABLE: obs_group_type = 2;
 
+pub const OBS_FONT_BOLD: u32 = 1 << 0;
+pub const OBS_FONT_ITALIC: u32 = 1 << 1;
+pub const OBS_FONT_UNDERLINE: u32 = 1 << 2;
+pub const OBS_FONT_STRIKEOUT: u32 = 1 << 3;
+
+pub type obs_output_t = obs_output;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_output {
+    _unused: [u8; 0],
+}
+
+pub type proc_handler_t = proc_handler;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct proc_handler {
+    _unused: [u8; 0],
+}
+
+pub type proc_handler_proc_t = Option<unsafe extern "C" fn(data: *mut c_void, cd: *mut calldata_t)>;
+
+pub type signal_handler_t = signal_handler;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct signal_handler {
+    _unused: [u8; 0],
+}
+
+pub type calldata_t = calldata;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct calldata {
+    pub stack: *mut u8,
+    pub size: size_t,
+    pub capacity: size_t,
+    pub fixed: bool,
+}
+
+pub type config_t = config_data;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct config_data {
+    _unused: [u8; 0],
+}
+
 pub type obs_data_t = obs_data;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -94,7 +152,10 @@ pub struct obs_hotkey {
     _unused: [u8; 0],
 }
 
+pub const OBS_ICON_TYPE_UNKNOWN: obs_icon_type = 0;
+pub const OBS_ICON_TYPE_AUDIO_OUTPUT: obs_icon_type = 5;
 pub const OBS_ICON_TYPE_GAME_CAPTURE: obs_icon_type = 8;
+pub const OBS_ICON_TYPE_TEXT: obs_icon_type = 10;
 pub type obs_icon_type = u32;
 
 pub type obs_module_t = obs_module;
@@ -114,6 +175,7 @@ pub struct obs_mouse_event {
 
 pub type obs_path_type = u32;
 pub const OBS_PATH_FILE: obs_path_type = 0;
+pub const OBS_PATH_FILE_SAVE: obs_path_type = 1;
 pub const OBS_PATH_DIRECTORY: obs_path_type = 2;
 
 pub type obs_properties_t = obs_properties;
@@ -270,6 +332,7 @@ pub struct obs_source_info {
 
 pub type obs_source_type = u32;
 pub const OBS_SOURCE_TYPE_INPUT: obs_source_type = 0;
+pub const OBS_SOURCE_TYPE_FILTER: obs_source_type = 1;
 
 pub type obs_source_t = obs_source;
 #[repr(C)]
@@ -278,6 +341,20 @@ pub struct obs_source {
     _unused: [u8; 0],
 }
 
+pub type obs_scene_t = obs_scene;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_scene {
+    _unused: [u8; 0],
+}
+
+pub type obs_sceneitem_t = obs_scene_item;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_scene_item {
+    _unused: [u8; 0],
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct obs_source_frame {
@@ -354,4 +431,31 @@ pub const LOG_DEBUG: _bindgen_ty_1 = 400;
 pub const OBS_SOURCE_CUSTOM_DRAW: u32 = 8;
 pub const OBS_SOURCE_INTERACTION: u32 = 32;
 pub const OBS_SOURCE_VIDEO: u32 = 1;
+pub const OBS_SOURCE_AUDIO: u32 = 2;
 pub const OBS_SOURCE_CONTROLLABLE_MEDIA: u32 = 1 << 13;
+
+pub type audio_format = u32;
+pub const AUDIO_FORMAT_FLOAT: audio_format = 4;
+
+pub type speaker_layout = u32;
+pub const SPEAKERS_STEREO: speaker_layout = 2;
+
+pub const MAX_AV_PLANES: usize = 8;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct obs_source_audio {
+    pub data: [*const u8; MAX_AV_PLANES],
+    pub frames: u32,
+    pub speakers: speaker_layout,
+    pub format: audio_format,
+    pub samples_per_sec: u32,
+    pub timestamp: u64,
+}
+
+pub type obs_frontend_event = u32;
+pub const OBS_FRONTEND_EVENT_RECORDING_STARTED: obs_frontend_event = 5;
+pub const OBS_FRONTEND_EVENT_RECORDING_STOPPED: obs_frontend_event = 7;
+
+pub type obs_frontend_event_cb =
+    Option<unsafe extern "C" fn(event: obs_frontend_event, private_data: *mut c_void)>;
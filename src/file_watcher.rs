@@ -0,0 +1,69 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Notices when a file changes by checking its modification time every now
+/// and then. A change is only reported once the file stopped changing, so a
+/// file that is still being written isn't used halfway through. Each file is
+/// watched on its own, so the watcher can be shared by sources that watch
+/// different files.
+#[derive(Default)]
+pub struct FileWatcher {
+    files: HashMap<PathBuf, WatchedFile>,
+}
+
+struct WatchedFile {
+    modified: Option<SystemTime>,
+    pending: Option<SystemTime>,
+    last_check: Instant,
+}
+
+impl FileWatcher {
+    /// Returns whether the file changed since the last time it was checked.
+    /// A file that isn't watched yet starts being watched from now on.
+    pub fn has_changed(&mut self, path: &Path) -> bool {
+        let Some(file) = self.files.get_mut(path) else {
+            self.files.insert(
+                path.to_owned(),
+                WatchedFile {
+                    modified: modified(path),
+                    pending: None,
+                    last_check: Instant::now(),
+                },
+            );
+            return false;
+        };
+
+        if file.last_check.elapsed() < CHECK_INTERVAL {
+            return false;
+        }
+        file.last_check = Instant::now();
+
+        let modified = modified(path);
+        if modified == file.modified {
+            file.pending = None;
+            return false;
+        }
+        if modified != file.pending {
+            file.pending = modified;
+            return false;
+        }
+
+        file.modified = modified;
+        file.pending = None;
+
+        // There's nothing to use while the file is gone.
+        modified.is_some()
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
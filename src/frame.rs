@@ -0,0 +1,263 @@
+use livesplit_core::{
+    layout::LayoutState,
+    rendering::software::Renderer,
+    settings::{Gradient, ImageCache, LayoutBackground},
+    Lang, Layout, Timer,
+};
+
+use crate::drop_shadow::DropShadow;
+
+/// Produces the frames of a layout as premultiplied RGBA buffers. This does not
+/// interact with OBS at all, so the frames can be inspected without a graphics
+/// context.
+pub struct FrameRenderer {
+    state: LayoutState,
+    image_cache: ImageCache,
+    renderer: Renderer,
+    frame: Vec<u8>,
+}
+
+impl FrameRenderer {
+    pub fn new() -> Self {
+        Self {
+            state: LayoutState::default(),
+            image_cache: ImageCache::new(),
+            renderer: Renderer::new(),
+            frame: Vec::new(),
+        }
+    }
+
+    /// Renders the layout for the current state of the timer. The returned
+    /// buffer has a stride of `width * 4` bytes.
+    pub fn render(
+        &mut self,
+        layout: &mut Layout,
+        timer: &Timer,
+        lang: Lang,
+        transparent_background: bool,
+        drop_shadow: Option<&mut DropShadow>,
+        [width, height]: [u32; 2],
+    ) -> &[u8] {
+        layout.update_state(
+            &mut self.state,
+            &mut self.image_cache,
+            &timer.snapshot(),
+            lang,
+        );
+
+        if transparent_background {
+            self.state.background = LayoutBackground::Gradient(Gradient::Transparent);
+        }
+
+        self.renderer
+            .render(&self.state, &self.image_cache, [width, height]);
+
+        self.image_cache.collect();
+
+        match drop_shadow {
+            Some(drop_shadow) => {
+                drop_shadow.apply(
+                    self.renderer.image_data(),
+                    &mut self.frame,
+                    width as usize,
+                    height as usize,
+                );
+                &self.frame
+            }
+            None => self.renderer.image_data(),
+        }
+    }
+}
+
+// The reference images are checked in to `tests/golden`. A missing reference
+// image fails the test rather than being created, so a clean checkout can't
+// pass by accident. Set `UPDATE_GOLDEN_IMAGES=1` to create or regenerate them
+// after an intentional change to the rendering, then review and commit them.
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs::{self, File},
+        io::{BufReader, BufWriter},
+        path::{Path, PathBuf},
+    };
+
+    use livesplit_core::{Lang, Layout, Run, Segment, TimeSpan, Timer, TimingMethod};
+
+    use super::FrameRenderer;
+    use crate::drop_shadow::DropShadow;
+
+    const SIZE: [u32; 2] = [300, 500];
+
+    // The maximum difference of a single channel for two pixels to still be
+    // considered equal. Font rasterization may differ slightly between
+    // platforms.
+    const CHANNEL_TOLERANCE: u8 = 16;
+    const MAX_MISMATCHED_PIXELS: f64 = 0.005;
+
+    // All the times are set as game time, which is frozen, so the frames don't
+    // depend on the wall clock.
+    fn set_game_time(timer: &mut Timer, seconds: f64) {
+        let _ = timer.initialize_game_time();
+        let _ = timer.pause_game_time();
+        timer
+            .set_game_time(TimeSpan::from_seconds(seconds))
+            .unwrap();
+    }
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.set_game_name("Golden Game");
+        run.set_category_name("Any%");
+        for name in ["First", "Second", "Third"] {
+            run.push_segment(Segment::new(name));
+        }
+
+        let mut timer = Timer::new(run).unwrap();
+        timer.set_current_timing_method(TimingMethod::GameTime);
+
+        // Finish an attempt, so there are comparisons to show.
+        timer.start().unwrap();
+        for seconds in [62.5, 131.25, 200.0] {
+            set_game_time(&mut timer, seconds);
+            timer.split().unwrap();
+        }
+        timer.reset(true).unwrap();
+
+        timer
+    }
+
+    fn running_timer() -> Timer {
+        let mut timer = timer();
+        timer.start().unwrap();
+        set_game_time(&mut timer, 58.0);
+        timer.split().unwrap();
+        set_game_time(&mut timer, 140.75);
+        timer
+    }
+
+    fn render(timer: &Timer, transparent_background: bool, drop_shadow: bool) -> Vec<u8> {
+        let mut layout = Layout::default_layout(Lang::English);
+        let mut drop_shadow = drop_shadow.then(|| DropShadow::new([0, 0, 0, 160], 2, 2, 2));
+        FrameRenderer::new()
+            .render(
+                &mut layout,
+                timer,
+                Lang::English,
+                transparent_background,
+                drop_shadow.as_mut(),
+                SIZE,
+            )
+            .to_vec()
+    }
+
+    fn reference_path(name: &str) -> PathBuf {
+        PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"))
+            .join(format!("{name}.png"))
+    }
+
+    // The frames are stored as is, so the PNG files contain premultiplied
+    // alpha.
+    fn write_png(path: &Path, image: &[u8]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(File::create(path).unwrap()),
+            SIZE[0],
+            SIZE[1],
+        );
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(image).unwrap();
+        writer.finish().unwrap();
+    }
+
+    fn read_png(path: &Path) -> Vec<u8> {
+        let mut reader = png::Decoder::new(BufReader::new(File::open(path).unwrap()))
+            .read_info()
+            .unwrap();
+        let mut image = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut image).unwrap();
+        assert_eq!([info.width, info.height], SIZE);
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        image.truncate(info.buffer_size());
+        image
+    }
+
+    fn assert_matches_reference(name: &str, image: &[u8]) {
+        let path = reference_path(name);
+
+        if env::var_os("UPDATE_GOLDEN_IMAGES").is_some() {
+            write_png(&path, image);
+            return;
+        }
+
+        if !path.exists() {
+            let actual_path = path.with_extension("actual.png");
+            write_png(&actual_path, image);
+            panic!(
+                "The reference image `{}` is missing. The rendered frame has been written to `{}`. Run the tests with `UPDATE_GOLDEN_IMAGES=1` to create the reference images, then review and commit them.",
+                path.display(),
+                actual_path.display(),
+            );
+        }
+
+        let reference = read_png(&path);
+        assert_eq!(reference.len(), image.len());
+
+        let mismatched = reference
+            .chunks_exact(4)
+            .zip(image.chunks_exact(4))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > CHANNEL_TOLERANCE)
+            })
+            .count();
+
+        let total = (SIZE[0] * SIZE[1]) as usize;
+        if mismatched as f64 > total as f64 * MAX_MISMATCHED_PIXELS {
+            let actual_path = path.with_extension("actual.png");
+            write_png(&actual_path, image);
+            panic!(
+                "{mismatched} of {total} pixels differ from `{}`. The rendered frame has been written to `{}`.",
+                path.display(),
+                actual_path.display(),
+            );
+        }
+    }
+
+    #[test]
+    fn not_running() {
+        let timer = timer();
+        assert_matches_reference("not_running", &render(&timer, false, false));
+    }
+
+    #[test]
+    fn running() {
+        let timer = running_timer();
+        assert_matches_reference("running", &render(&timer, false, false));
+    }
+
+    #[test]
+    fn paused() {
+        let mut timer = running_timer();
+        timer.pause().unwrap();
+        assert_matches_reference("paused", &render(&timer, false, false));
+    }
+
+    #[test]
+    fn ended() {
+        let mut timer = running_timer();
+        timer.split().unwrap();
+        set_game_time(&mut timer, 195.5);
+        timer.split().unwrap();
+        assert_matches_reference("ended", &render(&timer, false, false));
+    }
+
+    #[test]
+    fn transparent_with_drop_shadow() {
+        let timer = running_timer();
+        assert_matches_reference("transparent_with_drop_shadow", &render(&timer, true, true));
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    ffi::CStr,
+    fs::{self, File},
+    io::{self, BufWriter},
+    os::raw::c_char,
+    path::PathBuf,
+    sync::mpsc::{self, SyncSender, TrySendError},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use livesplit_core::TimerPhase;
+use log::{error, info, warn};
+
+use crate::{
+    ffi::{
+        obs_data_get_bool, obs_data_get_int, obs_data_get_string, obs_data_set_default_bool,
+        obs_data_set_default_int, obs_data_t, obs_properties_add_bool, obs_properties_add_int,
+        obs_properties_add_path, obs_properties_t, OBS_PATH_DIRECTORY,
+    },
+    localization::{lang, Text},
+};
+
+const SETTINGS_EXPORT_DIRECTORY: *const c_char = cstr!(c"export_directory");
+const SETTINGS_EXPORT_IMAGE_SEQUENCE: *const c_char = cstr!(c"export_image_sequence");
+const SETTINGS_EXPORT_FRAMES_PER_SECOND: *const c_char = cstr!(c"export_frames_per_second");
+const MAX_FRAMES_PER_SECOND: i64 = 60;
+
+// Encoding PNGs is too slow to do on the graphics thread, so the frames are
+// handed off to a separate thread. If it can't keep up, frames get dropped.
+const QUEUED_FRAMES: usize = 8;
+
+struct QueuedFrame {
+    path: PathBuf,
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+/// Writes rendered frames to PNG files. Single frames can be saved on demand,
+/// and every attempt can optionally be recorded as an image sequence.
+pub struct FrameExport {
+    directory: PathBuf,
+    image_sequence_interval: Option<Duration>,
+    sequence: Option<ImageSequence>,
+    last_phase: TimerPhase,
+    sender: Option<SyncSender<QueuedFrame>>,
+}
+
+struct ImageSequence {
+    folder: PathBuf,
+    frame_index: u32,
+    last_frame: Instant,
+}
+
+impl FrameExport {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Self {
+        unsafe {
+            let directory =
+                CStr::from_ptr(obs_data_get_string(settings, SETTINGS_EXPORT_DIRECTORY).cast());
+            let directory = PathBuf::from(directory.to_string_lossy().into_owned());
+
+            let image_sequence_interval =
+                obs_data_get_bool(settings, SETTINGS_EXPORT_IMAGE_SEQUENCE).then(|| {
+                    frame_interval(obs_data_get_int(
+                        settings,
+                        SETTINGS_EXPORT_FRAMES_PER_SECOND,
+                    ))
+                });
+
+            Self {
+                directory,
+                image_sequence_interval,
+                sequence: None,
+                last_phase: TimerPhase::NotRunning,
+                sender: None,
+            }
+        }
+    }
+
+    /// Takes over the settings of the other export, while keeping an image
+    /// sequence that is currently being recorded going.
+    pub fn update(&mut self, other: Self) {
+        self.directory = other.directory;
+        self.image_sequence_interval = other.image_sequence_interval;
+    }
+
+    /// Saves a single frame as a PNG file in the export folder.
+    pub fn save_frame(&mut self, frame: &[u8], [width, height]: [u32; 2]) {
+        if self.directory.as_os_str().is_empty() {
+            warn!("Can't save the frame, as no export folder is selected.");
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let path = self.directory.join(format!("livesplit_{timestamp}.png"));
+        info!("Saving frame to {}.", path.display());
+        self.queue(path, frame, width, height);
+    }
+
+    /// Called for every rendered frame. Records the frame if an attempt is in
+    /// progress and the image sequence is enabled.
+    pub fn on_frame(
+        &mut self,
+        frame: &[u8],
+        [width, height]: [u32; 2],
+        phase: TimerPhase,
+        attempt: u32,
+    ) {
+        let last_phase = std::mem::replace(&mut self.last_phase, phase);
+
+        let Some(interval) = self.image_sequence_interval else {
+            return;
+        };
+        if self.directory.as_os_str().is_empty() {
+            return;
+        }
+
+        match phase {
+            TimerPhase::NotRunning => {
+                self.sequence = None;
+                return;
+            }
+            // The final frame of the attempt gets recorded, but after that
+            // the sequence is over.
+            TimerPhase::Ended if last_phase == TimerPhase::Ended => return,
+            _ => {}
+        }
+
+        let sequence = self.sequence.get_or_insert_with(|| {
+            let folder = self.directory.join(format!("attempt_{attempt}"));
+            info!("Recording image sequence to {}.", folder.display());
+            ImageSequence {
+                folder,
+                frame_index: 0,
+                last_frame: Instant::now(),
+            }
+        });
+
+        if sequence.frame_index != 0
+            && phase != TimerPhase::Ended
+            && sequence.last_frame.elapsed() < interval
+        {
+            return;
+        }
+
+        sequence.last_frame = Instant::now();
+        let path = sequence
+            .folder
+            .join(format!("frame_{:06}.png", sequence.frame_index));
+        sequence.frame_index += 1;
+
+        self.queue(path, frame, width, height);
+    }
+
+    fn queue(&mut self, path: PathBuf, frame: &[u8], width: u32, height: u32) {
+        let sender = self.sender.get_or_insert_with(|| {
+            let (sender, receiver) = mpsc::sync_channel::<QueuedFrame>(QUEUED_FRAMES);
+            thread::spawn(move || {
+                for frame in receiver {
+                    if let Err(e) = write_png(&frame) {
+                        error!("Failed saving {}: {e}", frame.path.display());
+                    }
+                }
+            });
+            sender
+        });
+
+        let frame = QueuedFrame {
+            path,
+            width,
+            height,
+            data: frame[..4 * width as usize * height as usize].to_vec(),
+        };
+
+        match sender.try_send(frame) {
+            Ok(()) => {}
+            Err(TrySendError::Full(frame)) => {
+                warn!(
+                    "Dropped frame {}, as saving is too slow.",
+                    frame.path.display()
+                );
+            }
+            Err(TrySendError::Disconnected(_)) => self.sender = None,
+        }
+    }
+}
+
+// The settings may have been edited by hand, so they can be out of range.
+fn frame_interval(fps: i64) -> Duration {
+    Duration::from_secs(1) / fps.clamp(1, MAX_FRAMES_PER_SECOND) as u32
+}
+
+fn write_png(frame: &QueuedFrame) -> io::Result<()> {
+    if let Some(parent) = frame.path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(File::create(&frame.path)?),
+        frame.width,
+        frame.height,
+    );
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&unpremultiply(&frame.data))?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+// The renderer produces premultiplied alpha, but PNG expects straight alpha.
+fn unpremultiply(data: &[u8]) -> Vec<u8> {
+    let mut data = data.to_vec();
+    for pixel in data.chunks_exact_mut(4) {
+        let a = pixel[3] as u32;
+        if a != 0 && a != 255 {
+            for c in &mut pixel[..3] {
+                *c = ((*c as u32 * 255 + a / 2) / a).min(255) as u8;
+            }
+        }
+    }
+    data
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        obs_properties_add_path(
+            props,
+            SETTINGS_EXPORT_DIRECTORY,
+            Text::PropertyExportDirectory.resolve(lang),
+            OBS_PATH_DIRECTORY,
+            std::ptr::null(),
+            std::ptr::null(),
+        );
+        obs_properties_add_bool(
+            props,
+            SETTINGS_EXPORT_IMAGE_SEQUENCE,
+            Text::PropertyExportImageSequence.resolve(lang),
+        );
+        obs_properties_add_int(
+            props,
+            SETTINGS_EXPORT_FRAMES_PER_SECOND,
+            Text::PropertyFramesPerSecond.resolve(lang),
+            1,
+            MAX_FRAMES_PER_SECOND as _,
+            1,
+        );
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_bool(settings, SETTINGS_EXPORT_IMAGE_SEQUENCE, false);
+        obs_data_set_default_int(settings, SETTINGS_EXPORT_FRAMES_PER_SECOND, 30);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        fs::{self, File},
+        io::BufReader,
+        time::Duration,
+    };
+
+    use super::{frame_interval, write_png, QueuedFrame};
+
+    #[test]
+    fn clamps_the_frame_rate() {
+        assert_eq!(frame_interval(0), Duration::from_secs(1));
+        assert_eq!(frame_interval(-5), Duration::from_secs(1));
+        assert_eq!(frame_interval(1 << 32), Duration::from_secs(1) / 60);
+        assert_eq!(frame_interval(30), Duration::from_secs(1) / 30);
+    }
+
+    #[test]
+    fn writes_frames_with_straight_alpha() {
+        let path = env::temp_dir()
+            .join("obs-livesplit-one-frame-export")
+            .join("frame.png");
+        let _ = fs::remove_file(&path);
+
+        write_png(&QueuedFrame {
+            path: path.clone(),
+            width: 2,
+            height: 1,
+            data: vec![255, 0, 0, 255, 64, 32, 0, 128],
+        })
+        .unwrap();
+
+        let mut reader = png::Decoder::new(BufReader::new(File::open(&path).unwrap()))
+            .read_info()
+            .unwrap();
+        let mut image = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut image).unwrap();
+        assert_eq!([info.width, info.height], [2, 1]);
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        assert_eq!(
+            image[..info.buffer_size()],
+            [255, 0, 0, 255, 128, 64, 0, 128]
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::{ffi::CStr, os::raw::c_char};
+
+use livesplit_core::{
+    settings::{Color, Font, FontStretch, FontStyle, FontWeight, Gradient, LayoutBackground},
+    Layout,
+};
+
+use crate::{
+    ffi::{
+        obs_data_get_bool, obs_data_get_int, obs_data_get_obj, obs_data_get_string,
+        obs_data_release, obs_data_set_default_int, obs_data_t, obs_properties_add_color_alpha,
+        obs_properties_add_font, obs_properties_add_group, obs_properties_create, obs_properties_t,
+        OBS_FONT_BOLD, OBS_FONT_ITALIC, OBS_GROUP_CHECKABLE,
+    },
+    localization::{lang, Text},
+};
+
+const SETTINGS_OVERRIDE_BACKGROUND: *const c_char = cstr!(c"override_background");
+const SETTINGS_BACKGROUND_COLOR: *const c_char = cstr!(c"override_background_color");
+const SETTINGS_OVERRIDE_TEXT_COLOR: *const c_char = cstr!(c"override_text_color");
+const SETTINGS_TEXT_COLOR: *const c_char = cstr!(c"override_text_color_value");
+const SETTINGS_OVERRIDE_TIMER_FONT: *const c_char = cstr!(c"override_timer_font");
+const SETTINGS_TIMER_FONT: *const c_char = cstr!(c"override_timer_font_value");
+const SETTINGS_OVERRIDE_TEXT_FONT: *const c_char = cstr!(c"override_text_font");
+const SETTINGS_TEXT_FONT: *const c_char = cstr!(c"override_text_font_value");
+const SETTINGS_OVERRIDE_TIMES_FONT: *const c_char = cstr!(c"override_times_font");
+const SETTINGS_TIMES_FONT: *const c_char = cstr!(c"override_times_font_value");
+
+/// Per source overrides that get applied on top of the layout that is loaded
+/// from the layout file. The layout file itself is never modified.
+pub struct LayoutOverrides {
+    background: Option<Color>,
+    text_color: Option<Color>,
+    timer_font: Option<Font>,
+    text_font: Option<Font>,
+    times_font: Option<Font>,
+}
+
+impl LayoutOverrides {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Self {
+        unsafe {
+            Self {
+                background: obs_data_get_bool(settings, SETTINGS_OVERRIDE_BACKGROUND)
+                    .then(|| parse_color(obs_data_get_int(settings, SETTINGS_BACKGROUND_COLOR))),
+                text_color: obs_data_get_bool(settings, SETTINGS_OVERRIDE_TEXT_COLOR)
+                    .then(|| parse_color(obs_data_get_int(settings, SETTINGS_TEXT_COLOR))),
+                timer_font: obs_data_get_bool(settings, SETTINGS_OVERRIDE_TIMER_FONT)
+                    .then(|| parse_font(settings, SETTINGS_TIMER_FONT))
+                    .flatten(),
+                text_font: obs_data_get_bool(settings, SETTINGS_OVERRIDE_TEXT_FONT)
+                    .then(|| parse_font(settings, SETTINGS_TEXT_FONT))
+                    .flatten(),
+                times_font: obs_data_get_bool(settings, SETTINGS_OVERRIDE_TIMES_FONT)
+                    .then(|| parse_font(settings, SETTINGS_TIMES_FONT))
+                    .flatten(),
+            }
+        }
+    }
+
+    pub fn apply(&self, layout: &mut Layout) {
+        let settings = layout.general_settings_mut();
+
+        if let Some(background) = self.background {
+            settings.background = LayoutBackground::Gradient(Gradient::Plain(background));
+        }
+        if let Some(text_color) = self.text_color {
+            settings.text_color = text_color;
+        }
+        if let Some(timer_font) = &self.timer_font {
+            settings.timer_font = Some(timer_font.clone());
+        }
+        if let Some(text_font) = &self.text_font {
+            settings.text_font = Some(text_font.clone());
+        }
+        if let Some(times_font) = &self.times_font {
+            settings.times_font = Some(times_font.clone());
+        }
+    }
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        obs_properties_add_color_alpha(
+            group,
+            SETTINGS_BACKGROUND_COLOR,
+            Text::PropertyColor.resolve(lang),
+        );
+        obs_properties_add_group(
+            props,
+            SETTINGS_OVERRIDE_BACKGROUND,
+            Text::PropertyOverrideBackground.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+
+        let group = obs_properties_create();
+        obs_properties_add_color_alpha(
+            group,
+            SETTINGS_TEXT_COLOR,
+            Text::PropertyColor.resolve(lang),
+        );
+        obs_properties_add_group(
+            props,
+            SETTINGS_OVERRIDE_TEXT_COLOR,
+            Text::PropertyOverrideTextColor.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+
+        for (toggle, font, description) in [
+            (
+                SETTINGS_OVERRIDE_TIMER_FONT,
+                SETTINGS_TIMER_FONT,
+                Text::PropertyOverrideTimerFont,
+            ),
+            (
+                SETTINGS_OVERRIDE_TEXT_FONT,
+                SETTINGS_TEXT_FONT,
+                Text::PropertyOverrideTextFont,
+            ),
+            (
+                SETTINGS_OVERRIDE_TIMES_FONT,
+                SETTINGS_TIMES_FONT,
+                Text::PropertyOverrideTimesFont,
+            ),
+        ] {
+            let group = obs_properties_create();
+            obs_properties_add_font(group, font, Text::PropertyFont.resolve(lang));
+            obs_properties_add_group(
+                props,
+                toggle,
+                description.resolve(lang),
+                OBS_GROUP_CHECKABLE,
+                group,
+            );
+        }
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_int(settings, SETTINGS_BACKGROUND_COLOR, 0xFF0F0F0F);
+        obs_data_set_default_int(settings, SETTINGS_TEXT_COLOR, 0xFFFFFFFF);
+    }
+}
+
+// OBS stores colors as 0xAABBGGRR.
+pub fn parse_color(value: i64) -> Color {
+    let [r, g, b, a] = (value as u32).to_le_bytes();
+    Color::rgba(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        a as f32 / 255.0,
+    )
+}
+
+// The size of the font is ignored, as the layout determines the size of the
+// text on its own.
+pub unsafe fn parse_font(settings: *mut obs_data_t, key: *const c_char) -> Option<Font> {
+    unsafe {
+        let obj = obs_data_get_obj(settings, key);
+        if obj.is_null() {
+            return None;
+        }
+
+        let family = CStr::from_ptr(obs_data_get_string(obj, cstr!(c"face")))
+            .to_string_lossy()
+            .into_owned();
+        let style = CStr::from_ptr(obs_data_get_string(obj, cstr!(c"style")))
+            .to_string_lossy()
+            .to_ascii_lowercase();
+        let flags = obs_data_get_int(obj, cstr!(c"flags")) as u32;
+
+        obs_data_release(obj);
+
+        if family.is_empty() {
+            return None;
+        }
+
+        let style = style.replace(['-', ' '], "");
+
+        let weight = if flags & OBS_FONT_BOLD != 0 {
+            FontWeight::Bold
+        } else if style.contains("thin") || style.contains("hairline") {
+            FontWeight::Thin
+        } else if style.contains("extralight") || style.contains("ultralight") {
+            FontWeight::ExtraLight
+        } else if style.contains("semilight") {
+            FontWeight::SemiLight
+        } else if style.contains("light") {
+            FontWeight::Light
+        } else if style.contains("medium") {
+            FontWeight::Medium
+        } else if style.contains("semibold") || style.contains("demibold") {
+            FontWeight::SemiBold
+        } else if style.contains("extrabold") || style.contains("ultrabold") {
+            FontWeight::ExtraBold
+        } else if style.contains("bold") {
+            FontWeight::Bold
+        } else if style.contains("extrablack") || style.contains("ultrablack") {
+            FontWeight::ExtraBlack
+        } else if style.contains("black") || style.contains("heavy") {
+            FontWeight::Black
+        } else {
+            FontWeight::Normal
+        };
+
+        let font_style = if flags & OBS_FONT_ITALIC != 0 || style.contains("italic") {
+            FontStyle::Italic
+        } else if style.contains("oblique") {
+            FontStyle::Oblique
+        } else {
+            FontStyle::Normal
+        };
+
+        let stretch = if style.contains("condensed") {
+            FontStretch::Condensed
+        } else if style.contains("expanded") {
+            FontStretch::Expanded
+        } else {
+            FontStretch::Normal
+        };
+
+        Some(Font {
+            family,
+            style: font_style,
+            weight,
+            stretch,
+        })
+    }
+}
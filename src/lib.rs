@@ -2,7 +2,7 @@ use core::fmt;
 use std::{
     borrow::Cow,
     cmp::Ordering,
-    ffi::{c_void, CStr},
+    ffi::{c_void, CStr, CString},
     fs::{self, File},
     future::Future,
     io::{BufWriter, Cursor},
@@ -30,51 +30,73 @@ use ffi::{
     obs_data_set_default_bool, obs_data_set_default_int, obs_data_t, obs_enter_graphics,
     obs_get_base_effect, obs_hotkey_id, obs_hotkey_register_source, obs_hotkey_t,
     obs_leave_graphics, obs_mouse_event, obs_properties_add_bool, obs_properties_add_button,
-    obs_properties_add_editable_list, obs_properties_add_int, obs_properties_add_path,
-    obs_properties_add_text, obs_properties_create, obs_properties_get,
-    obs_property_set_modified_callback2, obs_property_set_visible, obs_property_t,
-    obs_register_source_s, obs_source_info, obs_source_t, GS_DYNAMIC, GS_RGBA, LOG_WARNING,
-    OBS_EDITABLE_LIST_TYPE_STRINGS, OBS_EFFECT_PREMULTIPLIED_ALPHA, OBS_ICON_TYPE_GAME_CAPTURE,
-    OBS_PATH_FILE, OBS_SOURCE_CONTROLLABLE_MEDIA, OBS_SOURCE_CUSTOM_DRAW, OBS_SOURCE_INTERACTION,
+    obs_properties_add_editable_list, obs_properties_add_int, obs_properties_add_list,
+    obs_properties_add_path, obs_properties_add_text, obs_properties_create, obs_properties_get,
+    obs_property_list_add_string, obs_property_set_modified_callback2, obs_property_set_visible,
+    obs_property_t, obs_register_source_s, obs_source_info, obs_source_t, GS_DYNAMIC, GS_RGBA,
+    LOG_WARNING, OBS_COMBO_FORMAT_STRING, OBS_COMBO_TYPE_EDITABLE, OBS_EDITABLE_LIST_TYPE_STRINGS,
+    OBS_EFFECT_PREMULTIPLIED_ALPHA, OBS_ICON_TYPE_GAME_CAPTURE, OBS_PATH_FILE,
+    OBS_SOURCE_CONTROLLABLE_MEDIA, OBS_SOURCE_CUSTOM_DRAW, OBS_SOURCE_INTERACTION,
     OBS_SOURCE_TYPE_INPUT, OBS_SOURCE_VIDEO,
 };
 use ffi_types::{
-    obs_media_state, obs_module_t, obs_properties_t, LOG_DEBUG, LOG_ERROR, LOG_INFO,
-    OBS_MEDIA_STATE_ENDED, OBS_MEDIA_STATE_PAUSED, OBS_MEDIA_STATE_PLAYING,
+    obs_combo_type, obs_media_state, obs_module_t, obs_properties_t, LOG_DEBUG, LOG_ERROR,
+    LOG_INFO, OBS_MEDIA_STATE_ENDED, OBS_MEDIA_STATE_PAUSED, OBS_MEDIA_STATE_PLAYING,
     OBS_MEDIA_STATE_STOPPED, OBS_PATH_DIRECTORY, OBS_TEXT_DEFAULT,
 };
 
 use livesplit_core::{
     event::{CommandSink, Event, Result, TimerQuery},
-    layout::{self, LayoutSettings, LayoutState},
-    rendering::software::Renderer,
+    layout::{self, LayoutSettings},
     run::{
         parser::{composite, TimerKind},
         saver::livesplit::{save_timer, IoWrite},
     },
-    settings::ImageCache,
     Layout, Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 use log::{debug, error, info, warn, Level, LevelFilter, Log, Metadata, Record};
 use serde_derive::Deserialize;
 use serde_json::from_str;
 
-use crate::localization::{lang, Text};
+use crate::{
+    chapters::{ChapterOptions, Chapters},
+    control_panel::{ControlPanel, ControlPanelOptions},
+    drop_shadow::DropShadow,
+    event_worker::EventWorker,
+    frame::FrameRenderer,
+    frame_export::FrameExport,
+    layout_overrides::LayoutOverrides,
+    localization::{lang, Text},
+    recording_control::{RecordingControl, RecordingOptions},
+    scene_switcher::{Rule, SceneSwitcher},
+    scripting::TimerSignals,
+    text_export::TextExport,
+    timer_events::TimerEvents,
+    timestamp_log::{TimestampLog, TimestampLogOptions},
+};
 
 #[cfg(feature = "auto-splitting")]
 use {
     self::ffi::{
         obs_data_erase, obs_data_set_default_string, obs_data_set_string, obs_properties_add_group,
-        obs_properties_add_list, obs_property_list_add_string, obs_property_set_description,
-        obs_property_set_enabled, obs_property_set_long_description, obs_source_update_properties,
-        OBS_COMBO_FORMAT_STRING, OBS_COMBO_TYPE_LIST, OBS_GROUP_NORMAL, OBS_TEXT_INFO,
+        obs_property_list_clear, obs_property_set_description, obs_property_set_enabled,
+        obs_property_set_long_description, obs_source_update_properties, OBS_COMBO_TYPE_LIST,
+        OBS_GROUP_NORMAL, OBS_PATH_FILE_SAVE, OBS_TEXT_INFO, OBS_TEXT_MULTILINE,
     },
+    self::file_watcher::FileWatcher,
     livesplit_core::auto_splitting::{
         self,
         settings::{self, FileFilter, Value, Widget, WidgetKind},
         wasi_path,
     },
-    std::ffi::CString,
+    std::{
+        sync::{
+            atomic::AtomicUsize,
+            mpsc::{self, Sender},
+            Once,
+        },
+        thread::{self, JoinHandle},
+    },
 };
 
 macro_rules! cstr {
@@ -83,9 +105,32 @@ macro_rules! cstr {
     };
 }
 
+mod audio_cues;
+#[cfg(feature = "auto-splitting")]
+mod auto_splitter_settings;
+#[cfg(feature = "auto-splitting")]
+mod auto_splitter_status;
 #[cfg(feature = "auto-splitting")]
 mod auto_splitters;
+mod chapters;
+mod control_panel;
+mod drop_shadow;
+mod event_worker;
+#[cfg(feature = "auto-splitting")]
+mod file_watcher;
+mod frame;
+mod frame_export;
+mod layout_overrides;
 mod localization;
+mod recording_control;
+mod scene_switcher;
+mod scripting;
+mod text_export;
+mod text_source;
+mod timer_event_filter;
+mod timer_events;
+mod timer_values;
+mod timestamp_log;
 
 static OBS_MODULE_POINTER: AtomicPtr<obs_module_t> = AtomicPtr::new(ptr::null_mut());
 
@@ -112,6 +157,31 @@ struct GlobalTimer {
     auto_splitter: auto_splitting::Runtime<Arc<InnerTimer>>,
     #[cfg(feature = "auto-splitting")]
     auto_splitter_is_enabled: AtomicBool,
+    /// Activates the auto splitter again if it was active the last time the
+    /// splits were used.
+    #[cfg(feature = "auto-splitting")]
+    auto_splitter_restore: Once,
+    /// Changes whenever the user activates or deactivates the auto splitter,
+    /// so restoring it in the background doesn't undo that.
+    #[cfg(feature = "auto-splitting")]
+    auto_splitter_generation: AtomicUsize,
+    /// Notices when the local auto splitter gets rebuilt, so it can be
+    /// reloaded. It's shared by all the sources that use the splits, so the
+    /// auto splitter is only reloaded once. Each path is watched on its own,
+    /// so sources with different local auto splitters don't interfere.
+    #[cfg(feature = "auto-splitting")]
+    local_auto_splitter_watcher: Mutex<FileWatcher>,
+    /// Why the auto splitter couldn't be downloaded or loaded the last time.
+    #[cfg(feature = "auto-splitting")]
+    auto_splitter_error: Mutex<Option<String>>,
+    /// The downloaded files that differ from the version that was used the
+    /// first time, which the user can accept.
+    #[cfg(feature = "auto-splitting")]
+    auto_splitter_pin_mismatches: Mutex<Vec<auto_splitters::PinMismatch>>,
+    /// Sends the reloads of the local auto splitter to the thread that
+    /// carries them out. It's started with the first reload.
+    #[cfg(feature = "auto-splitting")]
+    auto_splitter_reloads: Mutex<Option<Sender<(PathBuf, settings::Map)>>>,
 }
 
 struct InnerTimer {
@@ -119,9 +189,27 @@ struct InnerTimer {
     can_save_splits: bool,
     timer: RwLock<Timer>,
     auto_save: AtomicBool,
+    events: TimerEvents,
+    /// What the auto splitter reported. It's kept with the timer, as that's
+    /// what the auto splitter uses while it runs.
+    #[cfg(feature = "auto-splitting")]
+    auto_splitter_log: Arc<Mutex<auto_splitter_status::RuntimeLog>>,
 }
 
 impl InnerTimer {
+    /// Applies a command to the timer and publishes the resulting event.
+    fn apply(&self, f: impl FnOnce(&mut Timer) -> Result) -> Result {
+        #[cfg(feature = "auto-splitting")]
+        auto_splitter_status::enter(&self.auto_splitter_log);
+
+        let mut timer = self.timer.write().unwrap();
+        let result = f(&mut timer);
+        if let Ok(event) = result {
+            self.events.publish(event, &timer);
+        }
+        result
+    }
+
     fn save(&self) {
         if self.can_save_splits {
             if let Ok(file) = File::create(&self.path) {
@@ -134,26 +222,22 @@ impl InnerTimer {
 
 impl CommandSink for InnerTimer {
     fn start(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().start();
+        let result = self.apply(|timer| timer.start());
         async move { result }
     }
 
     fn split(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().split();
+        let result = self.apply(|timer| timer.split());
         async move { result }
     }
 
     fn split_or_start(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().split_or_start();
+        let result = self.apply(|timer| timer.split_or_start());
         async move { result }
     }
 
     fn reset(&self, save_attempt: Option<bool>) -> impl Future<Output = Result> + 'static {
-        let result = self
-            .timer
-            .write()
-            .unwrap()
-            .reset(save_attempt.unwrap_or(true));
+        let result = self.apply(|timer| timer.reset(save_attempt.unwrap_or(true)));
 
         if result.is_ok() && self.auto_save.load(atomic::Ordering::Relaxed) {
             self.save();
@@ -163,62 +247,71 @@ impl CommandSink for InnerTimer {
     }
 
     fn undo_split(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().undo_split();
+        let result = self.apply(|timer| timer.undo_split());
         async move { result }
     }
 
     fn skip_split(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().skip_split();
+        let result = self.apply(|timer| timer.skip_split());
         async move { result }
     }
 
     fn toggle_pause_or_start(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().toggle_pause_or_start();
+        let result = self.apply(|timer| timer.toggle_pause_or_start());
         async move { result }
     }
 
     fn pause(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().pause();
+        let result = self.apply(|timer| timer.pause());
         async move { result }
     }
 
     fn resume(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().resume();
+        let result = self.apply(|timer| timer.resume());
         async move { result }
     }
 
     fn undo_all_pauses(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().undo_all_pauses();
+        let result = self.apply(|timer| timer.undo_all_pauses());
         async move { result }
     }
 
     fn switch_to_previous_comparison(&self) -> impl Future<Output = Result> + 'static {
-        self.timer.write().unwrap().switch_to_previous_comparison();
-        async { Ok(Event::ComparisonChanged) }
+        let result = self.apply(|timer| {
+            timer.switch_to_previous_comparison();
+            Ok(Event::ComparisonChanged)
+        });
+        async move { result }
     }
 
     fn switch_to_next_comparison(&self) -> impl Future<Output = Result> + 'static {
-        self.timer.write().unwrap().switch_to_next_comparison();
-        async { Ok(Event::ComparisonChanged) }
+        let result = self.apply(|timer| {
+            timer.switch_to_next_comparison();
+            Ok(Event::ComparisonChanged)
+        });
+        async move { result }
     }
 
     fn toggle_timing_method(&self) -> impl Future<Output = Result> + 'static {
-        self.timer.write().unwrap().toggle_timing_method();
-        async move { Ok(Event::TimingMethodChanged) }
+        let result = self.apply(|timer| {
+            timer.toggle_timing_method();
+            Ok(Event::TimingMethodChanged)
+        });
+        async move { result }
     }
 
     fn set_game_time(&self, time: TimeSpan) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().set_game_time(time);
+        let result = self.apply(|timer| timer.set_game_time(time));
         async move { result }
     }
 
     fn pause_game_time(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().pause_game_time();
+        let result = self.apply(|timer| timer.pause_game_time());
         async move { result }
     }
 
     fn resume_game_time(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().resume_game_time();
+        let result = self.apply(|timer| timer.resume_game_time());
         async move { result }
     }
 
@@ -227,19 +320,18 @@ impl CommandSink for InnerTimer {
         name: Cow<str>,
         value: Cow<str>,
     ) -> impl Future<Output = Result> + 'static {
-        self.timer.write().unwrap().set_custom_variable(name, value);
-        async { Ok(Event::CustomVariableSet) }
+        let result = self.apply(|timer| {
+            timer.set_custom_variable(name, value);
+            Ok(Event::CustomVariableSet)
+        });
+        async move { result }
     }
 
     fn set_current_comparison(
         &self,
         comparison: Cow<str>,
     ) -> impl Future<Output = Result> + 'static {
-        let result = self
-            .timer
-            .write()
-            .unwrap()
-            .set_current_comparison(comparison);
+        let result = self.apply(|timer| timer.set_current_comparison(comparison));
         async move { result }
     }
 
@@ -247,20 +339,20 @@ impl CommandSink for InnerTimer {
         &self,
         method: TimingMethod,
     ) -> impl Future<Output = Result> + 'static {
-        self.timer
-            .write()
-            .unwrap()
-            .set_current_timing_method(method);
-        async { Ok(Event::TimingMethodChanged) }
+        let result = self.apply(|timer| {
+            timer.set_current_timing_method(method);
+            Ok(Event::TimingMethodChanged)
+        });
+        async move { result }
     }
 
     fn initialize_game_time(&self) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().initialize_game_time();
+        let result = self.apply(|timer| timer.initialize_game_time());
         async move { result }
     }
 
     fn set_loading_times(&self, time: TimeSpan) -> impl Future<Output = Result> + 'static {
-        let result = self.timer.write().unwrap().set_loading_times(time);
+        let result = self.apply(|timer| timer.set_loading_times(time));
         async move { result }
     }
 }
@@ -269,6 +361,9 @@ impl TimerQuery for InnerTimer {
     type Guard<'a> = RwLockReadGuard<'a, Timer>;
 
     fn get_timer(&self) -> Self::Guard<'_> {
+        #[cfg(feature = "auto-splitting")]
+        auto_splitter_status::enter(&self.auto_splitter_log);
+
         self.timer.read().unwrap()
     }
 }
@@ -285,19 +380,29 @@ struct State {
     game_path: PathBuf,
     global_timer: Arc<GlobalTimer>,
     layout: Layout,
-    state: LayoutState,
-    image_cache: ImageCache,
-    renderer: Renderer,
+    frame_renderer: FrameRenderer,
+    transparent_background: bool,
+    drop_shadow: Option<DropShadow>,
+    frame_export: FrameExport,
+    text_export: Option<TextExport>,
     texture: *mut gs_texture_t,
     width: u32,
     height: u32,
     activated: bool,
     obs_settings: *mut obs_data_t,
     #[cfg(feature = "auto-splitting")]
+    auto_splitter_list: Arc<auto_splitters::List>,
+    #[cfg(feature = "auto-splitting")]
     auto_splitter_widgets: Arc<Vec<Widget>>,
     #[cfg(feature = "auto-splitting")]
     auto_splitter_map: settings::Map,
-    #[cfg(feature = "auto-splitting")]
+    timer_signals: TimerSignals,
+    scene_switcher: Option<EventWorker<SceneSwitcher>>,
+    recording_control: Option<EventWorker<RecordingControl>>,
+    chapters: Option<EventWorker<Chapters>>,
+    timestamp_log: Option<EventWorker<TimestampLog>>,
+    /// Keeps the control panel running as long as the source enables it.
+    control_panel: Option<Arc<ControlPanel>>,
     source: *mut obs_source_t,
 }
 
@@ -322,6 +427,15 @@ struct Settings {
     splits_path: PathBuf,
     auto_save: bool,
     layout: Layout,
+    transparent_background: bool,
+    drop_shadow: Option<DropShadow>,
+    frame_export: FrameExport,
+    text_export: Option<TextExport>,
+    scene_switching_rules: Option<Vec<Rule>>,
+    recording_options: Option<RecordingOptions>,
+    chapter_options: Option<ChapterOptions>,
+    timestamp_log_options: Option<TimestampLogOptions>,
+    control_panel_options: Option<ControlPanelOptions>,
     width: u32,
     height: u32,
 }
@@ -371,42 +485,42 @@ fn parse_layout(path: &CStr) -> Option<Layout> {
     layout::parser::parse(&file_data).ok()
 }
 
-unsafe fn get_game_environment_vars(settings: *mut obs_data_t) -> Vec<(String, String)> {
+/// Reads the entries of an editable list of strings.
+unsafe fn get_editable_list(settings: *mut obs_data_t, name: *const c_char) -> Vec<String> {
     unsafe {
-        let environment_list = obs_data_get_array(settings, SETTINGS_GAME_ENVIRONMENT_LIST);
-        let count = obs_data_array_count(environment_list);
+        let list = obs_data_get_array(settings, name);
+        let count = obs_data_array_count(list);
 
-        let mut vars = Vec::<(String, String)>::new();
+        let mut entries = Vec::new();
 
         for i in 0..count {
-            let item = obs_data_array_item(environment_list, i);
-            'use_item: {
-                let raw_json = obs_data_get_json(item);
-                let raw_json = CStr::from_ptr(raw_json.cast()).to_string_lossy();
-                let entry = match from_str::<ObsEditableListEntry>(raw_json.as_ref()) {
-                    Ok(entry) => entry,
-                    Err(e) => {
-                        warn!("Couldn't read item {i} contents: {e}");
-                        break 'use_item;
-                    }
-                };
-
-                let (key, value) = match entry.value.split_once('=') {
-                    Some((key, value)) => (key, value),
-                    None => {
-                        warn!("Invalid environment variable entry: '{}'", entry.value);
-                        break 'use_item;
-                    }
-                };
-
-                vars.push((key.to_string(), value.to_string()));
+            let item = obs_data_array_item(list, i);
+            let raw_json = obs_data_get_json(item);
+            let raw_json = CStr::from_ptr(raw_json.cast()).to_string_lossy();
+            match from_str::<ObsEditableListEntry>(raw_json.as_ref()) {
+                Ok(entry) => entries.push(entry.value),
+                Err(e) => warn!("Couldn't read item {i} contents: {e}"),
             }
-
             obs_data_release(item);
         }
-        obs_data_array_release(environment_list);
+        obs_data_array_release(list);
 
-        vars
+        entries
+    }
+}
+
+unsafe fn get_game_environment_vars(settings: *mut obs_data_t) -> Vec<(String, String)> {
+    unsafe {
+        get_editable_list(settings, SETTINGS_GAME_ENVIRONMENT_LIST)
+            .into_iter()
+            .filter_map(|entry| match entry.split_once('=') {
+                Some((key, value)) => Some((key.to_string(), value.to_string())),
+                None => {
+                    warn!("Invalid environment variable entry: '{entry}'");
+                    None
+                }
+            })
+            .collect()
     }
 }
 
@@ -452,7 +566,20 @@ unsafe fn parse_settings(settings: *mut obs_data_t) -> Settings {
 
         let layout_path =
             CStr::from_ptr(obs_data_get_string(settings, SETTINGS_LAYOUT_PATH).cast());
-        let layout = parse_layout(layout_path).unwrap_or_else(|| Layout::default_layout(lang()));
+        let mut layout =
+            parse_layout(layout_path).unwrap_or_else(|| Layout::default_layout(lang()));
+        LayoutOverrides::parse(settings).apply(&mut layout);
+
+        let transparent_background = obs_data_get_bool(settings, SETTINGS_TRANSPARENT_BACKGROUND);
+        let drop_shadow = DropShadow::parse(settings);
+        let frame_export = FrameExport::parse(settings);
+        let text_export = TextExport::parse(settings);
+        let scene_switching_rules =
+            Rule::parse_all(&get_editable_list(settings, SETTINGS_SCENE_SWITCHING_RULES));
+        let recording_options = RecordingOptions::parse(settings);
+        let chapter_options = ChapterOptions::parse(settings);
+        let timestamp_log_options = TimestampLogOptions::parse(settings);
+        let control_panel_options = ControlPanelOptions::parse(settings);
 
         let width = obs_data_get_int(settings, SETTINGS_WIDTH) as u32;
         let height = obs_data_get_int(settings, SETTINGS_HEIGHT) as u32;
@@ -468,6 +595,15 @@ unsafe fn parse_settings(settings: *mut obs_data_t) -> Settings {
             splits_path,
             auto_save,
             layout,
+            transparent_background,
+            drop_shadow,
+            frame_export,
+            text_export,
+            scene_switching_rules,
+            recording_options,
+            chapter_options,
+            timestamp_log_options,
+            control_panel_options,
             width,
             height,
         }
@@ -487,10 +623,19 @@ impl State {
             splits_path,
             auto_save,
             layout,
+            transparent_background,
+            drop_shadow,
+            frame_export,
+            text_export,
+            scene_switching_rules,
+            recording_options,
+            chapter_options,
+            timestamp_log_options,
+            control_panel_options,
             width,
             height,
         }: Settings,
-        _source: *mut obs_source_t,
+        source: *mut obs_source_t,
         obs_settings: *mut obs_data_t,
     ) -> Self {
         unsafe {
@@ -501,9 +646,26 @@ impl State {
                 .timer
                 .auto_save
                 .store(auto_save, atomic::Ordering::Relaxed);
-
-            let state = LayoutState::default();
-            let renderer = Renderer::new();
+            let mut scene_switcher = None;
+            EventWorker::update(
+                &mut scene_switcher,
+                scene_switching_rules,
+                &global_timer.timer,
+            );
+            let mut recording_control = None;
+            EventWorker::update(
+                &mut recording_control,
+                recording_options,
+                &global_timer.timer,
+            );
+            let mut chapters = None;
+            EventWorker::update(&mut chapters, chapter_options, &global_timer.timer);
+            let mut timestamp_log = None;
+            EventWorker::update(
+                &mut timestamp_log,
+                timestamp_log_options,
+                &global_timer.timer,
+            );
 
             obs_enter_graphics();
             let texture = gs_texture_create(width, height, GS_RGBA, 1, ptr::null_mut(), GS_DYNAMIC);
@@ -512,6 +674,8 @@ impl State {
             #[cfg(feature = "auto-splitting")]
             if let Some(local_auto_splitter) = &local_auto_splitter {
                 auto_splitter_load(&global_timer, local_auto_splitter.clone())
+            } else {
+                auto_splitter_restore(&global_timer);
             }
 
             Self {
@@ -524,49 +688,69 @@ impl State {
                 game_path,
                 global_timer,
                 layout,
-                state,
-                image_cache: ImageCache::new(),
-                renderer,
+                frame_renderer: FrameRenderer::new(),
+                transparent_background,
+                drop_shadow,
+                frame_export,
+                text_export,
                 texture,
                 width,
                 height,
                 activated: false,
                 obs_settings,
                 #[cfg(feature = "auto-splitting")]
+                auto_splitter_list: auto_splitters::get_list(),
+                #[cfg(feature = "auto-splitting")]
                 auto_splitter_widgets: Arc::default(),
                 #[cfg(feature = "auto-splitting")]
                 auto_splitter_map: settings::Map::new(),
-                #[cfg(feature = "auto-splitting")]
-                source: _source,
+                timer_signals: TimerSignals::new(&global_timer),
+                scene_switcher,
+                recording_control,
+                chapters,
+                timestamp_log,
+                control_panel: control_panel_options.and_then(ControlPanel::get),
+                source,
             }
         }
     }
 
     unsafe fn render(&mut self) {
         unsafe {
-            self.layout.update_state(
-                &mut self.state,
-                &mut self.image_cache,
-                &self.global_timer.timer.get_timer().snapshot(),
+            let timer = self.global_timer.timer.get_timer();
+
+            let image_data = self.frame_renderer.render(
+                &mut self.layout,
+                &timer,
                 lang(),
+                self.transparent_background,
+                self.drop_shadow.as_mut(),
+                [self.width, self.height],
             );
 
-            self.renderer
-                .render(&self.state, &self.image_cache, [self.width, self.height]);
+            gs_texture_set_image(self.texture, image_data.as_ptr(), self.width * 4, false);
 
-            gs_texture_set_image(
-                self.texture,
-                self.renderer.image_data().as_ptr(),
-                self.width * 4,
-                false,
+            self.frame_export.on_frame(
+                image_data,
+                [self.width, self.height],
+                timer.current_phase(),
+                timer.run().attempt_count(),
             );
 
-            self.image_cache.collect();
+            drop(timer);
 
             #[cfg(feature = "auto-splitting")]
             {
                 let mut needs_properties_update = false;
 
+                // The list is refreshed in the background, so the choices
+                // are only updated once it got replaced.
+                let auto_splitter_list = auto_splitters::get_list();
+                if !Arc::ptr_eq(&self.auto_splitter_list, &auto_splitter_list) {
+                    self.auto_splitter_list = auto_splitter_list;
+                    needs_properties_update = true;
+                }
+
                 if let Some(auto_splitter_widgets) =
                     self.global_timer.auto_splitter.settings_widgets()
                 {
@@ -858,6 +1042,8 @@ unsafe extern "C" fn create(settings: *mut obs_data_t, source: *mut obs_source_t
             data,
         );
 
+        scripting::register(source, data);
+
         data
     }
 }
@@ -904,6 +1090,42 @@ unsafe extern "C" fn video_render(data: *mut c_void, _: *mut gs_effect_t) {
     }
 }
 
+unsafe extern "C" fn video_tick(data: *mut c_void, _: f32) {
+    unsafe {
+        let (source, events) = {
+            let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+            #[cfg(feature = "auto-splitting")]
+            if let Some(path) = &state.local_auto_splitter {
+                let has_changed = state
+                    .global_timer
+                    .local_auto_splitter_watcher
+                    .lock()
+                    .unwrap()
+                    .has_changed(path);
+                if has_changed {
+                    auto_splitter_reload(
+                        &state.global_timer,
+                        path.clone(),
+                        &state.auto_splitter_map,
+                    );
+                }
+            }
+
+            // This runs while the source is hidden too, so the files keep
+            // being updated.
+            if let Some(text_export) = &mut state.text_export {
+                text_export.update(&state.global_timer.timer.get_timer());
+            }
+
+            (state.source, state.timer_signals.take())
+        };
+        // The handlers of the signals may call the procedures of the source,
+        // which lock the state again.
+        scripting::emit(source, &events);
+    }
+}
+
 unsafe extern "C" fn mouse_wheel(
     data: *mut c_void,
     _: *const obs_mouse_event,
@@ -932,6 +1154,27 @@ unsafe extern "C" fn save_splits(
     }
 }
 
+unsafe extern "C" fn save_frame(
+    _: *mut obs_properties_t,
+    _: *mut obs_property_t,
+    data: *mut c_void,
+) -> bool {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        let size = [state.width, state.height];
+        let image_data = state.frame_renderer.render(
+            &mut state.layout,
+            &state.global_timer.timer.get_timer(),
+            lang(),
+            state.transparent_background,
+            state.drop_shadow.as_mut(),
+            size,
+        );
+        state.frame_export.save_frame(image_data, size);
+        false
+    }
+}
+
 unsafe extern "C" fn use_game_arguments_modified(
     data: *mut c_void,
     props: *mut obs_properties_t,
@@ -1063,15 +1306,8 @@ unsafe extern "C" fn use_local_auto_splitter_modified(
         }
 
         let auto_splitter_activate = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_ACTIVATE);
-        let auto_splitter_info = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_INFO);
-        let auto_splitter_website = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_WEBSITE);
-
         let local_auto_splitter_path = obs_properties_get(props, SETTINGS_LOCAL_AUTO_SPLITTER_PATH);
 
-        obs_property_set_visible(auto_splitter_info, !use_local_auto_splitter);
-        obs_property_set_visible(auto_splitter_activate, !use_local_auto_splitter);
-        obs_property_set_visible(auto_splitter_website, !use_local_auto_splitter);
-
         obs_property_set_visible(local_auto_splitter_path, use_local_auto_splitter);
 
         obs_property_set_description(
@@ -1080,10 +1316,10 @@ unsafe extern "C" fn use_local_auto_splitter_modified(
         );
 
         update_auto_splitter_ui(
-            auto_splitter_info,
-            auto_splitter_website,
-            auto_splitter_activate,
-            state.global_timer.timer.get_timer().run().game_name(),
+            props,
+            &state.global_timer,
+            use_local_auto_splitter,
+            settings,
         );
 
         true
@@ -1107,17 +1343,16 @@ unsafe extern "C" fn splits_path_modified(
 
         #[cfg(feature = "auto-splitting")]
         {
-            let info_text = obs_properties_get(_props, SETTINGS_AUTO_SPLITTER_INFO);
-            let website_button = obs_properties_get(_props, SETTINGS_AUTO_SPLITTER_WEBSITE);
-            let activate_button = obs_properties_get(_props, SETTINGS_AUTO_SPLITTER_ACTIVATE);
-
             update_auto_splitter_ui(
-                info_text,
-                website_button,
-                activate_button,
-                state.global_timer.timer.get_timer().run().game_name(),
+                _props,
+                &state.global_timer,
+                state.local_auto_splitter.is_some(),
+                settings,
+            );
+            auto_splitter_update_activation_label(
+                obs_properties_get(_props, SETTINGS_AUTO_SPLITTER_ACTIVATE),
+                state,
             );
-            auto_splitter_update_activation_label(activate_button, state);
         }
 
         true
@@ -1126,13 +1361,83 @@ unsafe extern "C" fn splits_path_modified(
 
 #[cfg(feature = "auto-splitting")]
 unsafe fn update_auto_splitter_ui(
-    info_text: *mut obs_property_t,
-    website_button: *mut obs_property_t,
-    activate_button: *mut obs_property_t,
-    game_name: &str,
+    props: *mut obs_properties_t,
+    global_timer: &GlobalTimer,
+    uses_local_auto_splitter: bool,
+    settings: *mut obs_data_t,
 ) {
     unsafe {
-        if let Some(auto_splitter) = auto_splitters::get_list().get_for_game(game_name) {
+        let info_text = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_INFO);
+        let website_button = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_WEBSITE);
+        let activate_button = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_ACTIVATE);
+        let refresh_list_button = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_REFRESH_LIST);
+        let lists = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_LISTS);
+        let game_list = obs_properties_get(props, SETTINGS_AUTO_SPLITTER_GAME);
+
+        for prop in [
+            info_text,
+            website_button,
+            activate_button,
+            refresh_list_button,
+            lists,
+        ] {
+            obs_property_set_visible(prop, !uses_local_auto_splitter);
+        }
+
+        // The lists are shared by all the sources, so the setting only
+        // mirrors them.
+        if let Ok(sources) = CString::new(auto_splitters::get_list_sources()) {
+            obs_data_set_string(settings, SETTINGS_AUTO_SPLITTER_LISTS, sources.as_ptr());
+        }
+
+        let list = auto_splitters::get_list();
+        let timer = global_timer.timer.get_timer();
+        let game_name = timer.run().game_name();
+        let choice = auto_splitters::get_choice(&global_timer.timer.path);
+
+        // The games that match the splits best come first, followed by all the
+        // other auto splitters that can be used, so any of them can be chosen
+        // if the game of the splits is named differently.
+        let candidates = list.candidates_for_game(game_name);
+        let mut games = candidates
+            .iter()
+            .filter(|candidate| candidate.auto_splitter.is_using_auto_splitting_runtime())
+            .map(|candidate| candidate.game)
+            .collect::<Vec<_>>();
+        let mut others = list
+            .runtime_auto_splitters()
+            .filter(|auto_splitter| {
+                !candidates
+                    .iter()
+                    .any(|candidate| ptr::eq(candidate.auto_splitter, *auto_splitter))
+            })
+            .filter_map(|auto_splitter| auto_splitter.game())
+            .collect::<Vec<_>>();
+        others.sort_unstable_by_key(|game| game.to_lowercase());
+        games.extend(others);
+
+        // Editable lists store the text rather than the value of the item.
+        let automatic = Text::AutoSplitterAutomatic.resolve(lang());
+        obs_property_list_clear(game_list);
+        obs_property_list_add_string(game_list, automatic, automatic);
+        for game in games {
+            if let Ok(game) = CString::new(game) {
+                obs_property_list_add_string(game_list, game.as_ptr(), game.as_ptr());
+            }
+        }
+        obs_property_set_visible(game_list, !uses_local_auto_splitter);
+
+        match choice
+            .as_deref()
+            .and_then(|choice| CString::new(choice).ok())
+        {
+            Some(choice) => {
+                obs_data_set_string(settings, SETTINGS_AUTO_SPLITTER_GAME, choice.as_ptr())
+            }
+            None => obs_data_set_string(settings, SETTINGS_AUTO_SPLITTER_GAME, automatic),
+        }
+
+        if let Some(auto_splitter) = list.find(game_name, choice.as_deref()) {
             obs_property_set_enabled(website_button, auto_splitter.website.is_some());
 
             if !auto_splitter.is_using_auto_splitting_runtime() {
@@ -1144,7 +1449,16 @@ unsafe fn update_auto_splitter_ui(
             } else {
                 obs_property_set_enabled(activate_button, true);
 
-                let mut auto_splitter_description = auto_splitter.description.as_bytes().to_vec();
+                // The hash is only known once the module got downloaded, unless
+                // the list contains it.
+                let mut auto_splitter_description = auto_splitter.description.clone();
+                if let Some(hash) =
+                    auto_splitters::module_hash(auto_splitter, auto_splitters::get_path())
+                {
+                    auto_splitter_description.push_str("\n\nSHA-256: ");
+                    auto_splitter_description.push_str(&hash);
+                }
+                let mut auto_splitter_description = auto_splitter_description.into_bytes();
                 auto_splitter_description.push(0);
 
                 obs_property_set_description(
@@ -1155,14 +1469,96 @@ unsafe fn update_auto_splitter_ui(
         } else {
             obs_property_set_enabled(activate_button, false);
             obs_property_set_enabled(website_button, false);
-            obs_property_set_description(info_text, Text::AutoSplitterUnavailable.resolve(lang()));
+
+            // Similar games are listed first, but only the user can tell
+            // whether any of them is the game of the splits.
+            let text = if choice.is_none() && !candidates.is_empty() {
+                Text::AutoSplitterChooseGame
+            } else {
+                Text::AutoSplitterUnavailable
+            };
+            obs_property_set_description(info_text, text.resolve(lang()));
         }
     }
 }
 
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_game_modified(
+    data: *mut c_void,
+    props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    settings: *mut obs_data_t,
+) -> bool {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+        let game =
+            CStr::from_ptr(obs_data_get_string(settings, SETTINGS_AUTO_SPLITTER_GAME).cast());
+
+        // Without a choice the best match is used. Anything that was typed
+        // in, but isn't in the list, is only part of a search.
+        let is_automatic =
+            game.is_empty() || game == CStr::from_ptr(Text::AutoSplitterAutomatic.resolve(lang()));
+        let game = game.to_string_lossy();
+        let choice = (!is_automatic).then_some(&*game);
+        if choice.is_some_and(|game| auto_splitters::get_list().get_for_game(game).is_none()) {
+            return false;
+        }
+
+        let path = &state.global_timer.timer.path;
+        if auto_splitters::get_choice(path).as_deref() == choice {
+            return false;
+        }
+        auto_splitters::set_choice(path, choice);
+
+        // The auto splitter of the other game needs to be activated again.
+        auto_splitter_unload(&state.global_timer);
+
+        update_auto_splitter_ui(
+            props,
+            &state.global_timer,
+            state.local_auto_splitter.is_some(),
+            settings,
+        );
+        auto_splitter_update_activation_label(
+            obs_properties_get(props, SETTINGS_AUTO_SPLITTER_ACTIVATE),
+            state,
+        );
+
+        true
+    }
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_lists_modified(
+    _data: *mut c_void,
+    _props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    settings: *mut obs_data_t,
+) -> bool {
+    unsafe {
+        let sources =
+            CStr::from_ptr(obs_data_get_string(settings, SETTINGS_AUTO_SPLITTER_LISTS).cast());
+        let sources = sources.to_string_lossy();
+
+        // The lists are only loaded again once the list gets refreshed, so
+        // they aren't downloaded while they are still being typed in.
+        if auto_splitters::get_list_sources() != sources {
+            auto_splitters::set_list_sources(&sources);
+        }
+
+        false
+    }
+}
+
 #[cfg(feature = "auto-splitting")]
 fn auto_splitter_unload(global_timer: &GlobalTimer) {
     global_timer.auto_splitter.unload().ok();
+    global_timer
+        .auto_splitter_pin_mismatches
+        .lock()
+        .unwrap()
+        .clear();
 
     global_timer
         .auto_splitter_is_enabled
@@ -1171,15 +1567,21 @@ fn auto_splitter_unload(global_timer: &GlobalTimer) {
 
 #[cfg(feature = "auto-splitting")]
 fn auto_splitter_load(global_timer: &GlobalTimer, path: PathBuf) {
+    global_timer.timer.auto_splitter_log.lock().unwrap().reset();
+
     let enabled = match global_timer
         .auto_splitter
         .load(path, global_timer.timer.clone())
     {
         Err(e) => {
             warn!("Auto Splitter could not be loaded: {e}");
+            *global_timer.auto_splitter_error.lock().unwrap() = Some(e.to_string());
             false
         }
-        _ => true,
+        _ => {
+            *global_timer.auto_splitter_error.lock().unwrap() = None;
+            true
+        }
     };
 
     global_timer
@@ -1196,6 +1598,10 @@ unsafe extern "C" fn auto_splitter_activate_clicked(
     unsafe {
         let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
 
+        state
+            .global_timer
+            .auto_splitter_generation
+            .fetch_add(1, atomic::Ordering::Relaxed);
         state
             .global_timer
             .auto_splitter_is_enabled
@@ -1203,19 +1609,18 @@ unsafe extern "C" fn auto_splitter_activate_clicked(
 
         auto_splitter_update_activation_label(prop, state);
 
-        if state
+        let is_enabled = state
             .global_timer
             .auto_splitter_is_enabled
-            .load(atomic::Ordering::Relaxed)
-        {
-            if let Some(auto_splitter_path) = auto_splitters::get_downloader().download_for_game(
-                auto_splitters::get_list(),
-                state.global_timer.timer.get_timer().run().game_name(),
-                auto_splitters::get_path(),
-            ) {
+            .load(atomic::Ordering::Relaxed);
+        auto_splitters::set_activated(&state.global_timer.timer.path, is_enabled);
+
+        if is_enabled {
+            if let Some(auto_splitter_path) = auto_splitter_download(&state.global_timer) {
                 auto_splitter_load(&state.global_timer, auto_splitter_path);
+                auto_splitter_report_pin_mismatches(&state.global_timer);
             } else {
-                error!("Couldn't download the auto splitter files.");
+                auto_splitter_download_failed(&state.global_timer);
             }
         } else {
             auto_splitter_unload(&state.global_timer);
@@ -1225,6 +1630,408 @@ unsafe extern "C" fn auto_splitter_activate_clicked(
     }
 }
 
+/// Loads the auto splitter again in the background, so the compilation doesn't
+/// hold up the rendering. The settings it had are kept, or if it isn't loaded
+/// right now, the ones the user chose the last time. The reloads of a timer
+/// happen one after another on the same thread, so they don't race each other.
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_reload(global_timer: &Arc<GlobalTimer>, path: PathBuf, map: &settings::Map) {
+    let map = global_timer
+        .auto_splitter
+        .settings_map()
+        .unwrap_or_else(|| map.clone());
+
+    let mut reloads = global_timer.auto_splitter_reloads.lock().unwrap();
+    let reloads = reloads.get_or_insert_with(|| {
+        let (sender, receiver) = mpsc::channel::<(PathBuf, settings::Map)>();
+        let global_timer = Arc::downgrade(global_timer);
+
+        // The thread stops once the timer is gone, as that drops the sender.
+        auto_splitter_spawn(move || {
+            while let Ok(mut reload) = receiver.recv() {
+                // Only the latest version needs to be loaded.
+                if let Some(latest) = receiver.try_iter().last() {
+                    reload = latest;
+                }
+                let (path, map) = reload;
+                let Some(global_timer) = global_timer.upgrade() else {
+                    break;
+                };
+
+                info!("The local auto splitter changed. Reloading it.");
+                auto_splitter_unload(&global_timer);
+                auto_splitter_load(&global_timer, path);
+                auto_splitter_replace_settings(&global_timer, map);
+            }
+        });
+
+        sender
+    });
+    reloads.send((path, map)).ok();
+}
+
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_download(global_timer: &GlobalTimer) -> Option<PathBuf> {
+    let mut pin_mismatches = Vec::new();
+    let path = auto_splitters::get_downloader().download_for_game(
+        &auto_splitters::get_list(),
+        global_timer.timer.get_timer().run().game_name(),
+        auto_splitters::get_choice(&global_timer.timer.path).as_deref(),
+        auto_splitters::get_path(),
+        &mut pin_mismatches,
+    );
+    *global_timer.auto_splitter_pin_mismatches.lock().unwrap() = pin_mismatches;
+    path
+}
+
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_download_failed(global_timer: &GlobalTimer) {
+    let message = "Couldn't download the auto splitter files.";
+    error!("{message}");
+    *global_timer.auto_splitter_error.lock().unwrap() = Some(message.to_owned());
+    auto_splitter_report_pin_mismatches(global_timer);
+}
+
+/// Shows the files that changed unexpectedly in the status, as they keep the
+/// auto splitter from being updated until the user accepts them.
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_report_pin_mismatches(global_timer: &GlobalTimer) {
+    let pin_mismatches = global_timer.auto_splitter_pin_mismatches.lock().unwrap();
+    if pin_mismatches.is_empty() {
+        return;
+    }
+    let urls = pin_mismatches
+        .iter()
+        .map(|pin_mismatch| pin_mismatch.url.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    *global_timer.auto_splitter_error.lock().unwrap() = Some(format!(
+        "The auto splitter changed since it was first used, so the change is ignored. Accept it if it's intended. Changed files: {urls}"
+    ));
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_accept_changes_clicked(
+    _props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    data: *mut c_void,
+) -> bool {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        let global_timer = &state.global_timer;
+
+        let pin_mismatches =
+            mem::take(&mut *global_timer.auto_splitter_pin_mismatches.lock().unwrap());
+        for pin_mismatch in &pin_mismatches {
+            if let Err(e) = pin_mismatch.accept(auto_splitters::get_path()) {
+                warn!(
+                    "Failed accepting the new version of `{}`: {e:?}",
+                    pin_mismatch.url
+                );
+            }
+        }
+        *global_timer.auto_splitter_error.lock().unwrap() = None;
+
+        if global_timer
+            .auto_splitter_is_enabled
+            .load(atomic::Ordering::Relaxed)
+        {
+            auto_splitter_unload(global_timer);
+            if let Some(auto_splitter_path) = auto_splitter_download(global_timer) {
+                auto_splitter_load(global_timer, auto_splitter_path);
+                auto_splitter_report_pin_mismatches(global_timer);
+            } else {
+                auto_splitter_download_failed(global_timer);
+            }
+        }
+
+        true
+    }
+}
+
+/// The threads that work on the auto splitters in the background. They are
+/// waited for when the plugin gets unloaded, so none of them outlives it.
+#[cfg(feature = "auto-splitting")]
+static AUTO_SPLITTER_THREADS: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_spawn(f: impl FnOnce() + Send + 'static) {
+    let mut threads = AUTO_SPLITTER_THREADS.lock().unwrap();
+    threads.retain(|thread| !thread.is_finished());
+    threads.push(thread::spawn(f));
+}
+
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_join_threads() {
+    let threads = mem::take(&mut *AUTO_SPLITTER_THREADS.lock().unwrap());
+    for thread in threads {
+        thread.join().ok();
+    }
+}
+
+/// Activates the auto splitter of the splits if it was active when OBS was
+/// closed the last time. It is downloaded in the background, so loading the
+/// source isn't delayed by it.
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_restore(global_timer: &Arc<GlobalTimer>) {
+    global_timer.auto_splitter_restore.call_once(|| {
+        if !auto_splitters::is_activated(&global_timer.timer.path) {
+            return;
+        }
+
+        global_timer
+            .auto_splitter_is_enabled
+            .store(true, atomic::Ordering::Relaxed);
+
+        let global_timer = global_timer.clone();
+        let generation = global_timer
+            .auto_splitter_generation
+            .load(atomic::Ordering::Relaxed);
+        let is_overridden = move |global_timer: &GlobalTimer| {
+            global_timer
+                .auto_splitter_generation
+                .load(atomic::Ordering::Relaxed)
+                != generation
+        };
+
+        auto_splitter_spawn(move || {
+            let auto_splitter_path = auto_splitter_download(&global_timer);
+
+            // The user may have activated or deactivated the auto splitter
+            // in the meantime, which takes precedence.
+            if is_overridden(&global_timer) {
+                return;
+            }
+
+            let Some(auto_splitter_path) = auto_splitter_path else {
+                auto_splitter_download_failed(&global_timer);
+                global_timer
+                    .auto_splitter_is_enabled
+                    .store(false, atomic::Ordering::Relaxed);
+                return;
+            };
+            auto_splitter_load(&global_timer, auto_splitter_path);
+            auto_splitter_report_pin_mismatches(&global_timer);
+
+            // Loading takes a while as well, so if the user deactivated it
+            // while it did, it's unloaded again.
+            if is_overridden(&global_timer)
+                && !auto_splitters::is_activated(&global_timer.timer.path)
+            {
+                auto_splitter_unload(&global_timer);
+            }
+        });
+    });
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_refresh_list_clicked(
+    _props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    _data: *mut c_void,
+) -> bool {
+    auto_splitters::request_list_refresh();
+    false
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe fn auto_splitter_settings_file(
+    settings: *mut obs_data_t,
+    key: *const c_char,
+) -> Option<PathBuf> {
+    unsafe {
+        let path = CStr::from_ptr(obs_data_get_string(settings, key).cast());
+        if path.is_empty() {
+            warn!("No file was chosen for the auto splitter settings.");
+            return None;
+        }
+        Some(PathBuf::from(path.to_string_lossy().into_owned()))
+    }
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_import_settings_clicked(
+    _props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    data: *mut c_void,
+) -> bool {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+        let Some(path) =
+            auto_splitter_settings_file(state.obs_settings, SETTINGS_AUTO_SPLITTER_IMPORT_FILE)
+        else {
+            return false;
+        };
+        let map = match auto_splitter_settings::import(&path) {
+            Ok(map) => map,
+            Err(e) => {
+                error!(
+                    "{:?}",
+                    e.context("Failed importing the auto splitter settings.")
+                );
+                return false;
+            }
+        };
+
+        // Without an auto splitter there are no widgets for the settings yet,
+        // so they are kept with the settings of the source the way the
+        // widgets store them, until it gets loaded.
+        if state.global_timer.auto_splitter.settings_map().is_none() {
+            auto_splitter_store_settings(state.obs_settings, &map);
+            state.auto_splitter_map = map;
+            info!(
+                "Imported the auto splitter settings from `{}`.",
+                path.display()
+            );
+            return false;
+        }
+
+        // The properties get updated once the rendering notices that the
+        // settings changed.
+        if auto_splitter_replace_settings(&state.global_timer, map) {
+            info!(
+                "Imported the auto splitter settings from `{}`.",
+                path.display()
+            );
+        }
+
+        false
+    }
+}
+
+/// Replaces the settings of the auto splitter. Returns whether it's loaded, as
+/// there are no settings to replace otherwise.
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_replace_settings(global_timer: &GlobalTimer, map: settings::Map) -> bool {
+    auto_splitter_update_settings(global_timer, |_| map.clone()).is_some()
+}
+
+/// Updates the settings of the auto splitter based on the current ones. The
+/// auto splitter may change them at the same time, so this is retried with
+/// its changes until they are the ones the update is based on. Returns the
+/// new settings, unless the auto splitter isn't loaded.
+#[cfg(feature = "auto-splitting")]
+fn auto_splitter_update_settings(
+    global_timer: &GlobalTimer,
+    mut update: impl FnMut(&settings::Map) -> settings::Map,
+) -> Option<settings::Map> {
+    loop {
+        let original = global_timer.auto_splitter.settings_map()?;
+        let map = update(&original);
+        if global_timer
+            .auto_splitter
+            .set_settings_map_if_unchanged(&original, map.clone())
+            != Some(false)
+        {
+            return Some(map);
+        }
+    }
+}
+
+/// Stores the settings in the OBS settings of the source. Only the kinds of
+/// values that the widgets use are stored.
+#[cfg(feature = "auto-splitting")]
+unsafe fn auto_splitter_store_settings(settings: *mut obs_data_t, map: &settings::Map) {
+    unsafe {
+        for (key, value) in map.iter() {
+            let Ok(data_key) = CString::new(format!("auto_splitter_setting_{key}")) else {
+                continue;
+            };
+            match value {
+                Value::Bool(value) => obs_data_set_bool(settings, data_key.as_ptr(), *value),
+                Value::String(value) => {
+                    // Files are stored as native paths.
+                    let value = wasi_path::to_native(value, true)
+                        .filter(|path| path.exists())
+                        .and_then(|path| CString::new(path.as_os_str().as_encoded_bytes()).ok())
+                        .or_else(|| CString::new(value.as_bytes()).ok());
+                    if let Some(value) = value {
+                        obs_data_set_string(settings, data_key.as_ptr(), value.as_ptr());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_export_settings_clicked(
+    _props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    data: *mut c_void,
+) -> bool {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+        let Some(path) =
+            auto_splitter_settings_file(state.obs_settings, SETTINGS_AUTO_SPLITTER_SETTINGS_FILE)
+        else {
+            return false;
+        };
+        let map = state
+            .global_timer
+            .auto_splitter
+            .settings_map()
+            .unwrap_or_else(|| state.auto_splitter_map.clone());
+
+        match auto_splitter_settings::export(&path, &map, state.local_auto_splitter.as_deref()) {
+            Ok(()) => info!(
+                "Exported the auto splitter settings to `{}`.",
+                path.display()
+            ),
+            Err(e) => error!(
+                "{:?}",
+                e.context("Failed exporting the auto splitter settings.")
+            ),
+        }
+
+        false
+    }
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe extern "C" fn auto_splitter_refresh_status_clicked(
+    props: *mut obs_properties_t,
+    _prop: *mut obs_property_t,
+    data: *mut c_void,
+) -> bool {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+        auto_splitter_update_status(props, &state.global_timer);
+
+        true
+    }
+}
+
+#[cfg(feature = "auto-splitting")]
+unsafe fn auto_splitter_update_status(props: *mut obs_properties_t, global_timer: &GlobalTimer) {
+    unsafe {
+        let status = auto_splitter_status::describe(
+            global_timer.auto_splitter.settings_map().is_some(),
+            global_timer.auto_splitter_error.lock().unwrap().as_deref(),
+            &global_timer.timer.auto_splitter_log.lock().unwrap(),
+            lang(),
+        );
+        if let Ok(status) = CString::new(status) {
+            obs_property_set_description(
+                obs_properties_get(props, SETTINGS_AUTO_SPLITTER_STATUS),
+                status.as_ptr(),
+            );
+        }
+        obs_property_set_visible(
+            obs_properties_get(props, SETTINGS_AUTO_SPLITTER_ACCEPT_CHANGES),
+            !global_timer
+                .auto_splitter_pin_mismatches
+                .lock()
+                .unwrap()
+                .is_empty(),
+        );
+    }
+}
+
 #[cfg(feature = "auto-splitting")]
 unsafe fn auto_splitter_update_activation_label(
     activate_button_prop: *mut obs_property_t,
@@ -1258,8 +2065,14 @@ unsafe extern "C" fn auto_splitter_open_website(
     unsafe {
         let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
 
-        let website = auto_splitters::get_list()
-            .get_website_for_game(state.global_timer.timer.get_timer().run().game_name());
+        let list = auto_splitters::get_list();
+        let choice = auto_splitters::get_choice(&state.global_timer.timer.path);
+        let website = list
+            .find(
+                state.global_timer.timer.get_timer().run().game_name(),
+                choice.as_deref(),
+            )
+            .and_then(|auto_splitter| auto_splitter.website.as_deref());
 
         match website {
             Some(website) => {
@@ -1394,8 +2207,33 @@ const SETTINGS_AUTO_SPLITTER_INFO: *const c_char = cstr!(c"auto_splitter_info");
 const SETTINGS_AUTO_SPLITTER_ACTIVATE: *const c_char = cstr!(c"auto_splitter_activate");
 #[cfg(feature = "auto-splitting")]
 const SETTINGS_AUTO_SPLITTER_WEBSITE: *const c_char = cstr!(c"auto_splitter_website");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_GAME: *const c_char = cstr!(c"auto_splitter_game");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_REFRESH_LIST: *const c_char = cstr!(c"auto_splitter_refresh_list");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_LISTS: *const c_char = cstr!(c"auto_splitter_lists");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_STATUS: *const c_char = cstr!(c"auto_splitter_status");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_SETTINGS_FILE: *const c_char = cstr!(c"auto_splitter_settings_file");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_IMPORT_FILE: *const c_char = cstr!(c"auto_splitter_import_file");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_IMPORT_SETTINGS: *const c_char =
+    cstr!(c"auto_splitter_import_settings");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_EXPORT_SETTINGS: *const c_char =
+    cstr!(c"auto_splitter_export_settings");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_REFRESH_STATUS: *const c_char = cstr!(c"auto_splitter_refresh_status");
+#[cfg(feature = "auto-splitting")]
+const SETTINGS_AUTO_SPLITTER_ACCEPT_CHANGES: *const c_char = cstr!(c"auto_splitter_accept_changes");
 const SETTINGS_LAYOUT_PATH: *const c_char = cstr!(c"layout_path");
+const SETTINGS_TRANSPARENT_BACKGROUND: *const c_char = cstr!(c"transparent_background");
 const SETTINGS_SAVE_SPLITS: *const c_char = cstr!(c"save_splits");
+const SETTINGS_SAVE_FRAME: *const c_char = cstr!(c"save_frame");
+const SETTINGS_SCENE_SWITCHING_RULES: *const c_char = cstr!(c"scene_switching_rules");
 
 unsafe extern "C" fn get_properties(data: *mut c_void) -> *mut obs_properties_t {
     unsafe {
@@ -1458,6 +2296,39 @@ unsafe extern "C" fn get_properties(data: *mut c_void) -> *mut obs_properties_t
             ptr::null(),
         );
 
+        layout_overrides::add_properties(props);
+
+        obs_properties_add_bool(
+            props,
+            SETTINGS_TRANSPARENT_BACKGROUND,
+            Text::PropertyTransparentBackground.resolve(lang),
+        );
+        drop_shadow::add_properties(props);
+
+        frame_export::add_properties(props);
+        obs_properties_add_button(
+            props,
+            SETTINGS_SAVE_FRAME,
+            Text::PropertySaveFrame.resolve(lang),
+            Some(save_frame),
+        );
+
+        text_export::add_properties(props);
+
+        obs_properties_add_editable_list(
+            props,
+            SETTINGS_SCENE_SWITCHING_RULES,
+            Text::PropertySceneSwitchingRules.resolve(lang),
+            OBS_EDITABLE_LIST_TYPE_STRINGS,
+            ptr::null(),
+            ptr::null(),
+        );
+
+        recording_control::add_properties(props);
+        chapters::add_properties(props);
+        timestamp_log::add_properties(props);
+        control_panel::add_properties(props);
+
         let use_game_arguments = obs_properties_add_bool(
             props,
             SETTINGS_USE_GAME_ARGUMENTS,
@@ -1538,7 +2409,17 @@ unsafe extern "C" fn get_properties(data: *mut c_void) -> *mut obs_properties_t
                 ptr::null(),
             );
 
-            let info_text = obs_properties_add_text(
+            let game_list = obs_properties_add_list(
+                props,
+                SETTINGS_AUTO_SPLITTER_GAME,
+                Text::AutoSplitterGame.resolve(lang),
+                OBS_COMBO_TYPE_EDITABLE,
+                OBS_COMBO_FORMAT_STRING,
+            );
+
+            obs_property_set_modified_callback2(game_list, Some(auto_splitter_game_modified), data);
+
+            obs_properties_add_text(
                 props,
                 SETTINGS_AUTO_SPLITTER_INFO,
                 Text::AutoSplitterNoSplitsLoaded.resolve(lang),
@@ -1554,31 +2435,74 @@ unsafe extern "C" fn get_properties(data: *mut c_void) -> *mut obs_properties_t
                 false => Text::AutoSplitterActivate.resolve(lang),
             };
 
-            let activate_button = obs_properties_add_button(
+            obs_properties_add_button(
                 props,
                 SETTINGS_AUTO_SPLITTER_ACTIVATE,
                 activate_button_text,
                 Some(auto_splitter_activate_clicked),
             );
 
-            let website_button = obs_properties_add_button(
+            obs_properties_add_button(
                 props,
                 SETTINGS_AUTO_SPLITTER_WEBSITE,
                 Text::AutoSplitterWebsite.resolve(lang),
                 Some(auto_splitter_open_website),
             );
 
-            update_auto_splitter_ui(
-                info_text,
-                website_button,
-                activate_button,
-                state.global_timer.timer.get_timer().run().game_name(),
+            let lists = obs_properties_add_text(
+                props,
+                SETTINGS_AUTO_SPLITTER_LISTS,
+                Text::AutoSplitterLists.resolve(lang),
+                OBS_TEXT_MULTILINE,
+            );
+            obs_property_set_long_description(
+                lists,
+                Text::AutoSplitterListsDescription.resolve(lang),
+            );
+            obs_property_set_modified_callback2(lists, Some(auto_splitter_lists_modified), data);
+
+            obs_properties_add_button(
+                props,
+                SETTINGS_AUTO_SPLITTER_REFRESH_LIST,
+                Text::AutoSplitterRefreshList.resolve(lang),
+                Some(auto_splitter_refresh_list_clicked),
+            );
+
+            let status = obs_properties_create();
+            obs_properties_add_text(
+                status,
+                SETTINGS_AUTO_SPLITTER_STATUS,
+                Text::AutoSplitterStatusNotLoaded.resolve(lang),
+                OBS_TEXT_INFO,
+            );
+            obs_properties_add_button(
+                status,
+                SETTINGS_AUTO_SPLITTER_REFRESH_STATUS,
+                Text::AutoSplitterRefreshStatus.resolve(lang),
+                Some(auto_splitter_refresh_status_clicked),
+            );
+            obs_properties_add_button(
+                status,
+                SETTINGS_AUTO_SPLITTER_ACCEPT_CHANGES,
+                Text::AutoSplitterAcceptChanges.resolve(lang),
+                Some(auto_splitter_accept_changes_clicked),
+            );
+            obs_properties_add_group(
+                props,
+                cstr!(c"auto_splitter_status_group"),
+                Text::AutoSplitterStatus.resolve(lang),
+                OBS_GROUP_NORMAL,
+                status,
             );
+            auto_splitter_update_status(props, &state.global_timer);
 
             let uses_local_auto_splitter = state.local_auto_splitter.is_some();
-            obs_property_set_visible(info_text, !uses_local_auto_splitter);
-            obs_property_set_visible(activate_button, !uses_local_auto_splitter);
-            obs_property_set_visible(website_button, !uses_local_auto_splitter);
+            update_auto_splitter_ui(
+                props,
+                &state.global_timer,
+                uses_local_auto_splitter,
+                state.obs_settings,
+            );
 
             obs_property_set_visible(local_auto_splitter_path, uses_local_auto_splitter);
 
@@ -1769,6 +2693,36 @@ unsafe extern "C" fn get_properties(data: *mut c_void) -> *mut obs_properties_t
                 }
             }
 
+            // The settings can be shared with other sources and LiveSplit.
+            obs_properties_add_path(
+                auto_splitter_properties,
+                SETTINGS_AUTO_SPLITTER_IMPORT_FILE,
+                Text::AutoSplitterImportFile.resolve(lang),
+                OBS_PATH_FILE,
+                Text::AutoSplitterSettingsFileFilter.resolve(lang),
+                ptr::null(),
+            );
+            obs_properties_add_button(
+                auto_splitter_properties,
+                SETTINGS_AUTO_SPLITTER_IMPORT_SETTINGS,
+                Text::AutoSplitterImportSettings.resolve(lang),
+                Some(auto_splitter_import_settings_clicked),
+            );
+            obs_properties_add_path(
+                auto_splitter_properties,
+                SETTINGS_AUTO_SPLITTER_SETTINGS_FILE,
+                Text::AutoSplitterSettingsFile.resolve(lang),
+                OBS_PATH_FILE_SAVE,
+                Text::AutoSplitterSettingsFileFilter.resolve(lang),
+                ptr::null(),
+            );
+            obs_properties_add_button(
+                auto_splitter_properties,
+                SETTINGS_AUTO_SPLITTER_EXPORT_SETTINGS,
+                Text::AutoSplitterExportSettings.resolve(lang),
+                Some(auto_splitter_export_settings_clicked),
+            );
+
             obs_properties_add_group(
                 props,
                 cstr!(c"auto_splitter_settings_group"),
@@ -1787,6 +2741,15 @@ unsafe extern "C" fn get_defaults(settings: *mut obs_data_t) {
         obs_data_set_default_int(settings, SETTINGS_WIDTH, 300);
         obs_data_set_default_int(settings, SETTINGS_HEIGHT, 500);
         obs_data_set_default_bool(settings, SETTINGS_AUTO_SAVE, false);
+        obs_data_set_default_bool(settings, SETTINGS_TRANSPARENT_BACKGROUND, false);
+        layout_overrides::get_defaults(settings);
+        drop_shadow::get_defaults(settings);
+        frame_export::get_defaults(settings);
+        text_export::get_defaults(settings);
+        recording_control::get_defaults(settings);
+        chapters::get_defaults(settings);
+        timestamp_log::get_defaults(settings);
+        control_panel::get_defaults(settings);
     }
 }
 
@@ -1831,7 +2794,32 @@ unsafe extern "C" fn update(data: *mut c_void, settings_obj: *mut obs_data_t) {
             .timer
             .auto_save
             .store(settings.auto_save, atomic::Ordering::Relaxed);
+        EventWorker::update(
+            &mut state.scene_switcher,
+            settings.scene_switching_rules,
+            &state.global_timer.timer,
+        );
+        EventWorker::update(
+            &mut state.recording_control,
+            settings.recording_options,
+            &state.global_timer.timer,
+        );
+        EventWorker::update(
+            &mut state.chapters,
+            settings.chapter_options,
+            &state.global_timer.timer,
+        );
+        EventWorker::update(
+            &mut state.timestamp_log,
+            settings.timestamp_log_options,
+            &state.global_timer.timer,
+        );
+        ControlPanel::update(&mut state.control_panel, settings.control_panel_options);
         state.layout = settings.layout;
+        state.transparent_background = settings.transparent_background;
+        state.drop_shadow = settings.drop_shadow;
+        state.frame_export.update(settings.frame_export);
+        state.text_export = settings.text_export;
 
         #[cfg(feature = "auto-splitting")]
         {
@@ -1845,13 +2833,17 @@ unsafe extern "C" fn update(data: *mut c_void, settings_obj: *mut obs_data_t) {
                 }
             }
 
-            loop {
-                let Some(original) = state.global_timer.auto_splitter.settings_map() else {
-                    break;
-                };
+            // The splits may have changed to ones whose auto splitter was
+            // active the last time.
+            if state.local_auto_splitter.is_none() {
+                auto_splitter_restore(&state.global_timer);
+            }
+
+            let widgets = &state.auto_splitter_widgets;
+            let map = auto_splitter_update_settings(&state.global_timer, |original| {
                 let mut map = original.clone();
 
-                for widget in state.auto_splitter_widgets.iter() {
+                for widget in widgets.iter() {
                     let key = &widget.key;
                     let Ok(data_key) = CString::new(format!("auto_splitter_setting_{}", key))
                     else {
@@ -1897,16 +2889,10 @@ unsafe extern "C" fn update(data: *mut c_void, settings_obj: *mut obs_data_t) {
                         }
                     }
                 }
-
-                if state
-                    .global_timer
-                    .auto_splitter
-                    .set_settings_map_if_unchanged(&original, map.clone())
-                    != Some(false)
-                {
-                    state.auto_splitter_map = map;
-                    break;
-                }
+                map
+            });
+            if let Some(map) = map {
+                state.auto_splitter_map = map;
             }
         }
 
@@ -1931,20 +2917,38 @@ unsafe extern "C" fn update(data: *mut c_void, settings_obj: *mut obs_data_t) {
 }
 
 fn handle_splits_path_change(state: &mut State, splits_path: PathBuf) {
-    state.global_timer = get_global_timer(splits_path);
+    let global_timer = get_global_timer(splits_path);
+    if !Arc::ptr_eq(&state.global_timer, &global_timer) {
+        state.timer_signals = TimerSignals::new(&global_timer);
+        // The workers are started again for the events of the new timer.
+        state.scene_switcher = None;
+        state.recording_control = None;
+        state.chapters = None;
+        state.timestamp_log = None;
+    }
+    state.global_timer = global_timer;
+}
+
+/// Looks for a timer that is already loaded for the splits, without loading
+/// one if there is none.
+fn find_global_timer(splits_path: &Path) -> Option<Arc<GlobalTimer>> {
+    find_loaded_timer(&mut TIMERS.lock().unwrap(), splits_path)
+}
+
+fn find_loaded_timer(
+    timers: &mut Vec<Weak<GlobalTimer>>,
+    splits_path: &Path,
+) -> Option<Arc<GlobalTimer>> {
+    timers.retain(|timer| timer.strong_count() > 0);
+    timers
+        .iter()
+        .filter_map(|timer| timer.upgrade())
+        .find(|timer| timer.timer.path == splits_path)
 }
 
 fn get_global_timer(splits_path: PathBuf) -> Arc<GlobalTimer> {
     let mut timers = TIMERS.lock().unwrap();
-    timers.retain(|timer| timer.strong_count() > 0);
-    if let Some(timer) = timers.iter().find_map(|timer| {
-        let timer = timer.upgrade()?;
-        if timer.timer.path == splits_path {
-            Some(timer)
-        } else {
-            None
-        }
-    }) {
+    if let Some(timer) = find_loaded_timer(&mut timers, &splits_path) {
         debug!("Found timer to reuse.");
         timer
     } else {
@@ -1957,19 +2961,62 @@ fn get_global_timer(splits_path: PathBuf) -> Arc<GlobalTimer> {
             timer: Arc::new(InnerTimer {
                 timer: RwLock::new(timer),
                 auto_save: AtomicBool::new(false),
+                events: TimerEvents::default(),
                 path: splits_path,
                 can_save_splits,
+                #[cfg(feature = "auto-splitting")]
+                auto_splitter_log: Arc::default(),
             }),
             #[cfg(feature = "auto-splitting")]
             auto_splitter,
             #[cfg(feature = "auto-splitting")]
             auto_splitter_is_enabled: AtomicBool::new(false),
+            #[cfg(feature = "auto-splitting")]
+            auto_splitter_restore: Once::new(),
+            #[cfg(feature = "auto-splitting")]
+            auto_splitter_generation: AtomicUsize::new(0),
+            #[cfg(feature = "auto-splitting")]
+            local_auto_splitter_watcher: Mutex::default(),
+            #[cfg(feature = "auto-splitting")]
+            auto_splitter_error: Mutex::new(None),
+            #[cfg(feature = "auto-splitting")]
+            auto_splitter_pin_mismatches: Mutex::default(),
+            #[cfg(feature = "auto-splitting")]
+            auto_splitter_reloads: Mutex::new(None),
         });
         timers.push(Arc::downgrade(&global_timer));
         global_timer
     }
 }
 
+/// Adds a list that offers the splits of all the timers that are currently
+/// loaded. Any other splits can be entered as well if the list is editable.
+unsafe fn add_loaded_splits_list(
+    props: *mut obs_properties_t,
+    name: *const c_char,
+    combo_type: obs_combo_type,
+) -> *mut obs_property_t {
+    unsafe {
+        let splits_path = obs_properties_add_list(
+            props,
+            name,
+            Text::PropertySplits.resolve(lang()),
+            combo_type,
+            OBS_COMBO_FORMAT_STRING,
+        );
+
+        let mut timers = TIMERS.lock().unwrap();
+        timers.retain(|timer| timer.strong_count() > 0);
+        for timer in timers.iter().filter_map(|timer| timer.upgrade()) {
+            if let Ok(path) = CString::new(timer.timer.path.as_os_str().as_encoded_bytes()) {
+                obs_property_list_add_string(splits_path, path.as_ptr(), path.as_ptr());
+            }
+        }
+
+        splits_path
+    }
+}
+
 struct ObsLog;
 
 impl Log for ObsLog {
@@ -1979,6 +3026,8 @@ impl Log for ObsLog {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
+            #[cfg(feature = "auto-splitting")]
+            auto_splitter_status::capture(record);
             log(record.level(), record.target(), record.args());
         }
     }
@@ -2010,7 +3059,7 @@ pub extern "C" fn obs_module_load() -> bool {
         deactivate: Some(deactivate),
         show: None,
         hide: None,
-        video_tick: None,
+        video_tick: Some(video_tick),
         filter_video: None,
         filter_audio: None,
         enum_active_sources: None,
@@ -2056,12 +3105,35 @@ pub extern "C" fn obs_module_load() -> bool {
         obs_register_source_s(source_info, mem::size_of_val(source_info) as _);
     }
 
+    text_source::register();
+    audio_cues::register();
+    timer_event_filter::register();
+
+    unsafe {
+        recording_control::register();
+        chapters::register();
+    }
+
     #[cfg(feature = "auto-splitting")]
     auto_splitters::set_up();
 
     true
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn obs_module_unload() {
+    unsafe {
+        recording_control::unregister();
+        chapters::unregister();
+    }
+
+    #[cfg(feature = "auto-splitting")]
+    {
+        auto_splitters::shut_down();
+        auto_splitter_join_threads();
+    }
+}
+
 #[cfg(feature = "auto-splitting")]
 fn build_filter(filters: &[FileFilter], output: &mut Vec<u8>) {
     for filter in filters.iter() {
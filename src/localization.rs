@@ -60,6 +60,92 @@ pub enum Text {
     AutoSplitterSettingsGroup,
     AutoSplitterIncompatible,
     AutoSplitterUnavailable,
+    PropertyOverrideBackground,
+    PropertyOverrideTextColor,
+    PropertyOverrideTimerFont,
+    PropertyOverrideTextFont,
+    PropertyOverrideTimesFont,
+    PropertyColor,
+    PropertyFont,
+    PropertyTransparentBackground,
+    PropertyDropShadow,
+    PropertyHorizontalOffset,
+    PropertyVerticalOffset,
+    PropertyBlurRadius,
+    PropertyExportDirectory,
+    PropertyExportImageSequence,
+    PropertyFramesPerSecond,
+    PropertySaveFrame,
+    PropertyTextExport,
+    PropertyUpdateInterval,
+    TextSourceName,
+    PropertyTimerValue,
+    PropertyTextColor,
+    PropertyBackgroundColor,
+    TimerValueCurrentTime,
+    TimerValueCurrentSplit,
+    TimerValueDelta,
+    TimerValueComparison,
+    TimerValuePersonalBest,
+    TimerValueSumOfBest,
+    TimerValueAttempts,
+    TimerEventFilterName,
+    PropertyIdleColor,
+    PropertyDuration,
+    TriggerStart,
+    TriggerAhead,
+    TriggerBehind,
+    TriggerGold,
+    TriggerPersonalBest,
+    TriggerReset,
+    PropertySceneSwitchingRules,
+    PropertyRecordingControl,
+    PropertyRecordOnStart,
+    PropertyStopRecordingOnReset,
+    PropertyStopRecordingOnFinish,
+    PropertySaveReplayOnGold,
+    PropertySaveReplayOnPersonalBest,
+    PropertyNameRecordings,
+    PropertyChapters,
+    PropertyChapterMarkers,
+    PropertyChaptersDirectory,
+    PropertyTimestampLog,
+    PropertyFileFormat,
+    AudioCuesSourceName,
+    PropertyVolume,
+    PropertySoundFilter,
+    CueSplit,
+    CueGold,
+    CueBehind,
+    CuePersonalBest,
+    CueUndo,
+    CueReset,
+    PropertyControlPanel,
+    PropertyPort,
+    PropertyAllowOtherComputers,
+    AutoSplitterRefreshList,
+    AutoSplitterGame,
+    AutoSplitterAutomatic,
+    AutoSplitterLists,
+    AutoSplitterListsDescription,
+    AutoSplitterStatus,
+    AutoSplitterStatusLoaded,
+    AutoSplitterStatusNotLoaded,
+    AutoSplitterStatusProcess,
+    AutoSplitterStatusTickRate,
+    AutoSplitterStatusLastError,
+    AutoSplitterStatusUnknown,
+    AutoSplitterRefreshStatus,
+    AutoSplitterSettingsFile,
+    AutoSplitterSettingsFileFilter,
+    AutoSplitterImportSettings,
+    AutoSplitterExportSettings,
+    PropertyCustomVariableFiles,
+    PropertyAccessToken,
+    AutoSplitterChooseGame,
+    AutoSplitterImportFile,
+    PropertyControlPanelAddress,
+    AutoSplitterAcceptChanges,
 }
 
 impl Text {
@@ -122,6 +208,96 @@ fn resolve_english(text: Text) -> *const c_char {
             cstr!(c"This game's auto splitter is incompatible with LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"No auto splitter available for this game."),
+        Text::PropertyOverrideBackground => cstr!(c"Override Background"),
+        Text::PropertyOverrideTextColor => cstr!(c"Override Text Color"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Override Timer Font"),
+        Text::PropertyOverrideTextFont => cstr!(c"Override Text Font"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Override Times Font"),
+        Text::PropertyColor => cstr!(c"Color"),
+        Text::PropertyFont => cstr!(c"Font"),
+        Text::PropertyTransparentBackground => cstr!(c"Transparent Background"),
+        Text::PropertyDropShadow => cstr!(c"Drop Shadow"),
+        Text::PropertyHorizontalOffset => cstr!(c"Horizontal Offset"),
+        Text::PropertyVerticalOffset => cstr!(c"Vertical Offset"),
+        Text::PropertyBlurRadius => cstr!(c"Blur Radius"),
+        Text::PropertyExportDirectory => cstr!(c"Export Folder"),
+        Text::PropertyExportImageSequence => cstr!(c"Record Image Sequence of Attempts"),
+        Text::PropertyFramesPerSecond => cstr!(c"Frames per Second"),
+        Text::PropertySaveFrame => cstr!(c"Save Frame as PNG"),
+        Text::PropertyTextExport => cstr!(c"Write Timer Values to Text Files"),
+        Text::PropertyUpdateInterval => cstr!(c"Update Interval (ms)"),
+        Text::TextSourceName => cstr!(c"LiveSplit One Text"),
+        Text::PropertyTimerValue => cstr!(c"Value"),
+        Text::PropertyTextColor => cstr!(c"Text Color"),
+        Text::PropertyBackgroundColor => cstr!(c"Background Color"),
+        Text::TimerValueCurrentTime => cstr!(c"Current Time"),
+        Text::TimerValueCurrentSplit => cstr!(c"Current Split"),
+        Text::TimerValueDelta => cstr!(c"Delta"),
+        Text::TimerValueComparison => cstr!(c"Comparison"),
+        Text::TimerValuePersonalBest => cstr!(c"Personal Best"),
+        Text::TimerValueSumOfBest => cstr!(c"Sum of Best Segments"),
+        Text::TimerValueAttempts => cstr!(c"Attempts"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One Timer Events"),
+        Text::PropertyIdleColor => cstr!(c"Color Without Event"),
+        Text::PropertyDuration => cstr!(c"Duration (seconds, 0 = until the next event)"),
+        Text::TriggerStart => cstr!(c"On Start"),
+        Text::TriggerAhead => cstr!(c"On Split Ahead"),
+        Text::TriggerBehind => cstr!(c"On Split Behind"),
+        Text::TriggerGold => cstr!(c"On Best Segment"),
+        Text::TriggerPersonalBest => cstr!(c"On Personal Best"),
+        Text::TriggerReset => cstr!(c"On Reset"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Scene Switching Rules (TRIGGER -> scene / show / hide NAME)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Control Recording"),
+        Text::PropertyRecordOnStart => cstr!(c"Start Recording When the Timer Starts"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Stop Recording on Reset"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Stop Recording When the Run Finishes"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Save Replay on Gold Splits"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"Save Replay on Personal Bests"),
+        Text::PropertyNameRecordings => {
+            cstr!(c"Name Recordings After the Game, Category and Attempt")
+        }
+        Text::PropertyChapters => cstr!(c"Chapters at Each Split"),
+        Text::PropertyChapterMarkers => cstr!(c"Add Chapter Markers to the Recording"),
+        Text::PropertyChaptersDirectory => cstr!(c"Chapter Files Directory"),
+        Text::PropertyTimestampLog => cstr!(c"Log Timestamps of Each Attempt"),
+        Text::PropertyFileFormat => cstr!(c"File Format"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One Audio Cues"),
+        Text::PropertyVolume => cstr!(c"Volume (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave Files (*.wav)"),
+        Text::CueSplit => cstr!(c"Split Sound"),
+        Text::CueGold => cstr!(c"Gold Split Sound"),
+        Text::CueBehind => cstr!(c"Behind Pace Sound"),
+        Text::CuePersonalBest => cstr!(c"Personal Best Sound"),
+        Text::CueUndo => cstr!(c"Undo Split Sound"),
+        Text::CueReset => cstr!(c"Reset Sound"),
+        Text::PropertyControlPanel => cstr!(c"Control Panel for Browser Docks"),
+        Text::PropertyPort => cstr!(c"Port"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Allow Access from Other Computers"),
+        Text::AutoSplitterRefreshList => cstr!(c"Refresh List"),
+        Text::AutoSplitterGame => cstr!(c"Game"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automatic"),
+        Text::AutoSplitterLists => cstr!(c"Auto Splitter Lists"),
+        Text::AutoSplitterListsDescription => cstr!(c"One list per line. Alternative locations of the same list, like mirrors, are separated by |. A location is a URL, a file or a folder of list files. Lists further down take precedence. Refresh the list to apply changes."),
+        Text::AutoSplitterStatus => cstr!(c"Auto Splitter Status"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"The auto splitter is loaded."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"No auto splitter is loaded."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Process"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Tick rate"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Last error"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Unknown"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Refresh Status"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Settings File"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Auto Splitter Settings (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Import Settings"),
+        Text::AutoSplitterExportSettings => cstr!(c"Export Settings"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Custom Variables ({name} is the Variable)"),
+        Text::PropertyAccessToken => cstr!(c"Access Token (Part of the Address)"),
+        Text::AutoSplitterChooseGame => cstr!(c"The game of the splits doesn't match any auto splitter exactly. Choose the game from the list."),
+        Text::AutoSplitterImportFile => cstr!(c"Settings File to Import"),
+        Text::PropertyControlPanelAddress => cstr!(c"Address for the Browser Dock"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Accept Changed Auto Splitter"),
     }
 }
 
@@ -166,6 +342,94 @@ fn resolve_dutch(text: Text) -> *const c_char {
             cstr!(c"De auto-splitter van dit spel is niet compatibel met LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"Geen auto-splitter beschikbaar voor dit spel."),
+        Text::PropertyOverrideBackground => cstr!(c"Achtergrond overschrijven"),
+        Text::PropertyOverrideTextColor => cstr!(c"Tekstkleur overschrijven"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Timerlettertype overschrijven"),
+        Text::PropertyOverrideTextFont => cstr!(c"Tekstlettertype overschrijven"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Tijdenlettertype overschrijven"),
+        Text::PropertyColor => cstr!(c"Kleur"),
+        Text::PropertyFont => cstr!(c"Lettertype"),
+        Text::PropertyTransparentBackground => cstr!(c"Transparante achtergrond"),
+        Text::PropertyDropShadow => cstr!(c"Slagschaduw"),
+        Text::PropertyHorizontalOffset => cstr!(c"Horizontale verschuiving"),
+        Text::PropertyVerticalOffset => cstr!(c"Verticale verschuiving"),
+        Text::PropertyBlurRadius => cstr!(c"Vervagingsstraal"),
+        Text::PropertyExportDirectory => cstr!(c"Exportmap"),
+        Text::PropertyExportImageSequence => cstr!(c"Beeldreeks van pogingen opnemen"),
+        Text::PropertyFramesPerSecond => cstr!(c"Beelden per seconde"),
+        Text::PropertySaveFrame => cstr!(c"Beeld opslaan als PNG"),
+        Text::PropertyTextExport => cstr!(c"Timerwaarden naar tekstbestanden schrijven"),
+        Text::PropertyUpdateInterval => cstr!(c"Update-interval (ms)"),
+        Text::TextSourceName => cstr!(c"LiveSplit One-tekst"),
+        Text::PropertyTimerValue => cstr!(c"Waarde"),
+        Text::PropertyTextColor => cstr!(c"Tekstkleur"),
+        Text::PropertyBackgroundColor => cstr!(c"Achtergrondkleur"),
+        Text::TimerValueCurrentTime => cstr!(c"Huidige tijd"),
+        Text::TimerValueCurrentSplit => cstr!(c"Huidige split"),
+        Text::TimerValueDelta => cstr!(c"Verschil"),
+        Text::TimerValueComparison => cstr!(c"Vergelijking"),
+        Text::TimerValuePersonalBest => cstr!(c"Persoonlijk record"),
+        Text::TimerValueSumOfBest => cstr!(c"Som van beste segmenten"),
+        Text::TimerValueAttempts => cstr!(c"Pogingen"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One-timergebeurtenissen"),
+        Text::PropertyIdleColor => cstr!(c"Kleur zonder gebeurtenis"),
+        Text::PropertyDuration => cstr!(c"Duur (seconden, 0 = tot de volgende gebeurtenis)"),
+        Text::TriggerStart => cstr!(c"Bij start"),
+        Text::TriggerAhead => cstr!(c"Bij split voor"),
+        Text::TriggerBehind => cstr!(c"Bij split achter"),
+        Text::TriggerGold => cstr!(c"Bij beste segment"),
+        Text::TriggerPersonalBest => cstr!(c"Bij persoonlijk record"),
+        Text::TriggerReset => cstr!(c"Bij reset"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Regels voor scènewissels (TRIGGER -> scene / show / hide NAAM)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Opname besturen"),
+        Text::PropertyRecordOnStart => cstr!(c"Opname starten wanneer de timer start"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Opname stoppen bij reset"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Opname stoppen wanneer de run eindigt"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Replay opslaan bij gouden splits"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"Replay opslaan bij persoonlijke records"),
+        Text::PropertyNameRecordings => cstr!(c"Opnames vernoemen naar spel, categorie en poging"),
+        Text::PropertyChapters => cstr!(c"Hoofdstukken bij elke split"),
+        Text::PropertyChapterMarkers => cstr!(c"Hoofdstukmarkeringen aan de opname toevoegen"),
+        Text::PropertyChaptersDirectory => cstr!(c"Map voor hoofdstukbestanden"),
+        Text::PropertyTimestampLog => cstr!(c"Tijdstempels van elke poging loggen"),
+        Text::PropertyFileFormat => cstr!(c"Bestandsformaat"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One-geluidssignalen"),
+        Text::PropertyVolume => cstr!(c"Volume (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave-bestanden (*.wav)"),
+        Text::CueSplit => cstr!(c"Geluid bij split"),
+        Text::CueGold => cstr!(c"Geluid bij gouden split"),
+        Text::CueBehind => cstr!(c"Geluid bij achterstand"),
+        Text::CuePersonalBest => cstr!(c"Geluid bij persoonlijk record"),
+        Text::CueUndo => cstr!(c"Geluid bij split ongedaan maken"),
+        Text::CueReset => cstr!(c"Geluid bij reset"),
+        Text::PropertyControlPanel => cstr!(c"Bedieningspaneel voor browserdocks"),
+        Text::PropertyPort => cstr!(c"Poort"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Toegang vanaf andere computers toestaan"),
+        Text::AutoSplitterRefreshList => cstr!(c"Lijst vernieuwen"),
+        Text::AutoSplitterGame => cstr!(c"Spel"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automatisch"),
+        Text::AutoSplitterLists => cstr!(c"Autosplitterlijsten"),
+        Text::AutoSplitterListsDescription => cstr!(c"Eén lijst per regel. Alternatieve locaties van dezelfde lijst, zoals mirrors, worden gescheiden door |. Een locatie is een URL, een bestand of een map met lijstbestanden. Lijsten verder naar beneden hebben voorrang. Vernieuw de lijst om wijzigingen toe te passen."),
+        Text::AutoSplitterStatus => cstr!(c"Status van de autosplitter"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"De autosplitter is geladen."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Er is geen autosplitter geladen."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Proces"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Tickrate"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Laatste fout"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Onbekend"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Status vernieuwen"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Instellingenbestand"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Autosplitterinstellingen (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Instellingen importeren"),
+        Text::AutoSplitterExportSettings => cstr!(c"Instellingen exporteren"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Aangepaste variabelen ({name} is de variabele)"),
+        Text::PropertyAccessToken => cstr!(c"Toegangstoken (deel van het adres)"),
+        Text::AutoSplitterChooseGame => cstr!(c"Het spel van de splits komt niet exact overeen met een auto-splitter. Kies het spel uit de lijst."),
+        Text::AutoSplitterImportFile => cstr!(c"Te importeren instellingenbestand"),
+        Text::PropertyControlPanelAddress => cstr!(c"Adres voor het browserdock"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Gewijzigde autosplitter accepteren"),
     }
 }
 
@@ -212,6 +476,110 @@ fn resolve_french(text: Text) -> *const c_char {
             cstr!(c"L'auto-splitter de ce jeu est incompatible avec LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"Aucun auto-splitter disponible pour ce jeu."),
+        Text::PropertyOverrideBackground => cstr!(c"Remplacer l'arrière-plan"),
+        Text::PropertyOverrideTextColor => cstr!(c"Remplacer la couleur du texte"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Remplacer la police du chronomètre"),
+        Text::PropertyOverrideTextFont => cstr!(c"Remplacer la police du texte"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Remplacer la police des temps"),
+        Text::PropertyColor => cstr!(c"Couleur"),
+        Text::PropertyFont => cstr!(c"Police"),
+        Text::PropertyTransparentBackground => cstr!(c"Arrière-plan transparent"),
+        Text::PropertyDropShadow => cstr!(c"Ombre portée"),
+        Text::PropertyHorizontalOffset => cstr!(c"Décalage horizontal"),
+        Text::PropertyVerticalOffset => cstr!(c"Décalage vertical"),
+        Text::PropertyBlurRadius => cstr!(c"Rayon de flou"),
+        Text::PropertyExportDirectory => cstr!(c"Dossier d'exportation"),
+        Text::PropertyExportImageSequence => {
+            cstr!(c"Enregistrer une séquence d'images des tentatives")
+        }
+        Text::PropertyFramesPerSecond => cstr!(c"Images par seconde"),
+        Text::PropertySaveFrame => cstr!(c"Enregistrer l'image en PNG"),
+        Text::PropertyTextExport => {
+            cstr!(c"Écrire les valeurs du chronomètre dans des fichiers texte")
+        }
+        Text::PropertyUpdateInterval => cstr!(c"Intervalle de mise à jour (ms)"),
+        Text::TextSourceName => cstr!(c"Texte LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Valeur"),
+        Text::PropertyTextColor => cstr!(c"Couleur du texte"),
+        Text::PropertyBackgroundColor => cstr!(c"Couleur d'arrière-plan"),
+        Text::TimerValueCurrentTime => cstr!(c"Temps actuel"),
+        Text::TimerValueCurrentSplit => cstr!(c"Segment actuel"),
+        Text::TimerValueDelta => cstr!(c"Écart"),
+        Text::TimerValueComparison => cstr!(c"Comparaison"),
+        Text::TimerValuePersonalBest => cstr!(c"Record personnel"),
+        Text::TimerValueSumOfBest => cstr!(c"Somme des meilleurs segments"),
+        Text::TimerValueAttempts => cstr!(c"Tentatives"),
+        Text::TimerEventFilterName => cstr!(c"Événements du chronomètre LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Couleur sans événement"),
+        Text::PropertyDuration => cstr!(c"Durée (secondes, 0 = jusqu'au prochain événement)"),
+        Text::TriggerStart => cstr!(c"Au démarrage"),
+        Text::TriggerAhead => cstr!(c"Segment en avance"),
+        Text::TriggerBehind => cstr!(c"Segment en retard"),
+        Text::TriggerGold => cstr!(c"Meilleur segment"),
+        Text::TriggerPersonalBest => cstr!(c"Record personnel"),
+        Text::TriggerReset => cstr!(c"À la réinitialisation"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Règles de changement de scène (TRIGGER -> scene / show / hide NOM)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Contrôler l'enregistrement"),
+        Text::PropertyRecordOnStart => cstr!(c"Démarrer l'enregistrement au lancement du chrono"),
+        Text::PropertyStopRecordingOnReset => {
+            cstr!(c"Arrêter l'enregistrement à la réinitialisation")
+        }
+        Text::PropertyStopRecordingOnFinish => {
+            cstr!(c"Arrêter l'enregistrement à la fin de la run")
+        }
+        Text::PropertySaveReplayOnGold => cstr!(c"Sauvegarder le replay sur les splits dorés"),
+        Text::PropertySaveReplayOnPersonalBest => {
+            cstr!(c"Sauvegarder le replay sur les records personnels")
+        }
+        Text::PropertyNameRecordings => {
+            cstr!(c"Nommer les enregistrements d'après le jeu, la catégorie et la tentative")
+        }
+        Text::PropertyChapters => cstr!(c"Chapitres à chaque split"),
+        Text::PropertyChapterMarkers => {
+            cstr!(c"Ajouter des marqueurs de chapitre à l'enregistrement")
+        }
+        Text::PropertyChaptersDirectory => cstr!(c"Dossier des fichiers de chapitres"),
+        Text::PropertyTimestampLog => cstr!(c"Journaliser les horodatages de chaque tentative"),
+        Text::PropertyFileFormat => cstr!(c"Format de fichier"),
+        Text::AudioCuesSourceName => cstr!(c"Signaux sonores LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Volume (%)"),
+        Text::PropertySoundFilter => cstr!(c"Fichiers Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Son de split"),
+        Text::CueGold => cstr!(c"Son de split doré"),
+        Text::CueBehind => cstr!(c"Son de retard"),
+        Text::CuePersonalBest => cstr!(c"Son de record personnel"),
+        Text::CueUndo => cstr!(c"Son d'annulation de split"),
+        Text::CueReset => cstr!(c"Son de réinitialisation"),
+        Text::PropertyControlPanel => cstr!(c"Panneau de contrôle pour les docks de navigateur"),
+        Text::PropertyPort => cstr!(c"Port"),
+        Text::PropertyAllowOtherComputers => {
+            cstr!(c"Autoriser l'accès depuis d'autres ordinateurs")
+        }
+        Text::AutoSplitterRefreshList => cstr!(c"Actualiser la liste"),
+        Text::AutoSplitterGame => cstr!(c"Jeu"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automatique"),
+        Text::AutoSplitterLists => cstr!(c"Listes d'auto splitters"),
+        Text::AutoSplitterListsDescription => cstr!(c"Une liste par ligne. Les emplacements alternatifs d'une même liste, comme les miroirs, sont séparés par |. Un emplacement est une URL, un fichier ou un dossier de fichiers de liste. Les listes plus bas ont la priorité. Actualisez la liste pour appliquer les modifications."),
+        Text::AutoSplitterStatus => cstr!(c"État de l'auto splitter"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"L'auto splitter est chargé."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Aucun auto splitter n'est chargé."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Processus"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Fréquence de mise à jour"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Dernière erreur"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Inconnu"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Actualiser l'état"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Fichier de paramètres"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Paramètres d'auto splitter (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Importer les paramètres"),
+        Text::AutoSplitterExportSettings => cstr!(c"Exporter les paramètres"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Variables personnalisées ({name} est la variable)"),
+        Text::PropertyAccessToken => cstr!(c"Jeton d'accès (partie de l'adresse)"),
+        Text::AutoSplitterChooseGame => cstr!(c"Le jeu des splits ne correspond exactement à aucun auto-splitter. Choisissez le jeu dans la liste."),
+        Text::AutoSplitterImportFile => cstr!(c"Fichier de paramètres à importer"),
+        Text::PropertyControlPanelAddress => cstr!(c"Adresse pour le dock de navigateur"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Accepter l'auto splitter modifié"),
     }
 }
 
@@ -256,6 +624,98 @@ fn resolve_german(text: Text) -> *const c_char {
         Text::AutoSplitterUnavailable => {
             cstr!(c"Für dieses Spiel ist kein Auto-Splitter verfügbar.")
         }
+        Text::PropertyOverrideBackground => cstr!(c"Hintergrund überschreiben"),
+        Text::PropertyOverrideTextColor => cstr!(c"Textfarbe überschreiben"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Timer-Schriftart überschreiben"),
+        Text::PropertyOverrideTextFont => cstr!(c"Text-Schriftart überschreiben"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Zeiten-Schriftart überschreiben"),
+        Text::PropertyColor => cstr!(c"Farbe"),
+        Text::PropertyFont => cstr!(c"Schriftart"),
+        Text::PropertyTransparentBackground => cstr!(c"Transparenter Hintergrund"),
+        Text::PropertyDropShadow => cstr!(c"Schlagschatten"),
+        Text::PropertyHorizontalOffset => cstr!(c"Horizontaler Versatz"),
+        Text::PropertyVerticalOffset => cstr!(c"Vertikaler Versatz"),
+        Text::PropertyBlurRadius => cstr!(c"Weichzeichnungsradius"),
+        Text::PropertyExportDirectory => cstr!(c"Exportordner"),
+        Text::PropertyExportImageSequence => cstr!(c"Bildsequenz der Versuche aufnehmen"),
+        Text::PropertyFramesPerSecond => cstr!(c"Bilder pro Sekunde"),
+        Text::PropertySaveFrame => cstr!(c"Bild als PNG speichern"),
+        Text::PropertyTextExport => cstr!(c"Timerwerte in Textdateien schreiben"),
+        Text::PropertyUpdateInterval => cstr!(c"Aktualisierungsintervall (ms)"),
+        Text::TextSourceName => cstr!(c"LiveSplit One-Text"),
+        Text::PropertyTimerValue => cstr!(c"Wert"),
+        Text::PropertyTextColor => cstr!(c"Textfarbe"),
+        Text::PropertyBackgroundColor => cstr!(c"Hintergrundfarbe"),
+        Text::TimerValueCurrentTime => cstr!(c"Aktuelle Zeit"),
+        Text::TimerValueCurrentSplit => cstr!(c"Aktueller Split"),
+        Text::TimerValueDelta => cstr!(c"Differenz"),
+        Text::TimerValueComparison => cstr!(c"Vergleich"),
+        Text::TimerValuePersonalBest => cstr!(c"Persönliche Bestzeit"),
+        Text::TimerValueSumOfBest => cstr!(c"Summe der besten Segmente"),
+        Text::TimerValueAttempts => cstr!(c"Versuche"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One-Timerereignisse"),
+        Text::PropertyIdleColor => cstr!(c"Farbe ohne Ereignis"),
+        Text::PropertyDuration => cstr!(c"Dauer (Sekunden, 0 = bis zum nächsten Ereignis)"),
+        Text::TriggerStart => cstr!(c"Beim Start"),
+        Text::TriggerAhead => cstr!(c"Bei Split im Vorsprung"),
+        Text::TriggerBehind => cstr!(c"Bei Split im Rückstand"),
+        Text::TriggerGold => cstr!(c"Bei bestem Segment"),
+        Text::TriggerPersonalBest => cstr!(c"Bei persönlicher Bestzeit"),
+        Text::TriggerReset => cstr!(c"Beim Zurücksetzen"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Regeln für Szenenwechsel (TRIGGER -> scene / show / hide NAME)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Aufnahme steuern"),
+        Text::PropertyRecordOnStart => cstr!(c"Aufnahme beim Start des Timers starten"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Aufnahme beim Zurücksetzen beenden"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Aufnahme am Ende des Runs beenden"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Wiederholung bei Gold-Splits speichern"),
+        Text::PropertySaveReplayOnPersonalBest => {
+            cstr!(c"Wiederholung bei persönlichen Bestzeiten speichern")
+        }
+        Text::PropertyNameRecordings => {
+            cstr!(c"Aufnahmen nach Spiel, Kategorie und Versuch benennen")
+        }
+        Text::PropertyChapters => cstr!(c"Kapitel bei jedem Split"),
+        Text::PropertyChapterMarkers => cstr!(c"Kapitelmarken zur Aufnahme hinzufügen"),
+        Text::PropertyChaptersDirectory => cstr!(c"Ordner für Kapiteldateien"),
+        Text::PropertyTimestampLog => cstr!(c"Zeitstempel jedes Versuchs protokollieren"),
+        Text::PropertyFileFormat => cstr!(c"Dateiformat"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One Audiosignale"),
+        Text::PropertyVolume => cstr!(c"Lautstärke (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave-Dateien (*.wav)"),
+        Text::CueSplit => cstr!(c"Split-Sound"),
+        Text::CueGold => cstr!(c"Gold-Split-Sound"),
+        Text::CueBehind => cstr!(c"Sound bei Rückstand"),
+        Text::CuePersonalBest => cstr!(c"Sound bei persönlicher Bestzeit"),
+        Text::CueUndo => cstr!(c"Sound bei rückgängig gemachtem Split"),
+        Text::CueReset => cstr!(c"Sound beim Zurücksetzen"),
+        Text::PropertyControlPanel => cstr!(c"Bedienfeld für Browser-Docks"),
+        Text::PropertyPort => cstr!(c"Port"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Zugriff von anderen Computern erlauben"),
+        Text::AutoSplitterRefreshList => cstr!(c"Liste aktualisieren"),
+        Text::AutoSplitterGame => cstr!(c"Spiel"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automatisch"),
+        Text::AutoSplitterLists => cstr!(c"Autosplitter-Listen"),
+        Text::AutoSplitterListsDescription => cstr!(c"Eine Liste pro Zeile. Alternative Orte derselben Liste, wie Mirrors, werden durch | getrennt. Ein Ort ist eine URL, eine Datei oder ein Ordner mit Listendateien. Weiter unten stehende Listen haben Vorrang. Aktualisiere die Liste, um Änderungen zu übernehmen."),
+        Text::AutoSplitterStatus => cstr!(c"Autosplitter-Status"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"Der Autosplitter ist geladen."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Es ist kein Autosplitter geladen."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Prozess"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Tickrate"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Letzter Fehler"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Unbekannt"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Status aktualisieren"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Einstellungsdatei"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Autosplitter-Einstellungen (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Einstellungen importieren"),
+        Text::AutoSplitterExportSettings => cstr!(c"Einstellungen exportieren"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Benutzerdefinierte Variablen ({name} ist die Variable)"),
+        Text::PropertyAccessToken => cstr!(c"Zugriffstoken (Teil der Adresse)"),
+        Text::AutoSplitterChooseGame => cstr!(c"Das Spiel der Splits passt zu keinem Auto-Splitter genau. Wähle das Spiel aus der Liste."),
+        Text::AutoSplitterImportFile => cstr!(c"Zu importierende Einstellungsdatei"),
+        Text::PropertyControlPanelAddress => cstr!(c"Adresse für das Browser-Dock"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Geänderten Auto Splitter akzeptieren"),
     }
 }
 
@@ -300,6 +760,96 @@ fn resolve_italian(text: Text) -> *const c_char {
         Text::AutoSplitterUnavailable => {
             cstr!(c"Nessun auto-splitter disponibile per questo gioco.")
         }
+        Text::PropertyOverrideBackground => cstr!(c"Sovrascrivi sfondo"),
+        Text::PropertyOverrideTextColor => cstr!(c"Sovrascrivi colore del testo"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Sovrascrivi carattere del timer"),
+        Text::PropertyOverrideTextFont => cstr!(c"Sovrascrivi carattere del testo"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Sovrascrivi carattere dei tempi"),
+        Text::PropertyColor => cstr!(c"Colore"),
+        Text::PropertyFont => cstr!(c"Carattere"),
+        Text::PropertyTransparentBackground => cstr!(c"Sfondo trasparente"),
+        Text::PropertyDropShadow => cstr!(c"Ombra esterna"),
+        Text::PropertyHorizontalOffset => cstr!(c"Scostamento orizzontale"),
+        Text::PropertyVerticalOffset => cstr!(c"Scostamento verticale"),
+        Text::PropertyBlurRadius => cstr!(c"Raggio di sfocatura"),
+        Text::PropertyExportDirectory => cstr!(c"Cartella di esportazione"),
+        Text::PropertyExportImageSequence => cstr!(c"Registra sequenza di immagini dei tentativi"),
+        Text::PropertyFramesPerSecond => cstr!(c"Fotogrammi al secondo"),
+        Text::PropertySaveFrame => cstr!(c"Salva fotogramma come PNG"),
+        Text::PropertyTextExport => cstr!(c"Scrivi i valori del timer in file di testo"),
+        Text::PropertyUpdateInterval => cstr!(c"Intervallo di aggiornamento (ms)"),
+        Text::TextSourceName => cstr!(c"Testo LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Valore"),
+        Text::PropertyTextColor => cstr!(c"Colore del testo"),
+        Text::PropertyBackgroundColor => cstr!(c"Colore di sfondo"),
+        Text::TimerValueCurrentTime => cstr!(c"Tempo attuale"),
+        Text::TimerValueCurrentSplit => cstr!(c"Split attuale"),
+        Text::TimerValueDelta => cstr!(c"Differenza"),
+        Text::TimerValueComparison => cstr!(c"Confronto"),
+        Text::TimerValuePersonalBest => cstr!(c"Record personale"),
+        Text::TimerValueSumOfBest => cstr!(c"Somma dei migliori segmenti"),
+        Text::TimerValueAttempts => cstr!(c"Tentativi"),
+        Text::TimerEventFilterName => cstr!(c"Eventi del timer di LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Colore senza evento"),
+        Text::PropertyDuration => cstr!(c"Durata (secondi, 0 = fino al prossimo evento)"),
+        Text::TriggerStart => cstr!(c"All'avvio"),
+        Text::TriggerAhead => cstr!(c"Split in vantaggio"),
+        Text::TriggerBehind => cstr!(c"Split in ritardo"),
+        Text::TriggerGold => cstr!(c"Miglior segmento"),
+        Text::TriggerPersonalBest => cstr!(c"Record personale"),
+        Text::TriggerReset => cstr!(c"Al reset"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Regole di cambio scena (TRIGGER -> scene / show / hide NOME)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Controlla registrazione"),
+        Text::PropertyRecordOnStart => cstr!(c"Avvia la registrazione all'avvio del timer"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Ferma la registrazione al reset"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Ferma la registrazione alla fine della run"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Salva replay sugli split d'oro"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"Salva replay sui record personali"),
+        Text::PropertyNameRecordings => {
+            cstr!(c"Nomina le registrazioni con gioco, categoria e tentativo")
+        }
+        Text::PropertyChapters => cstr!(c"Capitoli a ogni split"),
+        Text::PropertyChapterMarkers => cstr!(c"Aggiungi marcatori di capitolo alla registrazione"),
+        Text::PropertyChaptersDirectory => cstr!(c"Cartella dei file dei capitoli"),
+        Text::PropertyTimestampLog => cstr!(c"Registra i timestamp di ogni tentativo"),
+        Text::PropertyFileFormat => cstr!(c"Formato file"),
+        Text::AudioCuesSourceName => cstr!(c"Segnali audio LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Volume (%)"),
+        Text::PropertySoundFilter => cstr!(c"File Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Suono dello split"),
+        Text::CueGold => cstr!(c"Suono dello split d'oro"),
+        Text::CueBehind => cstr!(c"Suono in ritardo"),
+        Text::CuePersonalBest => cstr!(c"Suono del record personale"),
+        Text::CueUndo => cstr!(c"Suono di annullamento split"),
+        Text::CueReset => cstr!(c"Suono del reset"),
+        Text::PropertyControlPanel => cstr!(c"Pannello di controllo per i dock del browser"),
+        Text::PropertyPort => cstr!(c"Porta"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Consenti l'accesso da altri computer"),
+        Text::AutoSplitterRefreshList => cstr!(c"Aggiorna elenco"),
+        Text::AutoSplitterGame => cstr!(c"Gioco"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automatico"),
+        Text::AutoSplitterLists => cstr!(c"Elenchi di auto splitter"),
+        Text::AutoSplitterListsDescription => cstr!(c"Un elenco per riga. Le posizioni alternative dello stesso elenco, come i mirror, sono separate da |. Una posizione è un URL, un file o una cartella di file di elenco. Gli elenchi più in basso hanno la precedenza. Aggiorna l'elenco per applicare le modifiche."),
+        Text::AutoSplitterStatus => cstr!(c"Stato dell'auto splitter"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"L'auto splitter è caricato."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Nessun auto splitter è caricato."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Processo"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Frequenza di aggiornamento"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Ultimo errore"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Sconosciuto"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Aggiorna stato"),
+        Text::AutoSplitterSettingsFile => cstr!(c"File delle impostazioni"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Impostazioni dell'auto splitter (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Importa impostazioni"),
+        Text::AutoSplitterExportSettings => cstr!(c"Esporta impostazioni"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Variabili personalizzate ({name} è la variabile)"),
+        Text::PropertyAccessToken => cstr!(c"Token di accesso (parte dell'indirizzo)"),
+        Text::AutoSplitterChooseGame => cstr!(c"Il gioco degli split non corrisponde esattamente a nessun auto splitter. Scegli il gioco dall'elenco."),
+        Text::AutoSplitterImportFile => cstr!(c"File di impostazioni da importare"),
+        Text::PropertyControlPanelAddress => cstr!(c"Indirizzo per il dock del browser"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Accetta l'auto splitter modificato"),
     }
 }
 
@@ -342,6 +892,96 @@ fn resolve_portuguese(text: Text) -> *const c_char {
             cstr!(c"O auto-splitter deste jogo é incompatível com o LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"Não há auto-splitter disponível para este jogo."),
+        Text::PropertyOverrideBackground => cstr!(c"Substituir fundo"),
+        Text::PropertyOverrideTextColor => cstr!(c"Substituir cor do texto"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Substituir tipo de letra do cronómetro"),
+        Text::PropertyOverrideTextFont => cstr!(c"Substituir tipo de letra do texto"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Substituir tipo de letra dos tempos"),
+        Text::PropertyColor => cstr!(c"Cor"),
+        Text::PropertyFont => cstr!(c"Tipo de letra"),
+        Text::PropertyTransparentBackground => cstr!(c"Fundo transparente"),
+        Text::PropertyDropShadow => cstr!(c"Sombra projetada"),
+        Text::PropertyHorizontalOffset => cstr!(c"Deslocamento horizontal"),
+        Text::PropertyVerticalOffset => cstr!(c"Deslocamento vertical"),
+        Text::PropertyBlurRadius => cstr!(c"Raio de desfocagem"),
+        Text::PropertyExportDirectory => cstr!(c"Pasta de exportação"),
+        Text::PropertyExportImageSequence => cstr!(c"Gravar sequência de imagens das tentativas"),
+        Text::PropertyFramesPerSecond => cstr!(c"Imagens por segundo"),
+        Text::PropertySaveFrame => cstr!(c"Guardar imagem como PNG"),
+        Text::PropertyTextExport => cstr!(c"Escrever valores do cronómetro em ficheiros de texto"),
+        Text::PropertyUpdateInterval => cstr!(c"Intervalo de atualização (ms)"),
+        Text::TextSourceName => cstr!(c"Texto do LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Valor"),
+        Text::PropertyTextColor => cstr!(c"Cor do texto"),
+        Text::PropertyBackgroundColor => cstr!(c"Cor de fundo"),
+        Text::TimerValueCurrentTime => cstr!(c"Tempo atual"),
+        Text::TimerValueCurrentSplit => cstr!(c"Split atual"),
+        Text::TimerValueDelta => cstr!(c"Diferença"),
+        Text::TimerValueComparison => cstr!(c"Comparação"),
+        Text::TimerValuePersonalBest => cstr!(c"Recorde pessoal"),
+        Text::TimerValueSumOfBest => cstr!(c"Soma dos melhores segmentos"),
+        Text::TimerValueAttempts => cstr!(c"Tentativas"),
+        Text::TimerEventFilterName => cstr!(c"Eventos do cronómetro do LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Cor sem evento"),
+        Text::PropertyDuration => cstr!(c"Duração (segundos, 0 = até ao próximo evento)"),
+        Text::TriggerStart => cstr!(c"Ao iniciar"),
+        Text::TriggerAhead => cstr!(c"Split adiantado"),
+        Text::TriggerBehind => cstr!(c"Split atrasado"),
+        Text::TriggerGold => cstr!(c"Melhor segmento"),
+        Text::TriggerPersonalBest => cstr!(c"Recorde pessoal"),
+        Text::TriggerReset => cstr!(c"Ao reiniciar"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Regras de troca de cena (TRIGGER -> scene / show / hide NOME)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Controlar gravação"),
+        Text::PropertyRecordOnStart => cstr!(c"Iniciar gravação quando o cronómetro iniciar"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Parar gravação ao reiniciar"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Parar gravação quando a run terminar"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Guardar replay em splits dourados"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"Guardar replay em recordes pessoais"),
+        Text::PropertyNameRecordings => cstr!(c"Nomear gravações com jogo, categoria e tentativa"),
+        Text::PropertyChapters => cstr!(c"Capítulos em cada split"),
+        Text::PropertyChapterMarkers => cstr!(c"Adicionar marcadores de capítulo à gravação"),
+        Text::PropertyChaptersDirectory => cstr!(c"Pasta dos ficheiros de capítulos"),
+        Text::PropertyTimestampLog => cstr!(c"Registar marcas temporais de cada tentativa"),
+        Text::PropertyFileFormat => cstr!(c"Formato de ficheiro"),
+        Text::AudioCuesSourceName => cstr!(c"Sinais sonoros do LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Volume (%)"),
+        Text::PropertySoundFilter => cstr!(c"Ficheiros Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Som de split"),
+        Text::CueGold => cstr!(c"Som de split dourado"),
+        Text::CueBehind => cstr!(c"Som de atraso"),
+        Text::CuePersonalBest => cstr!(c"Som de recorde pessoal"),
+        Text::CueUndo => cstr!(c"Som de desfazer split"),
+        Text::CueReset => cstr!(c"Som de reinício"),
+        Text::PropertyControlPanel => cstr!(c"Painel de controlo para docks de navegador"),
+        Text::PropertyPort => cstr!(c"Porta"),
+        Text::PropertyAllowOtherComputers => {
+            cstr!(c"Permitir acesso a partir de outros computadores")
+        }
+        Text::AutoSplitterRefreshList => cstr!(c"Atualizar lista"),
+        Text::AutoSplitterGame => cstr!(c"Jogo"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automático"),
+        Text::AutoSplitterLists => cstr!(c"Listas de auto splitters"),
+        Text::AutoSplitterListsDescription => cstr!(c"Uma lista por linha. Localizações alternativas da mesma lista, como mirrors, são separadas por |. Uma localização é um URL, um ficheiro ou uma pasta de ficheiros de lista. As listas mais abaixo têm prioridade. Atualize a lista para aplicar as alterações."),
+        Text::AutoSplitterStatus => cstr!(c"Estado do auto splitter"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"O auto splitter está carregado."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Nenhum auto splitter está carregado."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Processo"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Taxa de atualização"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Último erro"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Desconhecido"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Atualizar estado"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Ficheiro de definições"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Definições do auto splitter (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Importar definições"),
+        Text::AutoSplitterExportSettings => cstr!(c"Exportar definições"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Variáveis personalizadas ({name} é a variável)"),
+        Text::PropertyAccessToken => cstr!(c"Token de acesso (parte do endereço)"),
+        Text::AutoSplitterChooseGame => cstr!(c"O jogo dos splits não corresponde exatamente a nenhum auto splitter. Escolha o jogo na lista."),
+        Text::AutoSplitterImportFile => cstr!(c"Ficheiro de definições a importar"),
+        Text::PropertyControlPanelAddress => cstr!(c"Endereço para a doca do navegador"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Aceitar auto splitter alterado"),
     }
 }
 
@@ -384,6 +1024,96 @@ fn resolve_polish(text: Text) -> *const c_char {
             cstr!(c"Auto-splitter tej gry jest niezgodny z LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"Brak auto-splittera dla tej gry."),
+        Text::PropertyOverrideBackground => cstr!(c"Zastąp tło"),
+        Text::PropertyOverrideTextColor => cstr!(c"Zastąp kolor tekstu"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Zastąp czcionkę timera"),
+        Text::PropertyOverrideTextFont => cstr!(c"Zastąp czcionkę tekstu"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Zastąp czcionkę czasów"),
+        Text::PropertyColor => cstr!(c"Kolor"),
+        Text::PropertyFont => cstr!(c"Czcionka"),
+        Text::PropertyTransparentBackground => cstr!(c"Przezroczyste tło"),
+        Text::PropertyDropShadow => cstr!(c"Cień"),
+        Text::PropertyHorizontalOffset => cstr!(c"Przesunięcie poziome"),
+        Text::PropertyVerticalOffset => cstr!(c"Przesunięcie pionowe"),
+        Text::PropertyBlurRadius => cstr!(c"Promień rozmycia"),
+        Text::PropertyExportDirectory => cstr!(c"Folder eksportu"),
+        Text::PropertyExportImageSequence => cstr!(c"Nagrywaj sekwencję obrazów prób"),
+        Text::PropertyFramesPerSecond => cstr!(c"Klatki na sekundę"),
+        Text::PropertySaveFrame => cstr!(c"Zapisz klatkę jako PNG"),
+        Text::PropertyTextExport => cstr!(c"Zapisuj wartości stopera do plików tekstowych"),
+        Text::PropertyUpdateInterval => cstr!(c"Interwał aktualizacji (ms)"),
+        Text::TextSourceName => cstr!(c"Tekst LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Wartość"),
+        Text::PropertyTextColor => cstr!(c"Kolor tekstu"),
+        Text::PropertyBackgroundColor => cstr!(c"Kolor tła"),
+        Text::TimerValueCurrentTime => cstr!(c"Aktualny czas"),
+        Text::TimerValueCurrentSplit => cstr!(c"Aktualny split"),
+        Text::TimerValueDelta => cstr!(c"Różnica"),
+        Text::TimerValueComparison => cstr!(c"Porównanie"),
+        Text::TimerValuePersonalBest => cstr!(c"Rekord osobisty"),
+        Text::TimerValueSumOfBest => cstr!(c"Suma najlepszych segmentów"),
+        Text::TimerValueAttempts => cstr!(c"Próby"),
+        Text::TimerEventFilterName => cstr!(c"Zdarzenia stopera LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Kolor bez zdarzenia"),
+        Text::PropertyDuration => cstr!(c"Czas trwania (sekundy, 0 = do następnego zdarzenia)"),
+        Text::TriggerStart => cstr!(c"Przy starcie"),
+        Text::TriggerAhead => cstr!(c"Przy splicie z przewagą"),
+        Text::TriggerBehind => cstr!(c"Przy splicie ze stratą"),
+        Text::TriggerGold => cstr!(c"Przy najlepszym segmencie"),
+        Text::TriggerPersonalBest => cstr!(c"Przy rekordzie osobistym"),
+        Text::TriggerReset => cstr!(c"Przy resecie"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Reguły przełączania scen (TRIGGER -> scene / show / hide NAZWA)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Sterowanie nagrywaniem"),
+        Text::PropertyRecordOnStart => cstr!(c"Rozpocznij nagrywanie przy starcie timera"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Zatrzymaj nagrywanie przy resecie"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Zatrzymaj nagrywanie po ukończeniu runu"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Zapisz powtórkę przy złotych splitach"),
+        Text::PropertySaveReplayOnPersonalBest => {
+            cstr!(c"Zapisz powtórkę przy rekordach osobistych")
+        }
+        Text::PropertyNameRecordings => cstr!(c"Nazywaj nagrania według gry, kategorii i próby"),
+        Text::PropertyChapters => cstr!(c"Rozdziały przy każdym splicie"),
+        Text::PropertyChapterMarkers => cstr!(c"Dodawaj znaczniki rozdziałów do nagrania"),
+        Text::PropertyChaptersDirectory => cstr!(c"Folder plików rozdziałów"),
+        Text::PropertyTimestampLog => cstr!(c"Zapisuj znaczniki czasu każdej próby"),
+        Text::PropertyFileFormat => cstr!(c"Format pliku"),
+        Text::AudioCuesSourceName => cstr!(c"Sygnały dźwiękowe LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Głośność (%)"),
+        Text::PropertySoundFilter => cstr!(c"Pliki Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Dźwięk splitu"),
+        Text::CueGold => cstr!(c"Dźwięk złotego splitu"),
+        Text::CueBehind => cstr!(c"Dźwięk straty"),
+        Text::CuePersonalBest => cstr!(c"Dźwięk rekordu osobistego"),
+        Text::CueUndo => cstr!(c"Dźwięk cofnięcia splitu"),
+        Text::CueReset => cstr!(c"Dźwięk resetu"),
+        Text::PropertyControlPanel => cstr!(c"Panel sterowania dla doków przeglądarki"),
+        Text::PropertyPort => cstr!(c"Port"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Zezwalaj na dostęp z innych komputerów"),
+        Text::AutoSplitterRefreshList => cstr!(c"Odśwież listę"),
+        Text::AutoSplitterGame => cstr!(c"Gra"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automatycznie"),
+        Text::AutoSplitterLists => cstr!(c"Listy autosplitterów"),
+        Text::AutoSplitterListsDescription => cstr!(c"Jedna lista na wiersz. Alternatywne lokalizacje tej samej listy, np. serwery lustrzane, oddziela się znakiem |. Lokalizacja to URL, plik lub folder z plikami list. Listy położone niżej mają pierwszeństwo. Odśwież listę, aby zastosować zmiany."),
+        Text::AutoSplitterStatus => cstr!(c"Stan autosplittera"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"Autosplitter jest wczytany."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Żaden autosplitter nie jest wczytany."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Proces"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Częstotliwość odświeżania"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Ostatni błąd"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Nieznany"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Odśwież stan"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Plik ustawień"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Ustawienia autosplittera (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Importuj ustawienia"),
+        Text::AutoSplitterExportSettings => cstr!(c"Eksportuj ustawienia"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Zmienne niestandardowe ({name} to zmienna)"),
+        Text::PropertyAccessToken => cstr!(c"Token dostępu (część adresu)"),
+        Text::AutoSplitterChooseGame => cstr!(c"Gra z podziałów nie pasuje dokładnie do żadnego auto splittera. Wybierz grę z listy."),
+        Text::AutoSplitterImportFile => cstr!(c"Plik ustawień do zaimportowania"),
+        Text::PropertyControlPanelAddress => cstr!(c"Adres dla doku przeglądarki"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Zaakceptuj zmieniony auto splitter"),
     }
 }
 
@@ -426,6 +1156,96 @@ fn resolve_russian(text: Text) -> *const c_char {
             cstr!(c"Авто-сплиттер этой игры несовместим с LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"Для этой игры нет авто-сплиттера."),
+        Text::PropertyOverrideBackground => cstr!(c"Переопределить фон"),
+        Text::PropertyOverrideTextColor => cstr!(c"Переопределить цвет текста"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Переопределить шрифт таймера"),
+        Text::PropertyOverrideTextFont => cstr!(c"Переопределить шрифт текста"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Переопределить шрифт времён"),
+        Text::PropertyColor => cstr!(c"Цвет"),
+        Text::PropertyFont => cstr!(c"Шрифт"),
+        Text::PropertyTransparentBackground => cstr!(c"Прозрачный фон"),
+        Text::PropertyDropShadow => cstr!(c"Тень"),
+        Text::PropertyHorizontalOffset => cstr!(c"Смещение по горизонтали"),
+        Text::PropertyVerticalOffset => cstr!(c"Смещение по вертикали"),
+        Text::PropertyBlurRadius => cstr!(c"Радиус размытия"),
+        Text::PropertyExportDirectory => cstr!(c"Папка экспорта"),
+        Text::PropertyExportImageSequence => {
+            cstr!(c"Записывать последовательность изображений попыток")
+        }
+        Text::PropertyFramesPerSecond => cstr!(c"Кадров в секунду"),
+        Text::PropertySaveFrame => cstr!(c"Сохранить кадр как PNG"),
+        Text::PropertyTextExport => cstr!(c"Записывать значения таймера в текстовые файлы"),
+        Text::PropertyUpdateInterval => cstr!(c"Интервал обновления (мс)"),
+        Text::TextSourceName => cstr!(c"Текст LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Значение"),
+        Text::PropertyTextColor => cstr!(c"Цвет текста"),
+        Text::PropertyBackgroundColor => cstr!(c"Цвет фона"),
+        Text::TimerValueCurrentTime => cstr!(c"Текущее время"),
+        Text::TimerValueCurrentSplit => cstr!(c"Текущий сплит"),
+        Text::TimerValueDelta => cstr!(c"Разница"),
+        Text::TimerValueComparison => cstr!(c"Сравнение"),
+        Text::TimerValuePersonalBest => cstr!(c"Личный рекорд"),
+        Text::TimerValueSumOfBest => cstr!(c"Сумма лучших сегментов"),
+        Text::TimerValueAttempts => cstr!(c"Попытки"),
+        Text::TimerEventFilterName => cstr!(c"События таймера LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Цвет без события"),
+        Text::PropertyDuration => cstr!(c"Длительность (секунды, 0 = до следующего события)"),
+        Text::TriggerStart => cstr!(c"При старте"),
+        Text::TriggerAhead => cstr!(c"При сплите с опережением"),
+        Text::TriggerBehind => cstr!(c"При сплите с отставанием"),
+        Text::TriggerGold => cstr!(c"При лучшем сегменте"),
+        Text::TriggerPersonalBest => cstr!(c"При личном рекорде"),
+        Text::TriggerReset => cstr!(c"При сбросе"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Правила переключения сцен (TRIGGER -> scene / show / hide ИМЯ)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Управление записью"),
+        Text::PropertyRecordOnStart => cstr!(c"Начинать запись при запуске таймера"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Останавливать запись при сбросе"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Останавливать запись по завершении забега"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Сохранять повтор при золотых сплитах"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"Сохранять повтор при личных рекордах"),
+        Text::PropertyNameRecordings => cstr!(c"Называть записи по игре, категории и попытке"),
+        Text::PropertyChapters => cstr!(c"Главы на каждом сплите"),
+        Text::PropertyChapterMarkers => cstr!(c"Добавлять метки глав в запись"),
+        Text::PropertyChaptersDirectory => cstr!(c"Папка для файлов глав"),
+        Text::PropertyTimestampLog => cstr!(c"Записывать метки времени каждой попытки"),
+        Text::PropertyFileFormat => cstr!(c"Формат файла"),
+        Text::AudioCuesSourceName => cstr!(c"Звуковые сигналы LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Громкость (%)"),
+        Text::PropertySoundFilter => cstr!(c"Файлы Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Звук сплита"),
+        Text::CueGold => cstr!(c"Звук золотого сплита"),
+        Text::CueBehind => cstr!(c"Звук отставания"),
+        Text::CuePersonalBest => cstr!(c"Звук личного рекорда"),
+        Text::CueUndo => cstr!(c"Звук отмены сплита"),
+        Text::CueReset => cstr!(c"Звук сброса"),
+        Text::PropertyControlPanel => cstr!(c"Панель управления для доков браузера"),
+        Text::PropertyPort => cstr!(c"Порт"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Разрешить доступ с других компьютеров"),
+        Text::AutoSplitterRefreshList => cstr!(c"Обновить список"),
+        Text::AutoSplitterGame => cstr!(c"Игра"),
+        Text::AutoSplitterAutomatic => cstr!(c"Автоматически"),
+        Text::AutoSplitterLists => cstr!(c"Списки автосплиттеров"),
+        Text::AutoSplitterListsDescription => cstr!(c"Один список на строку. Альтернативные расположения одного списка, например зеркала, разделяются символом |. Расположение — это URL, файл или папка с файлами списков. Списки ниже имеют приоритет. Обновите список, чтобы применить изменения."),
+        Text::AutoSplitterStatus => cstr!(c"Состояние автосплиттера"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"Автосплиттер загружен."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Автосплиттер не загружен."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Процесс"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Частота обновления"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Последняя ошибка"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Неизвестно"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Обновить состояние"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Файл настроек"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Настройки автосплиттера (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Импортировать настройки"),
+        Text::AutoSplitterExportSettings => cstr!(c"Экспортировать настройки"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Пользовательские переменные ({name} — переменная)"),
+        Text::PropertyAccessToken => cstr!(c"Токен доступа (часть адреса)"),
+        Text::AutoSplitterChooseGame => cstr!(c"Игра сплитов не совпадает точно ни с одним автосплиттером. Выберите игру из списка."),
+        Text::AutoSplitterImportFile => cstr!(c"Файл настроек для импорта"),
+        Text::PropertyControlPanelAddress => cstr!(c"Адрес для док-панели браузера"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Принять изменённый автосплиттер"),
     }
 }
 
@@ -468,6 +1288,100 @@ fn resolve_spanish(text: Text) -> *const c_char {
             cstr!(c"El auto-splitter de este juego es incompatible con LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"No hay auto-splitter disponible para este juego."),
+        Text::PropertyOverrideBackground => cstr!(c"Reemplazar fondo"),
+        Text::PropertyOverrideTextColor => cstr!(c"Reemplazar color del texto"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Reemplazar fuente del temporizador"),
+        Text::PropertyOverrideTextFont => cstr!(c"Reemplazar fuente del texto"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Reemplazar fuente de los tiempos"),
+        Text::PropertyColor => cstr!(c"Color"),
+        Text::PropertyFont => cstr!(c"Fuente"),
+        Text::PropertyTransparentBackground => cstr!(c"Fondo transparente"),
+        Text::PropertyDropShadow => cstr!(c"Sombra paralela"),
+        Text::PropertyHorizontalOffset => cstr!(c"Desplazamiento horizontal"),
+        Text::PropertyVerticalOffset => cstr!(c"Desplazamiento vertical"),
+        Text::PropertyBlurRadius => cstr!(c"Radio de desenfoque"),
+        Text::PropertyExportDirectory => cstr!(c"Carpeta de exportación"),
+        Text::PropertyExportImageSequence => cstr!(c"Grabar secuencia de imágenes de los intentos"),
+        Text::PropertyFramesPerSecond => cstr!(c"Fotogramas por segundo"),
+        Text::PropertySaveFrame => cstr!(c"Guardar fotograma como PNG"),
+        Text::PropertyTextExport => {
+            cstr!(c"Escribir los valores del temporizador en archivos de texto")
+        }
+        Text::PropertyUpdateInterval => cstr!(c"Intervalo de actualización (ms)"),
+        Text::TextSourceName => cstr!(c"Texto de LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Valor"),
+        Text::PropertyTextColor => cstr!(c"Color del texto"),
+        Text::PropertyBackgroundColor => cstr!(c"Color de fondo"),
+        Text::TimerValueCurrentTime => cstr!(c"Tiempo actual"),
+        Text::TimerValueCurrentSplit => cstr!(c"Split actual"),
+        Text::TimerValueDelta => cstr!(c"Diferencia"),
+        Text::TimerValueComparison => cstr!(c"Comparación"),
+        Text::TimerValuePersonalBest => cstr!(c"Mejor marca personal"),
+        Text::TimerValueSumOfBest => cstr!(c"Suma de los mejores segmentos"),
+        Text::TimerValueAttempts => cstr!(c"Intentos"),
+        Text::TimerEventFilterName => cstr!(c"Eventos del temporizador de LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Color sin evento"),
+        Text::PropertyDuration => cstr!(c"Duración (segundos, 0 = hasta el próximo evento)"),
+        Text::TriggerStart => cstr!(c"Al iniciar"),
+        Text::TriggerAhead => cstr!(c"Split adelantado"),
+        Text::TriggerBehind => cstr!(c"Split atrasado"),
+        Text::TriggerGold => cstr!(c"Mejor segmento"),
+        Text::TriggerPersonalBest => cstr!(c"Mejor marca personal"),
+        Text::TriggerReset => cstr!(c"Al reiniciar"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Reglas de cambio de escena (TRIGGER -> scene / show / hide NOMBRE)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Controlar grabación"),
+        Text::PropertyRecordOnStart => cstr!(c"Iniciar grabación al iniciar el temporizador"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Detener grabación al reiniciar"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Detener grabación al terminar la run"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Guardar repetición en splits dorados"),
+        Text::PropertySaveReplayOnPersonalBest => {
+            cstr!(c"Guardar repetición en récords personales")
+        }
+        Text::PropertyNameRecordings => {
+            cstr!(c"Nombrar grabaciones según juego, categoría e intento")
+        }
+        Text::PropertyChapters => cstr!(c"Capítulos en cada split"),
+        Text::PropertyChapterMarkers => cstr!(c"Añadir marcadores de capítulo a la grabación"),
+        Text::PropertyChaptersDirectory => cstr!(c"Carpeta de archivos de capítulos"),
+        Text::PropertyTimestampLog => cstr!(c"Registrar marcas de tiempo de cada intento"),
+        Text::PropertyFileFormat => cstr!(c"Formato de archivo"),
+        Text::AudioCuesSourceName => cstr!(c"Señales de audio de LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Volumen (%)"),
+        Text::PropertySoundFilter => cstr!(c"Archivos Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Sonido de split"),
+        Text::CueGold => cstr!(c"Sonido de split dorado"),
+        Text::CueBehind => cstr!(c"Sonido de retraso"),
+        Text::CuePersonalBest => cstr!(c"Sonido de récord personal"),
+        Text::CueUndo => cstr!(c"Sonido de deshacer split"),
+        Text::CueReset => cstr!(c"Sonido de reinicio"),
+        Text::PropertyControlPanel => cstr!(c"Panel de control para docks de navegador"),
+        Text::PropertyPort => cstr!(c"Puerto"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Permitir el acceso desde otros equipos"),
+        Text::AutoSplitterRefreshList => cstr!(c"Actualizar lista"),
+        Text::AutoSplitterGame => cstr!(c"Juego"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automático"),
+        Text::AutoSplitterLists => cstr!(c"Listas de auto splitters"),
+        Text::AutoSplitterListsDescription => cstr!(c"Una lista por línea. Las ubicaciones alternativas de una misma lista, como espejos, se separan con |. Una ubicación es una URL, un archivo o una carpeta de archivos de lista. Las listas más abajo tienen prioridad. Actualiza la lista para aplicar los cambios."),
+        Text::AutoSplitterStatus => cstr!(c"Estado del auto splitter"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"El auto splitter está cargado."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"No hay ningún auto splitter cargado."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Proceso"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Frecuencia de actualización"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Último error"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Desconocido"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Actualizar estado"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Archivo de configuración"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Configuración del auto splitter (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Importar configuración"),
+        Text::AutoSplitterExportSettings => cstr!(c"Exportar configuración"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Variables personalizadas ({name} es la variable)"),
+        Text::PropertyAccessToken => cstr!(c"Token de acceso (parte de la dirección)"),
+        Text::AutoSplitterChooseGame => cstr!(c"El juego de los splits no coincide exactamente con ningún auto splitter. Elige el juego de la lista."),
+        Text::AutoSplitterImportFile => cstr!(c"Archivo de ajustes a importar"),
+        Text::PropertyControlPanelAddress => cstr!(c"Dirección para el panel del navegador"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Aceptar auto splitter modificado"),
     }
 }
 
@@ -510,6 +1424,94 @@ fn resolve_brazilian_portuguese(text: Text) -> *const c_char {
             cstr!(c"O auto-splitter deste jogo é incompatível com o LiveSplit One.")
         }
         Text::AutoSplitterUnavailable => cstr!(c"Nenhum auto-splitter disponível para este jogo."),
+        Text::PropertyOverrideBackground => cstr!(c"Substituir plano de fundo"),
+        Text::PropertyOverrideTextColor => cstr!(c"Substituir cor do texto"),
+        Text::PropertyOverrideTimerFont => cstr!(c"Substituir fonte do cronômetro"),
+        Text::PropertyOverrideTextFont => cstr!(c"Substituir fonte do texto"),
+        Text::PropertyOverrideTimesFont => cstr!(c"Substituir fonte dos tempos"),
+        Text::PropertyColor => cstr!(c"Cor"),
+        Text::PropertyFont => cstr!(c"Fonte"),
+        Text::PropertyTransparentBackground => cstr!(c"Plano de fundo transparente"),
+        Text::PropertyDropShadow => cstr!(c"Sombra projetada"),
+        Text::PropertyHorizontalOffset => cstr!(c"Deslocamento horizontal"),
+        Text::PropertyVerticalOffset => cstr!(c"Deslocamento vertical"),
+        Text::PropertyBlurRadius => cstr!(c"Raio de desfoque"),
+        Text::PropertyExportDirectory => cstr!(c"Pasta de exportação"),
+        Text::PropertyExportImageSequence => cstr!(c"Gravar sequência de imagens das tentativas"),
+        Text::PropertyFramesPerSecond => cstr!(c"Quadros por segundo"),
+        Text::PropertySaveFrame => cstr!(c"Salvar quadro como PNG"),
+        Text::PropertyTextExport => cstr!(c"Escrever valores do cronômetro em arquivos de texto"),
+        Text::PropertyUpdateInterval => cstr!(c"Intervalo de atualização (ms)"),
+        Text::TextSourceName => cstr!(c"Texto do LiveSplit One"),
+        Text::PropertyTimerValue => cstr!(c"Valor"),
+        Text::PropertyTextColor => cstr!(c"Cor do texto"),
+        Text::PropertyBackgroundColor => cstr!(c"Cor de fundo"),
+        Text::TimerValueCurrentTime => cstr!(c"Tempo atual"),
+        Text::TimerValueCurrentSplit => cstr!(c"Split atual"),
+        Text::TimerValueDelta => cstr!(c"Diferença"),
+        Text::TimerValueComparison => cstr!(c"Comparação"),
+        Text::TimerValuePersonalBest => cstr!(c"Recorde pessoal"),
+        Text::TimerValueSumOfBest => cstr!(c"Soma dos melhores segmentos"),
+        Text::TimerValueAttempts => cstr!(c"Tentativas"),
+        Text::TimerEventFilterName => cstr!(c"Eventos do cronômetro do LiveSplit One"),
+        Text::PropertyIdleColor => cstr!(c"Cor sem evento"),
+        Text::PropertyDuration => cstr!(c"Duração (segundos, 0 = até o próximo evento)"),
+        Text::TriggerStart => cstr!(c"Ao iniciar"),
+        Text::TriggerAhead => cstr!(c"Split adiantado"),
+        Text::TriggerBehind => cstr!(c"Split atrasado"),
+        Text::TriggerGold => cstr!(c"Melhor segmento"),
+        Text::TriggerPersonalBest => cstr!(c"Recorde pessoal"),
+        Text::TriggerReset => cstr!(c"Ao reiniciar"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"Regras de troca de cena (TRIGGER -> scene / show / hide NOME)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"Controlar gravação"),
+        Text::PropertyRecordOnStart => cstr!(c"Iniciar gravação quando o timer iniciar"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"Parar gravação ao resetar"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"Parar gravação quando a run terminar"),
+        Text::PropertySaveReplayOnGold => cstr!(c"Salvar replay em splits dourados"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"Salvar replay em recordes pessoais"),
+        Text::PropertyNameRecordings => cstr!(c"Nomear gravações com jogo, categoria e tentativa"),
+        Text::PropertyChapters => cstr!(c"Capítulos em cada split"),
+        Text::PropertyChapterMarkers => cstr!(c"Adicionar marcadores de capítulo à gravação"),
+        Text::PropertyChaptersDirectory => cstr!(c"Pasta dos arquivos de capítulos"),
+        Text::PropertyTimestampLog => cstr!(c"Registrar marcações de tempo de cada tentativa"),
+        Text::PropertyFileFormat => cstr!(c"Formato de arquivo"),
+        Text::AudioCuesSourceName => cstr!(c"Sinais sonoros do LiveSplit One"),
+        Text::PropertyVolume => cstr!(c"Volume (%)"),
+        Text::PropertySoundFilter => cstr!(c"Arquivos Wave (*.wav)"),
+        Text::CueSplit => cstr!(c"Som de split"),
+        Text::CueGold => cstr!(c"Som de split dourado"),
+        Text::CueBehind => cstr!(c"Som de atraso"),
+        Text::CuePersonalBest => cstr!(c"Som de recorde pessoal"),
+        Text::CueUndo => cstr!(c"Som de desfazer split"),
+        Text::CueReset => cstr!(c"Som de reset"),
+        Text::PropertyControlPanel => cstr!(c"Painel de controle para docks de navegador"),
+        Text::PropertyPort => cstr!(c"Porta"),
+        Text::PropertyAllowOtherComputers => cstr!(c"Permitir acesso de outros computadores"),
+        Text::AutoSplitterRefreshList => cstr!(c"Atualizar lista"),
+        Text::AutoSplitterGame => cstr!(c"Jogo"),
+        Text::AutoSplitterAutomatic => cstr!(c"Automático"),
+        Text::AutoSplitterLists => cstr!(c"Listas de auto splitters"),
+        Text::AutoSplitterListsDescription => cstr!(c"Uma lista por linha. Locais alternativos da mesma lista, como mirrors, são separados por |. Um local é uma URL, um arquivo ou uma pasta de arquivos de lista. As listas mais abaixo têm prioridade. Atualize a lista para aplicar as alterações."),
+        Text::AutoSplitterStatus => cstr!(c"Status do auto splitter"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"O auto splitter está carregado."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"Nenhum auto splitter está carregado."),
+        Text::AutoSplitterStatusProcess => cstr!(c"Processo"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"Taxa de atualização"),
+        Text::AutoSplitterStatusLastError => cstr!(c"Último erro"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"Desconhecido"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"Atualizar status"),
+        Text::AutoSplitterSettingsFile => cstr!(c"Arquivo de configurações"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"Configurações do auto splitter (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"Importar configurações"),
+        Text::AutoSplitterExportSettings => cstr!(c"Exportar configurações"),
+        Text::PropertyCustomVariableFiles => cstr!(c"Variáveis personalizadas ({name} é a variável)"),
+        Text::PropertyAccessToken => cstr!(c"Token de acesso (parte do endereço)"),
+        Text::AutoSplitterChooseGame => cstr!(c"O jogo dos splits não corresponde exatamente a nenhum auto splitter. Escolha o jogo na lista."),
+        Text::AutoSplitterImportFile => cstr!(c"Arquivo de configurações a importar"),
+        Text::PropertyControlPanelAddress => cstr!(c"Endereço para a doca do navegador"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"Aceitar auto splitter alterado"),
     }
 }
 
@@ -550,6 +1552,94 @@ fn resolve_chinese_simplified(text: Text) -> *const c_char {
         Text::AutoSplitterSettingsGroup => cstr!(c"自动分段器设置"),
         Text::AutoSplitterIncompatible => cstr!(c"该游戏的自动分段器与 LiveSplit One 不兼容。"),
         Text::AutoSplitterUnavailable => cstr!(c"此游戏没有可用的自动分段器。"),
+        Text::PropertyOverrideBackground => cstr!(c"覆盖背景"),
+        Text::PropertyOverrideTextColor => cstr!(c"覆盖文本颜色"),
+        Text::PropertyOverrideTimerFont => cstr!(c"覆盖计时器字体"),
+        Text::PropertyOverrideTextFont => cstr!(c"覆盖文本字体"),
+        Text::PropertyOverrideTimesFont => cstr!(c"覆盖时间字体"),
+        Text::PropertyColor => cstr!(c"颜色"),
+        Text::PropertyFont => cstr!(c"字体"),
+        Text::PropertyTransparentBackground => cstr!(c"透明背景"),
+        Text::PropertyDropShadow => cstr!(c"投影"),
+        Text::PropertyHorizontalOffset => cstr!(c"水平偏移"),
+        Text::PropertyVerticalOffset => cstr!(c"垂直偏移"),
+        Text::PropertyBlurRadius => cstr!(c"模糊半径"),
+        Text::PropertyExportDirectory => cstr!(c"导出文件夹"),
+        Text::PropertyExportImageSequence => cstr!(c"录制尝试的图像序列"),
+        Text::PropertyFramesPerSecond => cstr!(c"每秒帧数"),
+        Text::PropertySaveFrame => cstr!(c"将帧保存为 PNG"),
+        Text::PropertyTextExport => cstr!(c"将计时器数值写入文本文件"),
+        Text::PropertyUpdateInterval => cstr!(c"更新间隔（毫秒）"),
+        Text::TextSourceName => cstr!(c"LiveSplit One 文本"),
+        Text::PropertyTimerValue => cstr!(c"数值"),
+        Text::PropertyTextColor => cstr!(c"文本颜色"),
+        Text::PropertyBackgroundColor => cstr!(c"背景颜色"),
+        Text::TimerValueCurrentTime => cstr!(c"当前时间"),
+        Text::TimerValueCurrentSplit => cstr!(c"当前分段"),
+        Text::TimerValueDelta => cstr!(c"时间差"),
+        Text::TimerValueComparison => cstr!(c"对比"),
+        Text::TimerValuePersonalBest => cstr!(c"个人最佳"),
+        Text::TimerValueSumOfBest => cstr!(c"最佳分段总和"),
+        Text::TimerValueAttempts => cstr!(c"尝试次数"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One 计时器事件"),
+        Text::PropertyIdleColor => cstr!(c"无事件时的颜色"),
+        Text::PropertyDuration => cstr!(c"持续时间（秒，0 = 直到下一个事件）"),
+        Text::TriggerStart => cstr!(c"开始时"),
+        Text::TriggerAhead => cstr!(c"分段领先时"),
+        Text::TriggerBehind => cstr!(c"分段落后时"),
+        Text::TriggerGold => cstr!(c"最佳分段时"),
+        Text::TriggerPersonalBest => cstr!(c"个人最佳时"),
+        Text::TriggerReset => cstr!(c"重置时"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"场景切换规则 (TRIGGER -> scene / show / hide 名称)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"控制录制"),
+        Text::PropertyRecordOnStart => cstr!(c"计时器开始时开始录制"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"重置时停止录制"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"完成时停止录制"),
+        Text::PropertySaveReplayOnGold => cstr!(c"金色分段时保存回放"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"个人最佳时保存回放"),
+        Text::PropertyNameRecordings => cstr!(c"按游戏、类别和尝试次数命名录像"),
+        Text::PropertyChapters => cstr!(c"在每个分段添加章节"),
+        Text::PropertyChapterMarkers => cstr!(c"向录像添加章节标记"),
+        Text::PropertyChaptersDirectory => cstr!(c"章节文件目录"),
+        Text::PropertyTimestampLog => cstr!(c"记录每次尝试的时间戳"),
+        Text::PropertyFileFormat => cstr!(c"文件格式"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One 提示音"),
+        Text::PropertyVolume => cstr!(c"音量 (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave 文件 (*.wav)"),
+        Text::CueSplit => cstr!(c"分段提示音"),
+        Text::CueGold => cstr!(c"金色分段提示音"),
+        Text::CueBehind => cstr!(c"落后提示音"),
+        Text::CuePersonalBest => cstr!(c"个人最佳提示音"),
+        Text::CueUndo => cstr!(c"撤销分段提示音"),
+        Text::CueReset => cstr!(c"重置提示音"),
+        Text::PropertyControlPanel => cstr!(c"用于浏览器停靠窗口的控制面板"),
+        Text::PropertyPort => cstr!(c"端口"),
+        Text::PropertyAllowOtherComputers => cstr!(c"允许从其他计算机访问"),
+        Text::AutoSplitterRefreshList => cstr!(c"刷新列表"),
+        Text::AutoSplitterGame => cstr!(c"游戏"),
+        Text::AutoSplitterAutomatic => cstr!(c"自动"),
+        Text::AutoSplitterLists => cstr!(c"自动分段器列表"),
+        Text::AutoSplitterListsDescription => cstr!(c"每行一个列表。同一列表的备用位置（如镜像）用 | 分隔。位置可以是 URL、文件或包含列表文件的文件夹。越靠下的列表优先级越高。刷新列表以应用更改。"),
+        Text::AutoSplitterStatus => cstr!(c"自动分段器状态"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"自动分段器已加载。"),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"未加载自动分段器。"),
+        Text::AutoSplitterStatusProcess => cstr!(c"进程"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"刷新率"),
+        Text::AutoSplitterStatusLastError => cstr!(c"最近的错误"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"未知"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"刷新状态"),
+        Text::AutoSplitterSettingsFile => cstr!(c"设置文件"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"自动分段器设置 (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"导入设置"),
+        Text::AutoSplitterExportSettings => cstr!(c"导出设置"),
+        Text::PropertyCustomVariableFiles => cstr!(c"自定义变量（{name} 为变量名）"),
+        Text::PropertyAccessToken => cstr!(c"访问令牌（地址的一部分）"),
+        Text::AutoSplitterChooseGame => cstr!(c"分段的游戏与任何自动分段器都不完全匹配。请从列表中选择游戏。"),
+        Text::AutoSplitterImportFile => cstr!(c"要导入的设置文件"),
+        Text::PropertyControlPanelAddress => cstr!(c"浏览器停靠窗口的地址"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"接受已更改的自动分段器"),
     }
 }
 
@@ -590,6 +1680,94 @@ fn resolve_chinese_traditional(text: Text) -> *const c_char {
         Text::AutoSplitterSettingsGroup => cstr!(c"自動分段器設定"),
         Text::AutoSplitterIncompatible => cstr!(c"此遊戲的自動分段器與 LiveSplit One 不相容。"),
         Text::AutoSplitterUnavailable => cstr!(c"此遊戲沒有可用的自動分段器。"),
+        Text::PropertyOverrideBackground => cstr!(c"覆寫背景"),
+        Text::PropertyOverrideTextColor => cstr!(c"覆寫文字顏色"),
+        Text::PropertyOverrideTimerFont => cstr!(c"覆寫計時器字型"),
+        Text::PropertyOverrideTextFont => cstr!(c"覆寫文字字型"),
+        Text::PropertyOverrideTimesFont => cstr!(c"覆寫時間字型"),
+        Text::PropertyColor => cstr!(c"顏色"),
+        Text::PropertyFont => cstr!(c"字型"),
+        Text::PropertyTransparentBackground => cstr!(c"透明背景"),
+        Text::PropertyDropShadow => cstr!(c"陰影"),
+        Text::PropertyHorizontalOffset => cstr!(c"水平偏移"),
+        Text::PropertyVerticalOffset => cstr!(c"垂直偏移"),
+        Text::PropertyBlurRadius => cstr!(c"模糊半徑"),
+        Text::PropertyExportDirectory => cstr!(c"匯出資料夾"),
+        Text::PropertyExportImageSequence => cstr!(c"錄製嘗試的影像序列"),
+        Text::PropertyFramesPerSecond => cstr!(c"每秒影格數"),
+        Text::PropertySaveFrame => cstr!(c"將影格儲存為 PNG"),
+        Text::PropertyTextExport => cstr!(c"將計時器數值寫入文字檔"),
+        Text::PropertyUpdateInterval => cstr!(c"更新間隔（毫秒）"),
+        Text::TextSourceName => cstr!(c"LiveSplit One 文字"),
+        Text::PropertyTimerValue => cstr!(c"數值"),
+        Text::PropertyTextColor => cstr!(c"文字顏色"),
+        Text::PropertyBackgroundColor => cstr!(c"背景顏色"),
+        Text::TimerValueCurrentTime => cstr!(c"目前時間"),
+        Text::TimerValueCurrentSplit => cstr!(c"目前分段"),
+        Text::TimerValueDelta => cstr!(c"時間差"),
+        Text::TimerValueComparison => cstr!(c"比較"),
+        Text::TimerValuePersonalBest => cstr!(c"個人最佳"),
+        Text::TimerValueSumOfBest => cstr!(c"最佳分段總和"),
+        Text::TimerValueAttempts => cstr!(c"嘗試次數"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One 計時器事件"),
+        Text::PropertyIdleColor => cstr!(c"無事件時的顏色"),
+        Text::PropertyDuration => cstr!(c"持續時間（秒，0 = 直到下一個事件）"),
+        Text::TriggerStart => cstr!(c"開始時"),
+        Text::TriggerAhead => cstr!(c"分段領先時"),
+        Text::TriggerBehind => cstr!(c"分段落後時"),
+        Text::TriggerGold => cstr!(c"最佳分段時"),
+        Text::TriggerPersonalBest => cstr!(c"個人最佳時"),
+        Text::TriggerReset => cstr!(c"重置時"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"場景切換規則 (TRIGGER -> scene / show / hide 名稱)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"控制錄影"),
+        Text::PropertyRecordOnStart => cstr!(c"計時器開始時開始錄影"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"重設時停止錄影"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"完成時停止錄影"),
+        Text::PropertySaveReplayOnGold => cstr!(c"金色分段時儲存重播"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"個人最佳時儲存重播"),
+        Text::PropertyNameRecordings => cstr!(c"依遊戲、類別與嘗試次數命名錄影"),
+        Text::PropertyChapters => cstr!(c"在每個分段加入章節"),
+        Text::PropertyChapterMarkers => cstr!(c"在錄影中加入章節標記"),
+        Text::PropertyChaptersDirectory => cstr!(c"章節檔案目錄"),
+        Text::PropertyTimestampLog => cstr!(c"記錄每次嘗試的時間戳記"),
+        Text::PropertyFileFormat => cstr!(c"檔案格式"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One 提示音"),
+        Text::PropertyVolume => cstr!(c"音量 (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave 檔案 (*.wav)"),
+        Text::CueSplit => cstr!(c"分段提示音"),
+        Text::CueGold => cstr!(c"金色分段提示音"),
+        Text::CueBehind => cstr!(c"落後提示音"),
+        Text::CuePersonalBest => cstr!(c"個人最佳提示音"),
+        Text::CueUndo => cstr!(c"復原分段提示音"),
+        Text::CueReset => cstr!(c"重設提示音"),
+        Text::PropertyControlPanel => cstr!(c"用於瀏覽器停駐視窗的控制面板"),
+        Text::PropertyPort => cstr!(c"連接埠"),
+        Text::PropertyAllowOtherComputers => cstr!(c"允許從其他電腦存取"),
+        Text::AutoSplitterRefreshList => cstr!(c"重新整理清單"),
+        Text::AutoSplitterGame => cstr!(c"遊戲"),
+        Text::AutoSplitterAutomatic => cstr!(c"自動"),
+        Text::AutoSplitterLists => cstr!(c"自動分段器清單"),
+        Text::AutoSplitterListsDescription => cstr!(c"每行一個清單。同一清單的備用位置（例如鏡像）以 | 分隔。位置可以是 URL、檔案或包含清單檔案的資料夾。越下方的清單優先順序越高。重新整理清單以套用變更。"),
+        Text::AutoSplitterStatus => cstr!(c"自動分段器狀態"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"自動分段器已載入。"),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"未載入自動分段器。"),
+        Text::AutoSplitterStatusProcess => cstr!(c"處理程序"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"更新頻率"),
+        Text::AutoSplitterStatusLastError => cstr!(c"最近的錯誤"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"未知"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"重新整理狀態"),
+        Text::AutoSplitterSettingsFile => cstr!(c"設定檔"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"自動分段器設定 (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"匯入設定"),
+        Text::AutoSplitterExportSettings => cstr!(c"匯出設定"),
+        Text::PropertyCustomVariableFiles => cstr!(c"自訂變數（{name} 為變數名稱）"),
+        Text::PropertyAccessToken => cstr!(c"存取權杖（位址的一部分）"),
+        Text::AutoSplitterChooseGame => cstr!(c"分段的遊戲與任何自動分段器都不完全相符。請從清單中選擇遊戲。"),
+        Text::AutoSplitterImportFile => cstr!(c"要匯入的設定檔"),
+        Text::PropertyControlPanelAddress => cstr!(c"瀏覽器停駐視窗的位址"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"接受已變更的自動分段器"),
     }
 }
 
@@ -634,6 +1812,94 @@ fn resolve_japanese(text: Text) -> *const c_char {
         Text::AutoSplitterUnavailable => {
             cstr!(c"このゲームで利用可能な自動スプリッターはありません。")
         }
+        Text::PropertyOverrideBackground => cstr!(c"背景を上書き"),
+        Text::PropertyOverrideTextColor => cstr!(c"テキストの色を上書き"),
+        Text::PropertyOverrideTimerFont => cstr!(c"タイマーのフォントを上書き"),
+        Text::PropertyOverrideTextFont => cstr!(c"テキストのフォントを上書き"),
+        Text::PropertyOverrideTimesFont => cstr!(c"タイムのフォントを上書き"),
+        Text::PropertyColor => cstr!(c"色"),
+        Text::PropertyFont => cstr!(c"フォント"),
+        Text::PropertyTransparentBackground => cstr!(c"透明な背景"),
+        Text::PropertyDropShadow => cstr!(c"ドロップシャドウ"),
+        Text::PropertyHorizontalOffset => cstr!(c"水平オフセット"),
+        Text::PropertyVerticalOffset => cstr!(c"垂直オフセット"),
+        Text::PropertyBlurRadius => cstr!(c"ぼかし半径"),
+        Text::PropertyExportDirectory => cstr!(c"エクスポートフォルダー"),
+        Text::PropertyExportImageSequence => cstr!(c"試行の画像シーケンスを記録"),
+        Text::PropertyFramesPerSecond => cstr!(c"フレームレート"),
+        Text::PropertySaveFrame => cstr!(c"フレームを PNG として保存"),
+        Text::PropertyTextExport => cstr!(c"タイマーの値をテキストファイルに書き込む"),
+        Text::PropertyUpdateInterval => cstr!(c"更新間隔（ミリ秒）"),
+        Text::TextSourceName => cstr!(c"LiveSplit One テキスト"),
+        Text::PropertyTimerValue => cstr!(c"値"),
+        Text::PropertyTextColor => cstr!(c"文字色"),
+        Text::PropertyBackgroundColor => cstr!(c"背景色"),
+        Text::TimerValueCurrentTime => cstr!(c"現在のタイム"),
+        Text::TimerValueCurrentSplit => cstr!(c"現在の区間"),
+        Text::TimerValueDelta => cstr!(c"差分"),
+        Text::TimerValueComparison => cstr!(c"比較対象"),
+        Text::TimerValuePersonalBest => cstr!(c"自己ベスト"),
+        Text::TimerValueSumOfBest => cstr!(c"ベスト区間合計"),
+        Text::TimerValueAttempts => cstr!(c"試行回数"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One タイマーイベント"),
+        Text::PropertyIdleColor => cstr!(c"イベントがない時の色"),
+        Text::PropertyDuration => cstr!(c"継続時間（秒、0 = 次のイベントまで）"),
+        Text::TriggerStart => cstr!(c"開始時"),
+        Text::TriggerAhead => cstr!(c"先行で区切った時"),
+        Text::TriggerBehind => cstr!(c"遅れで区切った時"),
+        Text::TriggerGold => cstr!(c"ベスト区間の時"),
+        Text::TriggerPersonalBest => cstr!(c"自己ベストの時"),
+        Text::TriggerReset => cstr!(c"リセット時"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"シーン切り替えルール (TRIGGER -> scene / show / hide 名前)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"録画を制御"),
+        Text::PropertyRecordOnStart => cstr!(c"タイマー開始時に録画を開始"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"リセット時に録画を停止"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"ラン完了時に録画を停止"),
+        Text::PropertySaveReplayOnGold => cstr!(c"ゴールドスプリットでリプレイを保存"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"自己ベストでリプレイを保存"),
+        Text::PropertyNameRecordings => cstr!(c"ゲーム・カテゴリー・試行回数で録画に名前を付ける"),
+        Text::PropertyChapters => cstr!(c"各スプリットにチャプター"),
+        Text::PropertyChapterMarkers => cstr!(c"録画にチャプターマーカーを追加"),
+        Text::PropertyChaptersDirectory => cstr!(c"チャプターファイルのフォルダー"),
+        Text::PropertyTimestampLog => cstr!(c"各試行のタイムスタンプを記録"),
+        Text::PropertyFileFormat => cstr!(c"ファイル形式"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One 効果音"),
+        Text::PropertyVolume => cstr!(c"音量 (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave ファイル (*.wav)"),
+        Text::CueSplit => cstr!(c"スプリットの効果音"),
+        Text::CueGold => cstr!(c"ゴールドスプリットの効果音"),
+        Text::CueBehind => cstr!(c"遅れの効果音"),
+        Text::CuePersonalBest => cstr!(c"自己ベストの効果音"),
+        Text::CueUndo => cstr!(c"スプリット取り消しの効果音"),
+        Text::CueReset => cstr!(c"リセットの効果音"),
+        Text::PropertyControlPanel => cstr!(c"ブラウザドック用コントロールパネル"),
+        Text::PropertyPort => cstr!(c"ポート"),
+        Text::PropertyAllowOtherComputers => cstr!(c"他のコンピューターからのアクセスを許可"),
+        Text::AutoSplitterRefreshList => cstr!(c"リストを更新"),
+        Text::AutoSplitterGame => cstr!(c"ゲーム"),
+        Text::AutoSplitterAutomatic => cstr!(c"自動"),
+        Text::AutoSplitterLists => cstr!(c"オートスプリッターのリスト"),
+        Text::AutoSplitterListsDescription => cstr!(c"1行に1つのリストを指定します。ミラーなど同じリストの代替の場所は | で区切ります。場所にはURL、ファイル、またはリストファイルのフォルダーを指定できます。下にあるリストほど優先されます。変更を適用するにはリストを更新してください。"),
+        Text::AutoSplitterStatus => cstr!(c"オートスプリッターの状態"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"オートスプリッターは読み込まれています。"),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"オートスプリッターは読み込まれていません。"),
+        Text::AutoSplitterStatusProcess => cstr!(c"プロセス"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"ティックレート"),
+        Text::AutoSplitterStatusLastError => cstr!(c"最後のエラー"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"不明"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"状態を更新"),
+        Text::AutoSplitterSettingsFile => cstr!(c"設定ファイル"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"オートスプリッターの設定 (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"設定をインポート"),
+        Text::AutoSplitterExportSettings => cstr!(c"設定をエクスポート"),
+        Text::PropertyCustomVariableFiles => cstr!(c"カスタム変数（{name} は変数名）"),
+        Text::PropertyAccessToken => cstr!(c"アクセストークン（アドレスの一部）"),
+        Text::AutoSplitterChooseGame => cstr!(c"スプリットのゲームに完全に一致するオートスプリッターがありません。リストからゲームを選択してください。"),
+        Text::AutoSplitterImportFile => cstr!(c"インポートする設定ファイル"),
+        Text::PropertyControlPanelAddress => cstr!(c"ブラウザドックのアドレス"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"変更されたオートスプリッターを承認"),
     }
 }
 
@@ -678,5 +1944,93 @@ fn resolve_korean(text: Text) -> *const c_char {
         Text::AutoSplitterUnavailable => {
             cstr!(c"이 게임에 사용할 수 있는 자동 스플리터가 없습니다.")
         }
+        Text::PropertyOverrideBackground => cstr!(c"배경 재정의"),
+        Text::PropertyOverrideTextColor => cstr!(c"텍스트 색상 재정의"),
+        Text::PropertyOverrideTimerFont => cstr!(c"타이머 글꼴 재정의"),
+        Text::PropertyOverrideTextFont => cstr!(c"텍스트 글꼴 재정의"),
+        Text::PropertyOverrideTimesFont => cstr!(c"시간 글꼴 재정의"),
+        Text::PropertyColor => cstr!(c"색상"),
+        Text::PropertyFont => cstr!(c"글꼴"),
+        Text::PropertyTransparentBackground => cstr!(c"투명 배경"),
+        Text::PropertyDropShadow => cstr!(c"그림자"),
+        Text::PropertyHorizontalOffset => cstr!(c"가로 오프셋"),
+        Text::PropertyVerticalOffset => cstr!(c"세로 오프셋"),
+        Text::PropertyBlurRadius => cstr!(c"흐림 반경"),
+        Text::PropertyExportDirectory => cstr!(c"내보내기 폴더"),
+        Text::PropertyExportImageSequence => cstr!(c"시도의 이미지 시퀀스 기록"),
+        Text::PropertyFramesPerSecond => cstr!(c"초당 프레임 수"),
+        Text::PropertySaveFrame => cstr!(c"프레임을 PNG로 저장"),
+        Text::PropertyTextExport => cstr!(c"타이머 값을 텍스트 파일에 쓰기"),
+        Text::PropertyUpdateInterval => cstr!(c"업데이트 간격 (ms)"),
+        Text::TextSourceName => cstr!(c"LiveSplit One 텍스트"),
+        Text::PropertyTimerValue => cstr!(c"값"),
+        Text::PropertyTextColor => cstr!(c"텍스트 색상"),
+        Text::PropertyBackgroundColor => cstr!(c"배경 색상"),
+        Text::TimerValueCurrentTime => cstr!(c"현재 시간"),
+        Text::TimerValueCurrentSplit => cstr!(c"현재 스플릿"),
+        Text::TimerValueDelta => cstr!(c"차이"),
+        Text::TimerValueComparison => cstr!(c"비교 대상"),
+        Text::TimerValuePersonalBest => cstr!(c"개인 최고 기록"),
+        Text::TimerValueSumOfBest => cstr!(c"최고 구간 합계"),
+        Text::TimerValueAttempts => cstr!(c"시도 횟수"),
+        Text::TimerEventFilterName => cstr!(c"LiveSplit One 타이머 이벤트"),
+        Text::PropertyIdleColor => cstr!(c"이벤트가 없을 때의 색상"),
+        Text::PropertyDuration => cstr!(c"지속 시간 (초, 0 = 다음 이벤트까지)"),
+        Text::TriggerStart => cstr!(c"시작 시"),
+        Text::TriggerAhead => cstr!(c"앞선 스플릿 시"),
+        Text::TriggerBehind => cstr!(c"뒤처진 스플릿 시"),
+        Text::TriggerGold => cstr!(c"최고 구간 시"),
+        Text::TriggerPersonalBest => cstr!(c"개인 최고 기록 시"),
+        Text::TriggerReset => cstr!(c"리셋 시"),
+        Text::PropertySceneSwitchingRules => {
+            cstr!(c"장면 전환 규칙 (TRIGGER -> scene / show / hide 이름)")
+        }
+        Text::PropertyRecordingControl => cstr!(c"녹화 제어"),
+        Text::PropertyRecordOnStart => cstr!(c"타이머 시작 시 녹화 시작"),
+        Text::PropertyStopRecordingOnReset => cstr!(c"리셋 시 녹화 중지"),
+        Text::PropertyStopRecordingOnFinish => cstr!(c"런 완료 시 녹화 중지"),
+        Text::PropertySaveReplayOnGold => cstr!(c"골드 스플릿 시 리플레이 저장"),
+        Text::PropertySaveReplayOnPersonalBest => cstr!(c"개인 최고 기록 시 리플레이 저장"),
+        Text::PropertyNameRecordings => cstr!(c"게임, 카테고리, 시도 횟수로 녹화 이름 지정"),
+        Text::PropertyChapters => cstr!(c"각 스플릿마다 챕터"),
+        Text::PropertyChapterMarkers => cstr!(c"녹화에 챕터 마커 추가"),
+        Text::PropertyChaptersDirectory => cstr!(c"챕터 파일 폴더"),
+        Text::PropertyTimestampLog => cstr!(c"각 시도의 타임스탬프 기록"),
+        Text::PropertyFileFormat => cstr!(c"파일 형식"),
+        Text::AudioCuesSourceName => cstr!(c"LiveSplit One 알림음"),
+        Text::PropertyVolume => cstr!(c"볼륨 (%)"),
+        Text::PropertySoundFilter => cstr!(c"Wave 파일 (*.wav)"),
+        Text::CueSplit => cstr!(c"스플릿 소리"),
+        Text::CueGold => cstr!(c"골드 스플릿 소리"),
+        Text::CueBehind => cstr!(c"뒤처짐 소리"),
+        Text::CuePersonalBest => cstr!(c"개인 최고 기록 소리"),
+        Text::CueUndo => cstr!(c"스플릿 취소 소리"),
+        Text::CueReset => cstr!(c"리셋 소리"),
+        Text::PropertyControlPanel => cstr!(c"브라우저 독용 제어판"),
+        Text::PropertyPort => cstr!(c"포트"),
+        Text::PropertyAllowOtherComputers => cstr!(c"다른 컴퓨터에서의 접근 허용"),
+        Text::AutoSplitterRefreshList => cstr!(c"목록 새로 고침"),
+        Text::AutoSplitterGame => cstr!(c"게임"),
+        Text::AutoSplitterAutomatic => cstr!(c"자동"),
+        Text::AutoSplitterLists => cstr!(c"오토 스플리터 목록"),
+        Text::AutoSplitterListsDescription => cstr!(c"한 줄에 하나의 목록을 입력합니다. 미러처럼 같은 목록의 대체 위치는 |로 구분합니다. 위치는 URL, 파일 또는 목록 파일이 있는 폴더입니다. 아래쪽 목록이 우선합니다. 변경 사항을 적용하려면 목록을 새로 고치세요."),
+        Text::AutoSplitterStatus => cstr!(c"오토 스플리터 상태"),
+        Text::AutoSplitterStatusLoaded => cstr!(c"오토 스플리터가 로드되었습니다."),
+        Text::AutoSplitterStatusNotLoaded => cstr!(c"로드된 오토 스플리터가 없습니다."),
+        Text::AutoSplitterStatusProcess => cstr!(c"프로세스"),
+        Text::AutoSplitterStatusTickRate => cstr!(c"틱 레이트"),
+        Text::AutoSplitterStatusLastError => cstr!(c"마지막 오류"),
+        Text::AutoSplitterStatusUnknown => cstr!(c"알 수 없음"),
+        Text::AutoSplitterRefreshStatus => cstr!(c"상태 새로 고침"),
+        Text::AutoSplitterSettingsFile => cstr!(c"설정 파일"),
+        Text::AutoSplitterSettingsFileFilter => cstr!(c"오토 스플리터 설정 (*.xml *.lss)"),
+        Text::AutoSplitterImportSettings => cstr!(c"설정 가져오기"),
+        Text::AutoSplitterExportSettings => cstr!(c"설정 내보내기"),
+        Text::PropertyCustomVariableFiles => cstr!(c"사용자 지정 변수 ({name}은 변수 이름)"),
+        Text::PropertyAccessToken => cstr!(c"액세스 토큰 (주소의 일부)"),
+        Text::AutoSplitterChooseGame => cstr!(c"스플릿의 게임과 정확히 일치하는 오토 스플리터가 없습니다. 목록에서 게임을 선택하세요."),
+        Text::AutoSplitterImportFile => cstr!(c"가져올 설정 파일"),
+        Text::PropertyControlPanelAddress => cstr!(c"브라우저 독 주소"),
+        Text::AutoSplitterAcceptChanges => cstr!(c"변경된 자동 스플리터 수락"),
     }
 }
@@ -0,0 +1,264 @@
+use std::{
+    ffi::{c_void, CStr, CString},
+    os::raw::c_char,
+    ptr,
+    sync::{Arc, Mutex, Weak},
+};
+
+use livesplit_core::event::Event;
+use log::{debug, info};
+
+use crate::{
+    event_worker::EventHandler,
+    ffi::{
+        config_get_string, config_set_string, obs_data_get_bool, obs_data_set_default_bool,
+        obs_data_t, obs_frontend_add_event_callback, obs_frontend_event,
+        obs_frontend_get_profile_config, obs_frontend_recording_active,
+        obs_frontend_recording_start, obs_frontend_recording_stop,
+        obs_frontend_remove_event_callback, obs_frontend_replay_buffer_active,
+        obs_frontend_replay_buffer_save, obs_properties_add_bool, obs_properties_add_group,
+        obs_properties_create, obs_properties_t, OBS_FRONTEND_EVENT_RECORDING_STARTED,
+        OBS_FRONTEND_EVENT_RECORDING_STOPPED, OBS_GROUP_CHECKABLE,
+    },
+    localization::{lang, Text},
+    timer_events::TimerEvent,
+    InnerTimer,
+};
+
+const SETTINGS_RECORDING_CONTROL: *const c_char = cstr!(c"recording_control");
+const SETTINGS_RECORD_ON_START: *const c_char = cstr!(c"record_on_start");
+const SETTINGS_STOP_RECORDING_ON_RESET: *const c_char = cstr!(c"stop_recording_on_reset");
+const SETTINGS_STOP_RECORDING_ON_FINISH: *const c_char = cstr!(c"stop_recording_on_finish");
+const SETTINGS_SAVE_REPLAY_ON_GOLD: *const c_char = cstr!(c"save_replay_on_gold");
+const SETTINGS_SAVE_REPLAY_ON_PB: *const c_char = cstr!(c"save_replay_on_pb");
+const SETTINGS_NAME_RECORDINGS: *const c_char = cstr!(c"name_recordings");
+
+/// Starts and stops the recording of OBS and saves the replay buffer in
+/// reaction to the events of a timer, according to the options of a source.
+pub struct RecordingControl {
+    options: RecordingOptions,
+    timer: Weak<InnerTimer>,
+}
+
+#[derive(Copy, Clone)]
+pub struct RecordingOptions {
+    record_on_start: bool,
+    stop_on_reset: bool,
+    stop_on_finish: bool,
+    save_replay_on_gold: bool,
+    save_replay_on_pb: bool,
+    name_recordings: bool,
+}
+
+/// The user's own format for the file names of recordings, while it's
+/// replaced for a recording that the timer started. There's only a single
+/// profile, so this is shared by all the sources.
+static PREVIOUS_FILE_NAME_FORMAT: Mutex<Option<CString>> = Mutex::new(None);
+
+impl EventHandler for RecordingControl {
+    type Options = RecordingOptions;
+
+    fn new(options: RecordingOptions, timer: &Arc<InnerTimer>) -> Self {
+        // The timer itself is only weakly referenced, so it can still go
+        // away.
+        Self {
+            options,
+            timer: Arc::downgrade(timer),
+        }
+    }
+
+    fn set_options(&mut self, options: RecordingOptions) {
+        self.options = options;
+    }
+
+    fn handle_event(&mut self, event: &TimerEvent) {
+        let options = self.options;
+
+        unsafe {
+            match event.event {
+                Event::Started if options.record_on_start && !obs_frontend_recording_active() => {
+                    if options.name_recordings {
+                        if let Some(timer) = self.timer.upgrade() {
+                            let format = file_name_format(&timer, event.attempt);
+                            let mut previous_format = PREVIOUS_FILE_NAME_FORMAT.lock().unwrap();
+                            let previous = set_file_name_format(&format);
+                            if previous_format.is_none() {
+                                *previous_format = previous;
+                            }
+                        }
+                    }
+                    info!("Starting the recording.");
+                    obs_frontend_recording_start();
+                }
+                Event::Reset if options.stop_on_reset => stop_recording(),
+                Event::Finished if options.stop_on_finish => stop_recording(),
+                _ => {}
+            }
+
+            if let Some(split) = &event.split {
+                if ((options.save_replay_on_gold && split.is_gold)
+                    || (options.save_replay_on_pb && split.is_personal_best))
+                    && obs_frontend_replay_buffer_active()
+                {
+                    debug!("Saving the replay buffer.");
+                    obs_frontend_replay_buffer_save();
+                }
+            }
+        }
+    }
+}
+
+unsafe fn stop_recording() {
+    unsafe {
+        if obs_frontend_recording_active() {
+            info!("Stopping the recording.");
+            obs_frontend_recording_stop();
+        }
+    }
+}
+
+/// Restores the user's own file name format whenever a recording starts or
+/// stops, no matter who started or stopped it. The file name is determined
+/// when the recording starts, so it's not needed any longer by then. If the
+/// recording fails to start, it's reported as stopped.
+pub unsafe fn register() {
+    unsafe { obs_frontend_add_event_callback(Some(on_frontend_event), ptr::null_mut()) }
+}
+
+pub unsafe fn unregister() {
+    unsafe {
+        obs_frontend_remove_event_callback(Some(on_frontend_event), ptr::null_mut());
+        restore_file_name_format();
+    }
+}
+
+unsafe extern "C" fn on_frontend_event(event: obs_frontend_event, _: *mut c_void) {
+    if matches!(
+        event,
+        OBS_FRONTEND_EVENT_RECORDING_STARTED | OBS_FRONTEND_EVENT_RECORDING_STOPPED
+    ) {
+        unsafe { restore_file_name_format() };
+    }
+}
+
+unsafe fn restore_file_name_format() {
+    unsafe {
+        if let Some(previous) = PREVIOUS_FILE_NAME_FORMAT.lock().unwrap().take() {
+            set_file_name_format(&previous);
+        }
+    }
+}
+
+/// Formats the file names like `Game - Category - Attempt 42 <date>`. The date
+/// keeps the names unique if attempts are reset without being saved.
+fn file_name_format(timer: &InnerTimer, attempt: u32) -> CString {
+    let timer = timer.timer.read().unwrap();
+    let run = timer.run();
+
+    let mut format = String::new();
+    for name in [run.game_name(), run.category_name()] {
+        if !name.is_empty() {
+            format.push_str(&sanitize(name));
+            format.push_str(" - ");
+        }
+    }
+    format.push_str(&format!("Attempt {attempt} %CCYY-%MM-%DD %hh-%mm-%ss"));
+
+    CString::new(format).unwrap_or_default()
+}
+
+// OBS uses `%` for its own specifiers, so it needs to be replaced as well.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '%' | '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Sets the format OBS uses for the file names of recordings and returns the
+/// previous one.
+unsafe fn set_file_name_format(format: &CStr) -> Option<CString> {
+    unsafe {
+        let config = obs_frontend_get_profile_config();
+        if config.is_null() {
+            return None;
+        }
+        let previous = config_get_string(config, cstr!(c"Output"), cstr!(c"FilenameFormatting"));
+        let previous = (!previous.is_null()).then(|| CStr::from_ptr(previous).to_owned());
+        config_set_string(
+            config,
+            cstr!(c"Output"),
+            cstr!(c"FilenameFormatting"),
+            format.as_ptr(),
+        );
+        previous
+    }
+}
+
+impl RecordingOptions {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Option<Self> {
+        unsafe {
+            if !obs_data_get_bool(settings, SETTINGS_RECORDING_CONTROL) {
+                return None;
+            }
+
+            Some(Self {
+                record_on_start: obs_data_get_bool(settings, SETTINGS_RECORD_ON_START),
+                stop_on_reset: obs_data_get_bool(settings, SETTINGS_STOP_RECORDING_ON_RESET),
+                stop_on_finish: obs_data_get_bool(settings, SETTINGS_STOP_RECORDING_ON_FINISH),
+                save_replay_on_gold: obs_data_get_bool(settings, SETTINGS_SAVE_REPLAY_ON_GOLD),
+                save_replay_on_pb: obs_data_get_bool(settings, SETTINGS_SAVE_REPLAY_ON_PB),
+                name_recordings: obs_data_get_bool(settings, SETTINGS_NAME_RECORDINGS),
+            })
+        }
+    }
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        for (name, text) in [
+            (SETTINGS_RECORD_ON_START, Text::PropertyRecordOnStart),
+            (
+                SETTINGS_STOP_RECORDING_ON_RESET,
+                Text::PropertyStopRecordingOnReset,
+            ),
+            (
+                SETTINGS_STOP_RECORDING_ON_FINISH,
+                Text::PropertyStopRecordingOnFinish,
+            ),
+            (SETTINGS_SAVE_REPLAY_ON_GOLD, Text::PropertySaveReplayOnGold),
+            (
+                SETTINGS_SAVE_REPLAY_ON_PB,
+                Text::PropertySaveReplayOnPersonalBest,
+            ),
+            (SETTINGS_NAME_RECORDINGS, Text::PropertyNameRecordings),
+        ] {
+            obs_properties_add_bool(group, name, text.resolve(lang));
+        }
+        obs_properties_add_group(
+            props,
+            SETTINGS_RECORDING_CONTROL,
+            Text::PropertyRecordingControl.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_bool(settings, SETTINGS_RECORDING_CONTROL, false);
+        obs_data_set_default_bool(settings, SETTINGS_RECORD_ON_START, true);
+        obs_data_set_default_bool(settings, SETTINGS_STOP_RECORDING_ON_RESET, true);
+        obs_data_set_default_bool(settings, SETTINGS_STOP_RECORDING_ON_FINISH, false);
+        obs_data_set_default_bool(settings, SETTINGS_SAVE_REPLAY_ON_GOLD, false);
+        obs_data_set_default_bool(settings, SETTINGS_SAVE_REPLAY_ON_PB, false);
+        obs_data_set_default_bool(settings, SETTINGS_NAME_RECORDINGS, true);
+    }
+}
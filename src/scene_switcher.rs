@@ -0,0 +1,184 @@
+use std::{ffi::CString, sync::Arc};
+
+use livesplit_core::event::Event;
+use log::{debug, warn};
+
+use crate::{
+    event_worker::EventHandler,
+    ffi::{
+        obs_frontend_get_current_scene, obs_frontend_set_current_scene, obs_get_source_by_name,
+        obs_scene_find_source_recursive, obs_scene_from_source, obs_sceneitem_set_visible,
+        obs_source_release,
+    },
+    timer_events::TimerEvent,
+    InnerTimer,
+};
+
+/// Switches scenes and toggles the visibility of sources in reaction to the
+/// events of a timer, according to the rules of a source.
+pub struct SceneSwitcher {
+    rules: Vec<Rule>,
+}
+
+/// A single rule in the form of `<trigger> -> <action>`, e.g.
+/// `finish -> scene Run Ended` or `split Boss -> show Boss Cam`.
+#[derive(Clone)]
+pub struct Rule {
+    trigger: Trigger,
+    action: Action,
+}
+
+#[derive(Clone)]
+enum Trigger {
+    Start,
+    Split,
+    SplitOf(String),
+    Gold,
+    Finish,
+    PersonalBest,
+    Pause,
+    Resume,
+    Reset,
+}
+
+#[derive(Clone)]
+enum Action {
+    Scene(CString),
+    Show(CString),
+    Hide(CString),
+}
+
+impl EventHandler for SceneSwitcher {
+    type Options = Vec<Rule>;
+
+    fn new(rules: Vec<Rule>, _: &Arc<InnerTimer>) -> Self {
+        Self { rules }
+    }
+
+    fn set_options(&mut self, rules: Vec<Rule>) {
+        self.rules = rules;
+    }
+
+    fn handle_event(&mut self, event: &TimerEvent) {
+        for rule in &self.rules {
+            if rule.trigger.matches(event) {
+                unsafe { rule.action.perform() };
+            }
+        }
+    }
+}
+
+impl Rule {
+    fn parse(rule: &str) -> Option<Self> {
+        let (trigger, action) = rule.split_once("->")?;
+        Some(Self {
+            trigger: Trigger::parse(trigger.trim())?,
+            action: Action::parse(action.trim())?,
+        })
+    }
+
+    /// Parses all the valid rules. There's nothing to do if there aren't
+    /// any.
+    pub fn parse_all(rules: &[String]) -> Option<Vec<Self>> {
+        let rules = rules
+            .iter()
+            .filter_map(|rule| {
+                let parsed = Self::parse(rule);
+                if parsed.is_none() {
+                    warn!("Invalid scene switching rule: '{rule}'");
+                }
+                parsed
+            })
+            .collect::<Vec<_>>();
+        (!rules.is_empty()).then_some(rules)
+    }
+}
+
+impl Trigger {
+    fn parse(trigger: &str) -> Option<Self> {
+        let (kind, argument) = match trigger.split_once(' ') {
+            Some((kind, argument)) => (kind, Some(argument.trim())),
+            None => (trigger, None),
+        };
+
+        Some(match (kind.to_ascii_lowercase().as_str(), argument) {
+            ("start", None) => Self::Start,
+            ("split", None) => Self::Split,
+            ("split", Some(segment)) => Self::SplitOf(segment.to_owned()),
+            ("gold", None) => Self::Gold,
+            ("finish", None) => Self::Finish,
+            ("pb", None) => Self::PersonalBest,
+            ("pause", None) => Self::Pause,
+            ("resume", None) => Self::Resume,
+            ("reset", None) => Self::Reset,
+            _ => return None,
+        })
+    }
+
+    fn matches(&self, event: &TimerEvent) -> bool {
+        let split = event.split.as_ref();
+        match self {
+            Self::Start => matches!(event.event, Event::Started),
+            Self::Split => matches!(event.event, Event::Splitted),
+            // Skipping or undoing the split doesn't count.
+            Self::SplitOf(segment) => {
+                matches!(event.event, Event::Splitted | Event::Finished)
+                    && split.is_some_and(|split| split.name == *segment)
+            }
+            Self::Gold => split.is_some_and(|split| split.is_gold),
+            Self::Finish => matches!(event.event, Event::Finished),
+            Self::PersonalBest => split.is_some_and(|split| split.is_personal_best),
+            Self::Pause => matches!(event.event, Event::Paused),
+            Self::Resume => matches!(event.event, Event::Resumed),
+            Self::Reset => matches!(event.event, Event::Reset),
+        }
+    }
+}
+
+impl Action {
+    fn parse(action: &str) -> Option<Self> {
+        let (kind, name) = action.split_once(' ')?;
+        let name = CString::new(name.trim()).ok()?;
+        Some(match kind.to_ascii_lowercase().as_str() {
+            "scene" => Self::Scene(name),
+            "show" => Self::Show(name),
+            "hide" => Self::Hide(name),
+            _ => return None,
+        })
+    }
+
+    unsafe fn perform(&self) {
+        unsafe {
+            match self {
+                Self::Scene(name) => {
+                    let scene = obs_get_source_by_name(name.as_ptr());
+                    if scene.is_null() {
+                        warn!("Couldn't find the scene {name:?}.");
+                        return;
+                    }
+                    debug!("Switching to the scene {name:?}.");
+                    obs_frontend_set_current_scene(scene);
+                    obs_source_release(scene);
+                }
+                Self::Show(name) | Self::Hide(name) => {
+                    // The sources are looked up in the scene that is currently
+                    // live, as the same source may be in multiple scenes.
+                    let scene_source = obs_frontend_get_current_scene();
+                    if scene_source.is_null() {
+                        return;
+                    }
+                    let item = obs_scene_find_source_recursive(
+                        obs_scene_from_source(scene_source),
+                        name.as_ptr(),
+                    );
+                    if item.is_null() {
+                        warn!("Couldn't find the source {name:?} in the current scene.");
+                    } else {
+                        obs_sceneitem_set_visible(item, matches!(self, Self::Show(_)));
+                    }
+                    obs_source_release(scene_source);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! Lets scripts and other plugins query and control the timer of a source
+//! through its procedure handler, and informs them about everything that
+//! happens to the timer through its signal handler.
+
+use std::{
+    ffi::{c_void, CStr, CString},
+    mem,
+    os::raw::c_char,
+    sync::{mpsc::Receiver, Arc, Mutex},
+};
+
+use livesplit_core::{
+    event::{CommandSink, Event},
+    TimerPhase, TimingMethod,
+};
+
+use crate::{
+    ffi::{
+        calldata_get_string, calldata_init_fixed, calldata_set_data, calldata_t,
+        obs_source_get_proc_handler, obs_source_get_signal_handler, obs_source_t, proc_handler_add,
+        proc_handler_proc_t, signal_handler_add, signal_handler_signal,
+    },
+    timer_events::TimerEvent,
+    GlobalTimer, State,
+};
+
+const SIGNAL_TIMER_EVENT: *const c_char = cstr!(c"timer_event");
+
+/// Relays the events of the timer as signals of the source.
+pub struct TimerSignals {
+    receiver: Receiver<Arc<TimerEvent>>,
+}
+
+impl TimerSignals {
+    pub fn new(global_timer: &GlobalTimer) -> Self {
+        Self {
+            receiver: global_timer.timer.events.subscribe(),
+        }
+    }
+
+    /// Takes the events that happened since the last tick.
+    pub fn take(&self) -> Vec<Arc<TimerEvent>> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+/// Emits the events as signals of the source.
+pub unsafe fn emit(source: *mut obs_source_t, events: &[Arc<TimerEvent>]) {
+    unsafe {
+        let handler = obs_source_get_signal_handler(source);
+        for event in events {
+            let mut stack = [0u8; 512];
+            let mut cd = calldata_init_fixed(&mut stack);
+            set_ptr(&mut cd, cstr!(c"source"), source.cast());
+            set_string(&mut cd, cstr!(c"event"), event_name(&event.event));
+            set_string(&mut cd, cstr!(c"phase"), phase_name(event.phase));
+            set_int(
+                &mut cd,
+                cstr!(c"split_index"),
+                event.split.as_ref().map_or(-1, |split| split.index as i64),
+            );
+            let split_name = event
+                .split
+                .as_ref()
+                .and_then(|split| CString::new(split.name.as_str()).ok())
+                .unwrap_or_default();
+            set_string(&mut cd, cstr!(c"split_name"), &split_name);
+            signal_handler_signal(handler, SIGNAL_TIMER_EVENT, &mut cd);
+        }
+    }
+}
+
+fn event_name(event: &Event) -> &'static CStr {
+    match event {
+        Event::Started => c"started",
+        Event::Splitted => c"split",
+        Event::Finished => c"finished",
+        Event::Reset => c"reset",
+        Event::SplitUndone => c"split_undone",
+        Event::SplitSkipped => c"split_skipped",
+        Event::Paused => c"paused",
+        Event::Resumed => c"resumed",
+        Event::PausesUndone => c"pauses_undone",
+        Event::PausesUndoneAndResumed => c"pauses_undone_and_resumed",
+        Event::ComparisonChanged => c"comparison_changed",
+        Event::TimingMethodChanged => c"timing_method_changed",
+        Event::CustomVariableSet => c"custom_variable_set",
+        _ => c"other",
+    }
+}
+
+fn phase_name(phase: TimerPhase) -> &'static CStr {
+    match phase {
+        TimerPhase::NotRunning => c"not_running",
+        TimerPhase::Running => c"running",
+        TimerPhase::Ended => c"ended",
+        TimerPhase::Paused => c"paused",
+    }
+}
+
+unsafe fn set_string(cd: *mut calldata_t, name: *const c_char, value: &CStr) {
+    unsafe {
+        let bytes = value.to_bytes_with_nul();
+        calldata_set_data(cd, name, bytes.as_ptr().cast(), bytes.len());
+    }
+}
+
+unsafe fn set_int(cd: *mut calldata_t, name: *const c_char, value: i64) {
+    unsafe { calldata_set_data(cd, name, (&raw const value).cast(), mem::size_of::<i64>()) }
+}
+
+unsafe fn set_float(cd: *mut calldata_t, name: *const c_char, value: f64) {
+    unsafe { calldata_set_data(cd, name, (&raw const value).cast(), mem::size_of::<f64>()) }
+}
+
+unsafe fn set_bool(cd: *mut calldata_t, name: *const c_char, value: bool) {
+    unsafe { calldata_set_data(cd, name, (&raw const value).cast(), mem::size_of::<bool>()) }
+}
+
+unsafe fn set_ptr(cd: *mut calldata_t, name: *const c_char, value: *mut c_void) {
+    unsafe {
+        calldata_set_data(
+            cd,
+            name,
+            (&raw const value).cast(),
+            mem::size_of::<*mut c_void>(),
+        )
+    }
+}
+
+unsafe fn get_string<'a>(cd: *mut calldata_t, name: *const c_char) -> Option<&'a CStr> {
+    unsafe {
+        let mut value = std::ptr::null();
+        if calldata_get_string(cd, name, &mut value) && !value.is_null() {
+            Some(CStr::from_ptr(value))
+        } else {
+            None
+        }
+    }
+}
+
+/// Registers the procedures and signals of the source. `data` is the state of
+/// the source that is passed to all the procedures.
+pub unsafe fn register(source: *mut obs_source_t, data: *mut c_void) {
+    unsafe {
+        let procs: [(&CStr, proc_handler_proc_t); 12] = [
+            (
+                c"void get_state(out string phase, out float current_time, \
+                 out int split_index, out string split_name, out string comparison, \
+                 out string timing_method, out int attempt)",
+                Some(get_state),
+            ),
+            (c"void start()", Some(start)),
+            (c"void split()", Some(split)),
+            (c"void split_or_start()", Some(split_or_start)),
+            (c"void reset()", Some(reset)),
+            (c"void undo_split()", Some(undo_split)),
+            (c"void skip_split()", Some(skip_split)),
+            (c"void toggle_pause()", Some(toggle_pause)),
+            (c"void undo_all_pauses()", Some(undo_all_pauses)),
+            (
+                c"void set_comparison(in string comparison, out bool success)",
+                Some(set_comparison),
+            ),
+            (
+                c"void set_timing_method(in string timing_method, out bool success)",
+                Some(set_timing_method),
+            ),
+            (c"void toggle_timing_method()", Some(toggle_timing_method)),
+        ];
+
+        let handler = obs_source_get_proc_handler(source);
+        for (decl, proc_) in procs {
+            proc_handler_add(handler, decl.as_ptr(), proc_, data);
+        }
+
+        signal_handler_add(
+            obs_source_get_signal_handler(source),
+            cstr!(
+                c"void timer_event(ptr source, string event, string phase, \
+                int split_index, string split_name)"
+            ),
+        );
+    }
+}
+
+unsafe fn global_timer(data: *mut c_void) -> Arc<GlobalTimer> {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        state.global_timer.clone()
+    }
+}
+
+unsafe extern "C" fn get_state(data: *mut c_void, cd: *mut calldata_t) {
+    unsafe {
+        let global_timer = global_timer(data);
+        let timer = global_timer.timer.get_timer();
+        let method = timer.current_timing_method();
+
+        let current_time = timer.snapshot().current_time()[method].unwrap_or_default();
+        let (split_index, split_name) = match timer.current_split() {
+            Some(segment) => (
+                timer.current_split_index().map_or(-1, |index| index as i64),
+                segment.name(),
+            ),
+            None => (-1, ""),
+        };
+
+        set_string(cd, cstr!(c"phase"), phase_name(timer.current_phase()));
+        set_float(cd, cstr!(c"current_time"), current_time.total_seconds());
+        set_int(cd, cstr!(c"split_index"), split_index);
+        set_string(
+            cd,
+            cstr!(c"split_name"),
+            &CString::new(split_name).unwrap_or_default(),
+        );
+        set_string(
+            cd,
+            cstr!(c"comparison"),
+            &CString::new(timer.current_comparison()).unwrap_or_default(),
+        );
+        set_string(
+            cd,
+            cstr!(c"timing_method"),
+            match method {
+                TimingMethod::RealTime => c"real_time",
+                TimingMethod::GameTime => c"game_time",
+            },
+        );
+        set_int(cd, cstr!(c"attempt"), timer.run().attempt_count().into());
+    }
+}
+
+unsafe extern "C" fn start(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.start()) }
+}
+
+unsafe extern "C" fn split(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.split()) }
+}
+
+unsafe extern "C" fn split_or_start(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.split_or_start()) }
+}
+
+unsafe extern "C" fn reset(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.reset(None)) }
+}
+
+unsafe extern "C" fn undo_split(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.undo_split()) }
+}
+
+unsafe extern "C" fn skip_split(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.skip_split()) }
+}
+
+unsafe extern "C" fn toggle_pause(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.toggle_pause_or_start()) }
+}
+
+unsafe extern "C" fn undo_all_pauses(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.undo_all_pauses()) }
+}
+
+unsafe extern "C" fn toggle_timing_method(data: *mut c_void, _: *mut calldata_t) {
+    unsafe { drop(global_timer(data).timer.toggle_timing_method()) }
+}
+
+unsafe extern "C" fn set_comparison(data: *mut c_void, cd: *mut calldata_t) {
+    unsafe {
+        let success = match get_string(cd, cstr!(c"comparison")) {
+            Some(comparison) => {
+                let comparison = comparison.to_string_lossy().into_owned();
+                global_timer(data)
+                    .timer
+                    .apply(|timer| timer.set_current_comparison(comparison))
+                    .is_ok()
+            }
+            None => false,
+        };
+        set_bool(cd, cstr!(c"success"), success);
+    }
+}
+
+unsafe extern "C" fn set_timing_method(data: *mut c_void, cd: *mut calldata_t) {
+    unsafe {
+        let method = match get_string(cd, cstr!(c"timing_method")).map(CStr::to_bytes) {
+            Some(b"real_time") => Some(TimingMethod::RealTime),
+            Some(b"game_time") => Some(TimingMethod::GameTime),
+            _ => None,
+        };
+        if let Some(method) = method {
+            drop(global_timer(data).timer.set_current_timing_method(method));
+        }
+        set_bool(cd, cstr!(c"success"), method.is_some());
+    }
+}
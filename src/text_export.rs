@@ -0,0 +1,254 @@
+use std::{
+    collections::HashMap,
+    ffi::{CStr, CString},
+    fs,
+    os::raw::c_char,
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use livesplit_core::Timer;
+use log::warn;
+
+use crate::{
+    ffi::{
+        obs_data_get_bool, obs_data_get_int, obs_data_get_string, obs_data_set_default_int,
+        obs_data_set_default_string, obs_data_t, obs_properties_add_group, obs_properties_add_int,
+        obs_properties_add_path, obs_properties_add_text, obs_properties_create, obs_properties_t,
+        OBS_GROUP_CHECKABLE, OBS_PATH_DIRECTORY, OBS_TEXT_DEFAULT,
+    },
+    localization::{lang, Text},
+    timer_values::TimerValue,
+};
+
+const SETTINGS_TEXT_EXPORT: *const c_char = cstr!(c"text_export");
+const SETTINGS_TEXT_EXPORT_DIRECTORY: *const c_char = cstr!(c"text_export_directory");
+const SETTINGS_TEXT_EXPORT_INTERVAL: *const c_char = cstr!(c"text_export_interval");
+const SETTINGS_TEXT_EXPORT_VARIABLE_FILE: *const c_char = cstr!(c"text_export_variable_file");
+
+/// The placeholder in the file name of the custom variables that gets
+/// replaced by the name of the variable.
+const VARIABLE_NAME_PLACEHOLDER: &str = "{name}";
+
+/// Writes individual values of the timer to text files, so they can be shown
+/// with regular text sources. Files are only written when their contents
+/// change. The values are gathered on every tick of the source, whether it's
+/// visible or not, but the files are written by a separate thread, so the
+/// disk never holds up OBS.
+pub struct TextExport {
+    directory: PathBuf,
+    interval: Duration,
+    /// The file name of each value that gets exported.
+    files: Vec<(TimerValue, String)>,
+    /// The file name of the custom variables, if they get exported.
+    variable_file: Option<String>,
+    last_update: Option<Instant>,
+    written: HashMap<PathBuf, String>,
+    writer: Option<Sender<(PathBuf, String)>>,
+}
+
+impl TextExport {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Option<Self> {
+        unsafe {
+            if !obs_data_get_bool(settings, SETTINGS_TEXT_EXPORT) {
+                return None;
+            }
+
+            let directory = CStr::from_ptr(
+                obs_data_get_string(settings, SETTINGS_TEXT_EXPORT_DIRECTORY).cast(),
+            );
+            if directory.is_empty() {
+                return None;
+            }
+
+            let interval = obs_data_get_int(settings, SETTINGS_TEXT_EXPORT_INTERVAL).max(0);
+
+            let files = TimerValue::ALL
+                .into_iter()
+                .filter_map(|value| {
+                    let file_name = get_file_name(settings, file_setting_key(value).as_ptr())?;
+                    Some((value, file_name))
+                })
+                .collect();
+
+            let variable_file = get_file_name(settings, SETTINGS_TEXT_EXPORT_VARIABLE_FILE);
+
+            Some(Self {
+                directory: PathBuf::from(directory.to_string_lossy().into_owned()),
+                interval: Duration::from_millis(interval as u64),
+                files,
+                variable_file,
+                last_update: None,
+                written: HashMap::new(),
+                writer: None,
+            })
+        }
+    }
+
+    pub fn update(&mut self, timer: &Timer) {
+        if self
+            .last_update
+            .is_some_and(|last_update| last_update.elapsed() < self.interval)
+        {
+            return;
+        }
+        self.last_update = Some(Instant::now());
+
+        let mut files = self
+            .files
+            .iter()
+            .map(|(value, file_name)| (self.directory.join(file_name), value.format(timer)))
+            .collect::<Vec<_>>();
+
+        if let Some(variable_file) = &self.variable_file {
+            let run = timer.run();
+            files.extend(run.metadata().custom_variables().map(|(name, variable)| {
+                let file_name =
+                    variable_file.replace(VARIABLE_NAME_PLACEHOLDER, &sanitize_file_name(name));
+                (self.directory.join(file_name), variable.value.clone())
+            }));
+        }
+
+        // Files that aren't exported anymore, like the ones of variables that
+        // got removed, are forgotten.
+        self.written
+            .retain(|path, _| files.iter().any(|(file, _)| file == path));
+
+        for (path, contents) in files {
+            self.write(path, contents);
+        }
+    }
+
+    fn write(&mut self, path: PathBuf, contents: String) {
+        if self.written.get(&path) == Some(&contents) {
+            return;
+        }
+
+        let writer = self.writer.get_or_insert_with(|| {
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || run_writer(receiver));
+            sender
+        });
+        if writer.send((path.clone(), contents.clone())).is_ok() {
+            self.written.insert(path, contents);
+        }
+    }
+}
+
+// Runs until the export and with it the sender is gone.
+fn run_writer(receiver: Receiver<(PathBuf, String)>) {
+    for (path, contents) in receiver {
+        // Text sources may read the file at any time, so it's written next to
+        // it first and then replaces it at once.
+        let mut temporary_path = path.clone().into_os_string();
+        temporary_path.push(".tmp");
+        let temporary_path = PathBuf::from(temporary_path);
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&temporary_path, &contents))
+            .and_then(|_| fs::rename(&temporary_path, &path));
+        if let Err(e) = result {
+            warn!("Failed writing {}: {e}", path.display());
+        }
+    }
+}
+
+fn file_setting_key(value: TimerValue) -> CString {
+    CString::new(format!(
+        "text_export_file_{}",
+        value.key().to_string_lossy()
+    ))
+    .unwrap()
+}
+
+/// Returns the file name, unless it's empty, which means that the value
+/// doesn't get exported.
+unsafe fn get_file_name(settings: *mut obs_data_t, key: *const c_char) -> Option<String> {
+    unsafe {
+        let file_name = CStr::from_ptr(obs_data_get_string(settings, key).cast());
+        let file_name = file_name.to_string_lossy();
+        let file_name = file_name.trim();
+        (!file_name.is_empty()).then(|| file_name.to_owned())
+    }
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        obs_properties_add_path(
+            group,
+            SETTINGS_TEXT_EXPORT_DIRECTORY,
+            Text::PropertyExportDirectory.resolve(lang),
+            OBS_PATH_DIRECTORY,
+            std::ptr::null(),
+            std::ptr::null(),
+        );
+        obs_properties_add_int(
+            group,
+            SETTINGS_TEXT_EXPORT_INTERVAL,
+            Text::PropertyUpdateInterval.resolve(lang),
+            0,
+            10000,
+            50,
+        );
+        // Clearing a file name stops the value from being exported.
+        for value in TimerValue::ALL {
+            obs_properties_add_text(
+                group,
+                file_setting_key(value).as_ptr(),
+                value.description().resolve(lang),
+                OBS_TEXT_DEFAULT,
+            );
+        }
+        obs_properties_add_text(
+            group,
+            SETTINGS_TEXT_EXPORT_VARIABLE_FILE,
+            Text::PropertyCustomVariableFiles.resolve(lang),
+            OBS_TEXT_DEFAULT,
+        );
+        obs_properties_add_group(
+            props,
+            SETTINGS_TEXT_EXPORT,
+            Text::PropertyTextExport.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_int(settings, SETTINGS_TEXT_EXPORT_INTERVAL, 100);
+        for value in TimerValue::ALL {
+            let file_name = CString::new(format!("{}.txt", value.key().to_string_lossy())).unwrap();
+            obs_data_set_default_string(
+                settings,
+                file_setting_key(value).as_ptr(),
+                file_name.as_ptr(),
+            );
+        }
+        obs_data_set_default_string(
+            settings,
+            SETTINGS_TEXT_EXPORT_VARIABLE_FILE,
+            cstr!(c"variable_{name}.txt"),
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    ffi::{c_void, CStr},
+    mem,
+    os::raw::c_char,
+    path::PathBuf,
+    ptr,
+    sync::{Arc, Mutex},
+};
+
+use livesplit_core::{
+    component::text::{Component as TextComponent, Settings as TextSettings, Text as TextValue},
+    settings::{Color, Font, Gradient, LayoutBackground},
+    Component, Layout,
+};
+
+use crate::{
+    add_loaded_splits_list,
+    ffi::{
+        gs_draw_sprite, gs_effect_get_param_by_name, gs_effect_get_technique,
+        gs_effect_set_texture, gs_effect_t, gs_technique_begin, gs_technique_begin_pass,
+        gs_technique_end, gs_technique_end_pass, gs_texture_create, gs_texture_destroy,
+        gs_texture_set_image, gs_texture_t, obs_data_get_int, obs_data_get_string,
+        obs_data_set_default_int, obs_data_set_default_string, obs_data_t, obs_enter_graphics,
+        obs_get_base_effect, obs_leave_graphics, obs_properties_add_color_alpha,
+        obs_properties_add_font, obs_properties_add_int, obs_properties_add_list,
+        obs_properties_create, obs_properties_t, obs_property_list_add_string,
+        obs_register_source_s, obs_source_info, obs_source_t, GS_DYNAMIC, GS_RGBA,
+        OBS_COMBO_FORMAT_STRING, OBS_COMBO_TYPE_LIST, OBS_EFFECT_PREMULTIPLIED_ALPHA,
+        OBS_ICON_TYPE_TEXT, OBS_SOURCE_CUSTOM_DRAW, OBS_SOURCE_TYPE_INPUT, OBS_SOURCE_VIDEO,
+    },
+    find_global_timer,
+    frame::FrameRenderer,
+    layout_overrides::{parse_color, parse_font},
+    localization::{lang, Text},
+    timer_values::TimerValue,
+    GlobalTimer, UnsafeMultiThread,
+};
+
+const SETTINGS_WIDTH: *const c_char = cstr!(c"width");
+const SETTINGS_HEIGHT: *const c_char = cstr!(c"height");
+const SETTINGS_SPLITS_PATH: *const c_char = cstr!(c"splits_path");
+const SETTINGS_VALUE: *const c_char = cstr!(c"value");
+const SETTINGS_FONT: *const c_char = cstr!(c"font");
+const SETTINGS_TEXT_COLOR: *const c_char = cstr!(c"text_color");
+const SETTINGS_BACKGROUND_COLOR: *const c_char = cstr!(c"background_color");
+
+/// A source that shows a single value of a timer that is shared with the
+/// layout sources using the same splits. Each value can be positioned
+/// independently this way, without building a layout for each of them. The
+/// source never loads splits on its own, it only shows the timers of the
+/// layout sources.
+struct State {
+    splits_path: PathBuf,
+    global_timer: Option<Arc<GlobalTimer>>,
+    value: TimerValue,
+    text: String,
+    layout: Layout,
+    frame_renderer: FrameRenderer,
+    texture: *mut gs_texture_t,
+    width: u32,
+    height: u32,
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        unsafe {
+            obs_enter_graphics();
+            gs_texture_destroy(self.texture);
+            obs_leave_graphics();
+        }
+    }
+}
+
+struct Settings {
+    splits_path: PathBuf,
+    value: TimerValue,
+    font: Option<Font>,
+    text_color: Color,
+    background: Color,
+    width: u32,
+    height: u32,
+}
+
+unsafe fn parse_settings(settings: *mut obs_data_t) -> Settings {
+    unsafe {
+        let splits_path =
+            CStr::from_ptr(obs_data_get_string(settings, SETTINGS_SPLITS_PATH).cast());
+        let splits_path = PathBuf::from(splits_path.to_string_lossy().into_owned());
+
+        let value = TimerValue::from_key(CStr::from_ptr(
+            obs_data_get_string(settings, SETTINGS_VALUE).cast(),
+        ))
+        .unwrap_or(TimerValue::CurrentTime);
+
+        Settings {
+            splits_path,
+            value,
+            font: parse_font(settings, SETTINGS_FONT),
+            text_color: parse_color(obs_data_get_int(settings, SETTINGS_TEXT_COLOR)),
+            background: parse_color(obs_data_get_int(settings, SETTINGS_BACKGROUND_COLOR)),
+            width: obs_data_get_int(settings, SETTINGS_WIDTH) as u32,
+            height: obs_data_get_int(settings, SETTINGS_HEIGHT) as u32,
+        }
+    }
+}
+
+impl State {
+    unsafe fn new(
+        Settings {
+            splits_path,
+            value,
+            font,
+            text_color,
+            background,
+            width,
+            height,
+        }: Settings,
+    ) -> Self {
+        unsafe {
+            obs_enter_graphics();
+            let texture = gs_texture_create(width, height, GS_RGBA, 1, ptr::null_mut(), GS_DYNAMIC);
+            obs_leave_graphics();
+
+            // The layout consists of a single text component, whose text is
+            // replaced whenever the value changes.
+            let mut layout = Layout::new();
+            layout.push(TextComponent::with_settings(TextSettings::default()));
+
+            let mut state = Self {
+                global_timer: find_global_timer(&splits_path),
+                splits_path,
+                value,
+                text: String::new(),
+                layout,
+                frame_renderer: FrameRenderer::new(),
+                texture,
+                width,
+                height,
+            };
+            state.set_style(font, text_color, background);
+            state
+        }
+    }
+
+    fn set_style(&mut self, font: Option<Font>, text_color: Color, background: Color) {
+        let settings = self.layout.general_settings_mut();
+        settings.background = LayoutBackground::Gradient(Gradient::Plain(background));
+        settings.text_color = text_color;
+        settings.text_font = font;
+    }
+
+    fn set_text(&mut self, text: String) {
+        if let Some(Component::Text(component)) = self.layout.components.first_mut() {
+            component.settings_mut().text = TextValue::Center(text.clone());
+        }
+        self.text = text;
+    }
+
+    /// Returns whether there is anything to draw.
+    unsafe fn render(&mut self) -> bool {
+        unsafe {
+            // The layout source showing the splits may be created after this
+            // source, so the timer is looked for until it is found.
+            if self.global_timer.is_none() {
+                self.global_timer = find_global_timer(&self.splits_path);
+            }
+            let Some(global_timer) = self.global_timer.clone() else {
+                return false;
+            };
+            let timer = global_timer.timer.get_timer();
+
+            let text = self.value.format(&timer);
+            if text != self.text {
+                self.set_text(text);
+            }
+
+            let image_data = self.frame_renderer.render(
+                &mut self.layout,
+                &timer,
+                lang(),
+                false,
+                None,
+                [self.width, self.height],
+            );
+
+            gs_texture_set_image(self.texture, image_data.as_ptr(), self.width * 4, false);
+
+            true
+        }
+    }
+}
+
+unsafe extern "C" fn get_name(_: *mut c_void) -> *const c_char {
+    Text::TextSourceName.resolve(lang())
+}
+
+unsafe extern "C" fn create(settings: *mut obs_data_t, _: *mut obs_source_t) -> *mut c_void {
+    unsafe { Box::into_raw(Box::new(Mutex::new(State::new(parse_settings(settings))))).cast() }
+}
+
+unsafe extern "C" fn destroy(data: *mut c_void) {
+    unsafe {
+        drop(Box::<Mutex<State>>::from_raw(data.cast()));
+    }
+}
+
+unsafe extern "C" fn get_width(data: *mut c_void) -> u32 {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        state.width
+    }
+}
+
+unsafe extern "C" fn get_height(data: *mut c_void) -> u32 {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        state.height
+    }
+}
+
+unsafe extern "C" fn video_render(data: *mut c_void, _: *mut gs_effect_t) {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        if !state.render() {
+            return;
+        }
+
+        let effect = obs_get_base_effect(OBS_EFFECT_PREMULTIPLIED_ALPHA);
+        let tech = gs_effect_get_technique(effect, cstr!(c"Draw"));
+
+        gs_technique_begin(tech);
+        gs_technique_begin_pass(tech, 0);
+
+        gs_effect_set_texture(
+            gs_effect_get_param_by_name(effect, cstr!(c"image")),
+            state.texture,
+        );
+        gs_draw_sprite(state.texture, 0, 0, 0);
+
+        gs_technique_end_pass(tech);
+        gs_technique_end(tech);
+    }
+}
+
+unsafe extern "C" fn get_properties(_: *mut c_void) -> *mut obs_properties_t {
+    unsafe {
+        let lang = lang();
+
+        let props = obs_properties_create();
+        obs_properties_add_int(
+            props,
+            SETTINGS_WIDTH,
+            Text::PropertyWidth.resolve(lang),
+            10,
+            8200,
+            10,
+        );
+        obs_properties_add_int(
+            props,
+            SETTINGS_HEIGHT,
+            Text::PropertyHeight.resolve(lang),
+            10,
+            8200,
+            10,
+        );
+
+        add_loaded_splits_list(props, SETTINGS_SPLITS_PATH, OBS_COMBO_TYPE_LIST);
+
+        let value = obs_properties_add_list(
+            props,
+            SETTINGS_VALUE,
+            Text::PropertyTimerValue.resolve(lang),
+            OBS_COMBO_TYPE_LIST,
+            OBS_COMBO_FORMAT_STRING,
+        );
+        for timer_value in TimerValue::ALL {
+            obs_property_list_add_string(
+                value,
+                timer_value.description().resolve(lang),
+                timer_value.key().as_ptr(),
+            );
+        }
+
+        obs_properties_add_font(props, SETTINGS_FONT, Text::PropertyFont.resolve(lang));
+        obs_properties_add_color_alpha(
+            props,
+            SETTINGS_TEXT_COLOR,
+            Text::PropertyTextColor.resolve(lang),
+        );
+        obs_properties_add_color_alpha(
+            props,
+            SETTINGS_BACKGROUND_COLOR,
+            Text::PropertyBackgroundColor.resolve(lang),
+        );
+
+        props
+    }
+}
+
+unsafe extern "C" fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_int(settings, SETTINGS_WIDTH, 300);
+        obs_data_set_default_int(settings, SETTINGS_HEIGHT, 60);
+        obs_data_set_default_string(
+            settings,
+            SETTINGS_VALUE,
+            TimerValue::CurrentTime.key().as_ptr(),
+        );
+        obs_data_set_default_int(settings, SETTINGS_TEXT_COLOR, 0xFFFFFFFF);
+        obs_data_set_default_int(settings, SETTINGS_BACKGROUND_COLOR, 0);
+    }
+}
+
+unsafe extern "C" fn update(data: *mut c_void, settings: *mut obs_data_t) {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+        let settings = parse_settings(settings);
+
+        if state.splits_path != settings.splits_path {
+            state.global_timer = find_global_timer(&settings.splits_path);
+            state.splits_path = settings.splits_path;
+        }
+
+        state.value = settings.value;
+        state.set_style(settings.font, settings.text_color, settings.background);
+
+        if state.width != settings.width || state.height != settings.height {
+            state.width = settings.width;
+            state.height = settings.height;
+
+            obs_enter_graphics();
+            let mut texture = gs_texture_create(
+                state.width,
+                state.height,
+                GS_RGBA,
+                1,
+                ptr::null_mut(),
+                GS_DYNAMIC,
+            );
+            mem::swap(&mut state.texture, &mut texture);
+            gs_texture_destroy(texture);
+            obs_leave_graphics();
+        }
+    }
+}
+
+pub fn register() {
+    static SOURCE_INFO: UnsafeMultiThread<obs_source_info> = UnsafeMultiThread(obs_source_info {
+        id: cstr!(c"livesplit-one-text"),
+        type_: OBS_SOURCE_TYPE_INPUT,
+        output_flags: OBS_SOURCE_VIDEO | OBS_SOURCE_CUSTOM_DRAW,
+        get_name: Some(get_name),
+        create: Some(create),
+        destroy: Some(destroy),
+        get_width: Some(get_width),
+        get_height: Some(get_height),
+        video_render: Some(video_render),
+        mouse_wheel: None,
+        get_properties: Some(get_properties),
+        get_defaults: Some(get_defaults),
+        update: Some(update),
+        icon_type: OBS_ICON_TYPE_TEXT,
+        activate: None,
+        deactivate: None,
+        show: None,
+        hide: None,
+        video_tick: None,
+        filter_video: None,
+        filter_audio: None,
+        enum_active_sources: None,
+        save: None,
+        load: None,
+        mouse_click: None,
+        mouse_move: None,
+        focus: None,
+        key_click: None,
+        filter_remove: None,
+        type_data: ptr::null_mut(),
+        free_type_data: None,
+        audio_render: None,
+        enum_all_sources: None,
+        transition_start: None,
+        transition_stop: None,
+        get_defaults2: None,
+        get_properties2: None,
+        audio_mix: None,
+        media_play_pause: None,
+        media_restart: None,
+        media_stop: None,
+        media_next: None,
+        media_previous: None,
+        media_get_duration: None,
+        media_get_time: None,
+        media_set_time: None,
+        media_get_state: None,
+        version: 0,
+        unversioned_id: ptr::null(),
+    });
+
+    let source_info: &obs_source_info = &SOURCE_INFO.0;
+
+    unsafe {
+        obs_register_source_s(source_info, mem::size_of_val(source_info) as _);
+    }
+}
@@ -0,0 +1,483 @@
+use std::{
+    ffi::{c_void, CStr},
+    mem,
+    os::raw::c_char,
+    path::PathBuf,
+    ptr,
+    sync::{mpsc::Receiver, Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use livesplit_core::{event::Event, settings::Color};
+
+use crate::{
+    add_loaded_splits_list,
+    ffi::{
+        gs_effect_create, gs_effect_destroy, gs_effect_get_param_by_name, gs_effect_set_vec4,
+        gs_effect_t, gs_eparam_t, obs_data_get_bool, obs_data_get_int, obs_data_get_string,
+        obs_data_set_default_int, obs_data_t, obs_enter_graphics, obs_leave_graphics,
+        obs_properties_add_color_alpha, obs_properties_add_group, obs_properties_add_int,
+        obs_properties_create, obs_properties_t, obs_register_source_s, obs_source_info,
+        obs_source_process_filter_begin, obs_source_process_filter_end,
+        obs_source_skip_video_filter, obs_source_t, vec4, GS_RGBA, OBS_ALLOW_DIRECT_RENDERING,
+        OBS_COMBO_TYPE_EDITABLE, OBS_GROUP_CHECKABLE, OBS_ICON_TYPE_UNKNOWN,
+        OBS_SOURCE_TYPE_FILTER, OBS_SOURCE_VIDEO,
+    },
+    get_global_timer, layout_overrides,
+    localization::{lang, Text},
+    timer_events::TimerEvent,
+    GlobalTimer, UnsafeMultiThread,
+};
+
+const SETTINGS_SPLITS_PATH: *const c_char = cstr!(c"splits_path");
+const SETTINGS_IDLE_COLOR: *const c_char = cstr!(c"idle_color");
+
+// Multiplies the filtered source with a color. The alpha of the color is
+// used as the opacity of the source.
+const EFFECT: &CStr = c"
+uniform float4x4 ViewProj;
+uniform texture2d image;
+uniform float4 color;
+
+sampler_state def_sampler {
+    Filter   = Linear;
+    AddressU = Clamp;
+    AddressV = Clamp;
+};
+
+struct VertInOut {
+    float4 pos : POSITION;
+    float2 uv  : TEXCOORD0;
+};
+
+VertInOut VSDefault(VertInOut vert_in)
+{
+    VertInOut vert_out;
+    vert_out.pos = mul(float4(vert_in.pos.xyz, 1.0), ViewProj);
+    vert_out.uv  = vert_in.uv;
+    return vert_out;
+}
+
+float4 PSColor(VertInOut vert_in) : TARGET
+{
+    return image.Sample(def_sampler, vert_in.uv) * color;
+}
+
+technique Draw
+{
+    pass
+    {
+        vertex_shader = VSDefault(vert_in);
+        pixel_shader  = PSColor(vert_in);
+    }
+}
+";
+
+/// The timer events that the filter can react to.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Trigger {
+    Start,
+    Ahead,
+    Behind,
+    Gold,
+    PersonalBest,
+    Reset,
+}
+
+impl Trigger {
+    const ALL: [Self; 6] = [
+        Self::Start,
+        Self::Ahead,
+        Self::Behind,
+        Self::Gold,
+        Self::PersonalBest,
+        Self::Reset,
+    ];
+
+    // The settings keys for whether the trigger is enabled, its color and its
+    // duration.
+    fn keys(self) -> [*const c_char; 3] {
+        match self {
+            Self::Start => [
+                cstr!(c"on_start"),
+                cstr!(c"on_start_color"),
+                cstr!(c"on_start_duration"),
+            ],
+            Self::Ahead => [
+                cstr!(c"on_ahead"),
+                cstr!(c"on_ahead_color"),
+                cstr!(c"on_ahead_duration"),
+            ],
+            Self::Behind => [
+                cstr!(c"on_behind"),
+                cstr!(c"on_behind_color"),
+                cstr!(c"on_behind_duration"),
+            ],
+            Self::Gold => [
+                cstr!(c"on_gold"),
+                cstr!(c"on_gold_color"),
+                cstr!(c"on_gold_duration"),
+            ],
+            Self::PersonalBest => [
+                cstr!(c"on_personal_best"),
+                cstr!(c"on_personal_best_color"),
+                cstr!(c"on_personal_best_duration"),
+            ],
+            Self::Reset => [
+                cstr!(c"on_reset"),
+                cstr!(c"on_reset_color"),
+                cstr!(c"on_reset_duration"),
+            ],
+        }
+    }
+
+    fn description(self) -> Text {
+        match self {
+            Self::Start => Text::TriggerStart,
+            Self::Ahead => Text::TriggerAhead,
+            Self::Behind => Text::TriggerBehind,
+            Self::Gold => Text::TriggerGold,
+            Self::PersonalBest => Text::TriggerPersonalBest,
+            Self::Reset => Text::TriggerReset,
+        }
+    }
+
+    fn default_color(self) -> u32 {
+        match self {
+            Self::Start => 0xFFFFFFFF,
+            Self::Ahead => 0xFF7FFF7F,
+            Self::Behind => 0xFF7F7FFF,
+            Self::Gold => 0xFF3FD7FF,
+            Self::PersonalBest => 0xFFFF7FFF,
+            Self::Reset => 0x00FFFFFF,
+        }
+    }
+
+    /// Whether the trigger applies to the event.
+    fn matches(self, event: &TimerEvent) -> bool {
+        match self {
+            Self::Start => matches!(event.event, Event::Started),
+            Self::Reset => matches!(event.event, Event::Reset),
+            Self::PersonalBest => event.split.as_ref().is_some_and(|s| s.is_personal_best),
+            Self::Gold => event.split.as_ref().is_some_and(|s| s.is_gold),
+            Self::Behind => event
+                .split
+                .as_ref()
+                .is_some_and(|s| s.delta.is_some() && s.is_behind),
+            Self::Ahead => event
+                .split
+                .as_ref()
+                .is_some_and(|s| s.delta.is_some() && !s.is_behind),
+        }
+    }
+}
+
+struct Reaction {
+    trigger: Trigger,
+    color: [f32; 4],
+    duration: Option<Duration>,
+}
+
+/// A filter that changes the color and opacity of the filtered source in
+/// reaction to the events of a timer. Hiding the source is done by setting
+/// the opacity to zero.
+struct State {
+    filter: *mut obs_source_t,
+    global_timer: Arc<GlobalTimer>,
+    events: Receiver<Arc<TimerEvent>>,
+    idle_color: [f32; 4],
+    reactions: Vec<Reaction>,
+    active: Option<([f32; 4], Option<Instant>)>,
+    effect: *mut gs_effect_t,
+    color_param: *mut gs_eparam_t,
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        unsafe {
+            obs_enter_graphics();
+            gs_effect_destroy(self.effect);
+            obs_leave_graphics();
+        }
+    }
+}
+
+struct Settings {
+    splits_path: PathBuf,
+    idle_color: [f32; 4],
+    reactions: Vec<Reaction>,
+}
+
+fn parse_color(value: i64) -> [f32; 4] {
+    let Color {
+        red,
+        green,
+        blue,
+        alpha,
+    } = layout_overrides::parse_color(value);
+    [red, green, blue, alpha]
+}
+
+unsafe fn parse_settings(settings: *mut obs_data_t) -> Settings {
+    unsafe {
+        let splits_path =
+            CStr::from_ptr(obs_data_get_string(settings, SETTINGS_SPLITS_PATH).cast());
+        let splits_path = PathBuf::from(splits_path.to_string_lossy().into_owned());
+
+        // A split may apply to multiple triggers, in which case the more
+        // notable one wins, so the reactions are ordered from most to least
+        // notable.
+        let reactions = Trigger::ALL
+            .into_iter()
+            .rev()
+            .filter_map(|trigger| {
+                let [enabled, color, duration] = trigger.keys();
+                if !obs_data_get_bool(settings, enabled) {
+                    return None;
+                }
+                let duration = obs_data_get_int(settings, duration).max(0) as u64;
+                Some(Reaction {
+                    trigger,
+                    color: parse_color(obs_data_get_int(settings, color)),
+                    duration: (duration != 0).then(|| Duration::from_secs(duration)),
+                })
+            })
+            .collect();
+
+        Settings {
+            splits_path,
+            idle_color: parse_color(obs_data_get_int(settings, SETTINGS_IDLE_COLOR)),
+            reactions,
+        }
+    }
+}
+
+impl State {
+    fn handle_event(&mut self, event: &TimerEvent) {
+        if let Some(reaction) = self
+            .reactions
+            .iter()
+            .find(|reaction| reaction.trigger.matches(event))
+        {
+            self.active = Some((
+                reaction.color,
+                reaction.duration.map(|duration| Instant::now() + duration),
+            ));
+        }
+    }
+
+    fn current_color(&mut self) -> [f32; 4] {
+        if let Some((color, until)) = self.active {
+            if until.is_none_or(|until| Instant::now() < until) {
+                return color;
+            }
+            self.active = None;
+        }
+        self.idle_color
+    }
+}
+
+unsafe extern "C" fn get_name(_: *mut c_void) -> *const c_char {
+    Text::TimerEventFilterName.resolve(lang())
+}
+
+unsafe extern "C" fn create(settings: *mut obs_data_t, filter: *mut obs_source_t) -> *mut c_void {
+    unsafe {
+        let Settings {
+            splits_path,
+            idle_color,
+            reactions,
+        } = parse_settings(settings);
+
+        obs_enter_graphics();
+        let effect = gs_effect_create(EFFECT.as_ptr(), ptr::null(), ptr::null_mut());
+        let color_param = if effect.is_null() {
+            ptr::null_mut()
+        } else {
+            gs_effect_get_param_by_name(effect, cstr!(c"color"))
+        };
+        obs_leave_graphics();
+
+        let global_timer = get_global_timer(splits_path);
+        let events = global_timer.timer.events.subscribe();
+
+        Box::into_raw(Box::new(Mutex::new(State {
+            filter,
+            global_timer,
+            events,
+            idle_color,
+            reactions,
+            active: None,
+            effect,
+            color_param,
+        })))
+        .cast()
+    }
+}
+
+unsafe extern "C" fn destroy(data: *mut c_void) {
+    unsafe {
+        drop(Box::<Mutex<State>>::from_raw(data.cast()));
+    }
+}
+
+unsafe extern "C" fn video_tick(data: *mut c_void, _: f32) {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        while let Ok(event) = state.events.try_recv() {
+            state.handle_event(&event);
+        }
+    }
+}
+
+unsafe extern "C" fn video_render(data: *mut c_void, _: *mut gs_effect_t) {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+        let color = state.current_color();
+
+        if color == [1.0; 4] || state.effect.is_null() {
+            obs_source_skip_video_filter(state.filter);
+            return;
+        }
+
+        // Fully transparent sources don't need to be drawn at all.
+        if color[3] == 0.0 {
+            return;
+        }
+
+        if !obs_source_process_filter_begin(state.filter, GS_RGBA, OBS_ALLOW_DIRECT_RENDERING) {
+            return;
+        }
+
+        let [x, y, z, w] = color;
+        gs_effect_set_vec4(state.color_param, &vec4 { x, y, z, w });
+
+        obs_source_process_filter_end(state.filter, state.effect, 0, 0);
+    }
+}
+
+unsafe extern "C" fn get_properties(_: *mut c_void) -> *mut obs_properties_t {
+    unsafe {
+        let lang = lang();
+
+        let props = obs_properties_create();
+        add_loaded_splits_list(props, SETTINGS_SPLITS_PATH, OBS_COMBO_TYPE_EDITABLE);
+        obs_properties_add_color_alpha(
+            props,
+            SETTINGS_IDLE_COLOR,
+            Text::PropertyIdleColor.resolve(lang),
+        );
+
+        for trigger in Trigger::ALL {
+            let [enabled, color, duration] = trigger.keys();
+
+            let group = obs_properties_create();
+            obs_properties_add_color_alpha(group, color, Text::PropertyColor.resolve(lang));
+            obs_properties_add_int(
+                group,
+                duration,
+                Text::PropertyDuration.resolve(lang),
+                0,
+                3600,
+                1,
+            );
+            obs_properties_add_group(
+                props,
+                enabled,
+                trigger.description().resolve(lang),
+                OBS_GROUP_CHECKABLE,
+                group,
+            );
+        }
+
+        props
+    }
+}
+
+unsafe extern "C" fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_int(settings, SETTINGS_IDLE_COLOR, 0xFFFFFFFF);
+        for trigger in Trigger::ALL {
+            let [_, color, duration] = trigger.keys();
+            obs_data_set_default_int(settings, color, trigger.default_color() as _);
+            obs_data_set_default_int(settings, duration, 3);
+        }
+    }
+}
+
+unsafe extern "C" fn update(data: *mut c_void, settings: *mut obs_data_t) {
+    unsafe {
+        let state: &mut State = &mut (*data.cast::<Mutex<State>>()).lock().unwrap();
+
+        let settings = parse_settings(settings);
+
+        if state.global_timer.timer.path != settings.splits_path {
+            state.global_timer = get_global_timer(settings.splits_path);
+            state.events = state.global_timer.timer.events.subscribe();
+            state.active = None;
+        }
+
+        state.idle_color = settings.idle_color;
+        state.reactions = settings.reactions;
+    }
+}
+
+pub fn register() {
+    static SOURCE_INFO: UnsafeMultiThread<obs_source_info> = UnsafeMultiThread(obs_source_info {
+        id: cstr!(c"livesplit-one-timer-event-filter"),
+        type_: OBS_SOURCE_TYPE_FILTER,
+        output_flags: OBS_SOURCE_VIDEO,
+        get_name: Some(get_name),
+        create: Some(create),
+        destroy: Some(destroy),
+        get_width: None,
+        get_height: None,
+        video_render: Some(video_render),
+        mouse_wheel: None,
+        get_properties: Some(get_properties),
+        get_defaults: Some(get_defaults),
+        update: Some(update),
+        icon_type: OBS_ICON_TYPE_UNKNOWN,
+        activate: None,
+        deactivate: None,
+        show: None,
+        hide: None,
+        video_tick: Some(video_tick),
+        filter_video: None,
+        filter_audio: None,
+        enum_active_sources: None,
+        save: None,
+        load: None,
+        mouse_click: None,
+        mouse_move: None,
+        focus: None,
+        key_click: None,
+        filter_remove: None,
+        type_data: ptr::null_mut(),
+        free_type_data: None,
+        audio_render: None,
+        enum_all_sources: None,
+        transition_start: None,
+        transition_stop: None,
+        get_defaults2: None,
+        get_properties2: None,
+        audio_mix: None,
+        media_play_pause: None,
+        media_restart: None,
+        media_stop: None,
+        media_next: None,
+        media_previous: None,
+        media_get_duration: None,
+        media_get_time: None,
+        media_set_time: None,
+        media_get_state: None,
+        version: 0,
+        unversioned_id: ptr::null(),
+    });
+
+    let source_info: &obs_source_info = &SOURCE_INFO.0;
+
+    unsafe {
+        obs_register_source_s(source_info, mem::size_of_val(source_info) as _);
+    }
+}
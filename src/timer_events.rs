@@ -0,0 +1,125 @@
+use std::{
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    time::SystemTime,
+};
+
+use livesplit_core::{analysis::state_helper, event::Event, TimeSpan, Timer, TimerPhase};
+
+/// An event that was produced by one of the commands of a timer, along with
+/// the information about the split that caused it. The information is
+/// gathered while the timer is still locked, so it's consistent with the
+/// event.
+pub struct TimerEvent {
+    pub event: Event,
+    pub time: SystemTime,
+    pub phase: TimerPhase,
+    pub attempt: u32,
+    pub split: Option<SplitInfo>,
+}
+
+/// Information about the split that was just done. Only available for
+/// [`Event::Splitted`], [`Event::Finished`], [`Event::SplitSkipped`] and
+/// [`Event::SplitUndone`]. The latter two don't have any times.
+pub struct SplitInfo {
+    pub index: usize,
+    pub name: String,
+    pub split_time: Option<TimeSpan>,
+    pub segment_time: Option<TimeSpan>,
+    pub delta: Option<TimeSpan>,
+    /// The segment is faster than the best segment so far.
+    pub is_gold: bool,
+    /// The split is behind the current comparison.
+    pub is_behind: bool,
+    /// The run finished faster than the personal best.
+    pub is_personal_best: bool,
+}
+
+impl TimerEvent {
+    fn new(event: Event, timer: &Timer) -> Self {
+        let split = match event {
+            Event::Splitted | Event::Finished | Event::SplitSkipped => timer
+                .current_split_index()
+                .and_then(|index| index.checked_sub(1))
+                .map(|index| SplitInfo::new(timer, index, matches!(event, Event::Finished))),
+            // The undone segment is the current one again.
+            Event::SplitUndone => timer
+                .current_split_index()
+                .map(|index| SplitInfo::new(timer, index, false)),
+            _ => None,
+        };
+
+        Self {
+            event,
+            time: SystemTime::now(),
+            phase: timer.current_phase(),
+            attempt: timer.run().attempt_count(),
+            split,
+        }
+    }
+}
+
+impl SplitInfo {
+    // The best segments and the personal best only get updated once the
+    // attempt is reset, so they still refer to the previous attempts here.
+    fn new(timer: &Timer, index: usize, finished: bool) -> Self {
+        let run = timer.run();
+        let method = timer.current_timing_method();
+        let segment = run.segment(index);
+
+        let split_time = segment.split_time()[method];
+        // If the previous splits were skipped, the segment covers all of
+        // them, which the timer accounts for when comparing it against the
+        // best segments.
+        let segment_time = state_helper::previous_segment_time(timer, index, method);
+
+        let comparison = segment.comparison(timer.current_comparison())[method];
+        let delta = split_time
+            .zip(comparison)
+            .map(|(split, comparison)| split - comparison);
+
+        let is_gold = state_helper::check_best_segment(timer, index, method);
+
+        let is_personal_best = finished
+            && split_time.is_some_and(|split_time| {
+                segment.personal_best_split_time()[method].is_none_or(|pb| split_time < pb)
+            });
+
+        Self {
+            index,
+            name: segment.name().to_owned(),
+            split_time,
+            segment_time,
+            delta,
+            is_gold,
+            is_behind: delta.is_some_and(|delta| delta > TimeSpan::zero()),
+            is_personal_best,
+        }
+    }
+}
+
+/// Hands out the events of a timer to anyone that is interested in them.
+/// Subscribers that are gone get dropped the next time an event is published.
+#[derive(Default)]
+pub struct TimerEvents {
+    subscribers: Mutex<Vec<Sender<Arc<TimerEvent>>>>,
+}
+
+impl TimerEvents {
+    pub fn subscribe(&self) -> Receiver<Arc<TimerEvent>> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(sender);
+        receiver
+    }
+
+    pub fn publish(&self, event: Event, timer: &Timer) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        if subscribers.is_empty() {
+            return;
+        }
+        let event = Arc::new(TimerEvent::new(event, timer));
+        subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
+}
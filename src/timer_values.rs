@@ -0,0 +1,114 @@
+use std::ffi::CStr;
+
+use livesplit_core::{
+    analysis::sum_of_segments,
+    timing::formatter::{Delta, Regular, TimeFormatter},
+    Timer, TimerPhase,
+};
+
+use crate::localization::Text;
+
+/// The individual values of a timer that can be shown on their own, outside
+/// of a layout.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TimerValue {
+    CurrentTime,
+    CurrentSplit,
+    Delta,
+    Comparison,
+    PersonalBest,
+    SumOfBest,
+    Attempts,
+}
+
+impl TimerValue {
+    pub const ALL: [Self; 7] = [
+        Self::CurrentTime,
+        Self::CurrentSplit,
+        Self::Delta,
+        Self::Comparison,
+        Self::PersonalBest,
+        Self::SumOfBest,
+        Self::Attempts,
+    ];
+
+    /// The key that is used for the setting and the file name.
+    pub fn key(self) -> &'static CStr {
+        match self {
+            Self::CurrentTime => c"current_time",
+            Self::CurrentSplit => c"current_split",
+            Self::Delta => c"delta",
+            Self::Comparison => c"comparison",
+            Self::PersonalBest => c"personal_best",
+            Self::SumOfBest => c"sum_of_best",
+            Self::Attempts => c"attempts",
+        }
+    }
+
+    pub fn from_key(key: &CStr) -> Option<Self> {
+        Self::ALL.into_iter().find(|value| value.key() == key)
+    }
+
+    pub fn description(self) -> Text {
+        match self {
+            Self::CurrentTime => Text::TimerValueCurrentTime,
+            Self::CurrentSplit => Text::TimerValueCurrentSplit,
+            Self::Delta => Text::TimerValueDelta,
+            Self::Comparison => Text::TimerValueComparison,
+            Self::PersonalBest => Text::TimerValuePersonalBest,
+            Self::SumOfBest => Text::TimerValueSumOfBest,
+            Self::Attempts => Text::TimerValueAttempts,
+        }
+    }
+
+    pub fn format(self, timer: &Timer) -> String {
+        let run = timer.run();
+        let method = timer.current_timing_method();
+
+        match self {
+            Self::CurrentTime => Regular::new()
+                .format(timer.snapshot().current_time()[method])
+                .to_string(),
+            Self::CurrentSplit => timer
+                .current_split()
+                .map(|segment| segment.name().to_owned())
+                .unwrap_or_default(),
+            Self::Delta => {
+                // The delta of the most recent split, as the live delta is
+                // only shown once the runner is behind.
+                let split_count = match timer.current_phase() {
+                    TimerPhase::NotRunning => 0,
+                    _ => timer.current_split_index().unwrap_or(run.len()),
+                };
+                let comparison = timer.current_comparison();
+                let delta = run.segments()[..split_count]
+                    .iter()
+                    .rev()
+                    .find_map(|segment| {
+                        let split_time = segment.split_time()[method]?;
+                        Some(split_time - segment.comparison(comparison)[method]?)
+                    });
+                Delta::with_decimal_dropping().format(delta).to_string()
+            }
+            Self::Comparison => timer.current_comparison().to_owned(),
+            Self::PersonalBest => Regular::new()
+                .format(
+                    run.segments()
+                        .last()
+                        .and_then(|segment| segment.personal_best_split_time()[method]),
+                )
+                .to_string(),
+            // The same as the Sum of Best component, which also considers
+            // the history of segments that were skipped.
+            Self::SumOfBest => Regular::new()
+                .format(sum_of_segments::calculate_best(
+                    run.segments(),
+                    false,
+                    true,
+                    method,
+                ))
+                .to_string(),
+            Self::Attempts => run.attempt_count().to_string(),
+        }
+    }
+}
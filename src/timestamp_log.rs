@@ -0,0 +1,319 @@
+use std::{
+    ffi::CStr,
+    fmt::Write,
+    fs,
+    os::raw::c_char,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use livesplit_core::{event::Event, TimeSpan};
+use log::warn;
+use serde_derive::Serialize;
+
+use crate::{
+    event_worker::EventHandler,
+    ffi::{
+        obs_data_get_bool, obs_data_get_string, obs_data_set_default_string, obs_data_t,
+        obs_properties_add_group, obs_properties_add_list, obs_properties_add_path,
+        obs_properties_create, obs_properties_t, obs_property_list_add_string,
+        OBS_COMBO_FORMAT_STRING, OBS_COMBO_TYPE_LIST, OBS_GROUP_CHECKABLE, OBS_PATH_DIRECTORY,
+    },
+    localization::{lang, Text},
+    timer_events::TimerEvent,
+    InnerTimer,
+};
+
+const SETTINGS_TIMESTAMP_LOG: *const c_char = cstr!(c"timestamp_log");
+const SETTINGS_TIMESTAMP_LOG_DIRECTORY: *const c_char = cstr!(c"timestamp_log_directory");
+const SETTINGS_TIMESTAMP_LOG_FORMAT: *const c_char = cstr!(c"timestamp_log_format");
+
+/// Logs when each start, split, undo, skip, pause and reset of an attempt
+/// happened, so the splits can be found in the recording or VOD afterwards.
+/// Every attempt gets its own file.
+pub struct TimestampLog {
+    options: TimestampLogOptions,
+    attempt: Option<Attempt>,
+}
+
+#[derive(Clone)]
+pub struct TimestampLogOptions {
+    directory: PathBuf,
+    format: Format,
+}
+
+#[derive(Copy, Clone)]
+enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Entry {
+    event: &'static str,
+    /// The wall-clock time in UTC.
+    timestamp: String,
+    /// The seconds since the attempt started.
+    offset: f64,
+    segment_index: Option<usize>,
+    segment_name: Option<String>,
+    split_time: Option<f64>,
+    segment_time: Option<f64>,
+    delta: Option<f64>,
+}
+
+/// The entries of the attempt that is currently in progress.
+struct Attempt {
+    number: u32,
+    start: SystemTime,
+    entries: Vec<Entry>,
+}
+
+impl EventHandler for TimestampLog {
+    type Options = TimestampLogOptions;
+
+    fn new(options: TimestampLogOptions, _: &Arc<InnerTimer>) -> Self {
+        Self {
+            options,
+            attempt: None,
+        }
+    }
+
+    fn set_options(&mut self, options: TimestampLogOptions) {
+        self.options = options;
+    }
+
+    fn handle_event(&mut self, event: &TimerEvent) {
+        let name = match event.event {
+            Event::Started => {
+                self.attempt = Some(Attempt {
+                    number: event.attempt,
+                    start: event.time,
+                    entries: Vec::new(),
+                });
+                "start"
+            }
+            Event::Splitted => "split",
+            Event::Finished => "finish",
+            Event::SplitUndone => "undo",
+            Event::SplitSkipped => "skip",
+            Event::Paused => "pause",
+            Event::Resumed => "resume",
+            Event::Reset => "reset",
+            _ => return,
+        };
+
+        // Events that happen before the first start can't be associated with
+        // any attempt.
+        let Some(current) = &mut self.attempt else {
+            return;
+        };
+
+        let split = event.split.as_ref();
+        current.entries.push(Entry {
+            event: name,
+            timestamp: format_timestamp(event.time),
+            offset: event
+                .time
+                .duration_since(current.start)
+                .unwrap_or_default()
+                .as_secs_f64(),
+            segment_index: split.map(|split| split.index),
+            segment_name: split.map(|split| split.name.clone()),
+            split_time: split.and_then(|split| seconds(split.split_time)),
+            segment_time: split.and_then(|split| seconds(split.segment_time)),
+            delta: split.and_then(|split| seconds(split.delta)),
+        });
+
+        // The file gets rewritten on every event, so nothing is lost if OBS
+        // closes in the middle of an attempt.
+        current.save(&self.options.directory, self.options.format);
+
+        if matches!(event.event, Event::Reset) {
+            self.attempt = None;
+        }
+    }
+}
+
+fn seconds(time: Option<TimeSpan>) -> Option<f64> {
+    time.map(|time| time.total_seconds())
+}
+
+impl Attempt {
+    fn save(&self, directory: &Path, format: Format) {
+        let (extension, contents) = match format {
+            Format::Csv => ("csv", self.to_csv()),
+            Format::Json => (
+                "json",
+                serde_json::to_string_pretty(&self.entries).unwrap_or_default(),
+            ),
+        };
+
+        let path = directory.join(format!("attempt_{}.{extension}", self.number));
+        if let Err(e) = fs::create_dir_all(directory).and_then(|_| fs::write(&path, contents)) {
+            warn!("Couldn't write the timestamps to `{}`: {e}", path.display());
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "event,timestamp,offset,segment_index,segment_name,split_time,segment_time,delta\n",
+        );
+        for entry in &self.entries {
+            let _ = writeln!(
+                csv,
+                "{},{},{:.3},{},{},{},{},{}",
+                entry.event,
+                entry.timestamp,
+                entry.offset,
+                optional(entry.segment_index),
+                entry
+                    .segment_name
+                    .as_deref()
+                    .map(escape_csv)
+                    .unwrap_or_default(),
+                optional(entry.split_time.map(|t| format!("{t:.3}"))),
+                optional(entry.segment_time.map(|t| format!("{t:.3}"))),
+                optional(entry.delta.map(|t| format!("{t:.3}"))),
+            );
+        }
+        csv
+    }
+}
+
+fn optional(value: Option<impl ToString>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Formats the time as an RFC 3339 timestamp in UTC with milliseconds.
+fn format_timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = ((secs / 86400) as i64, secs % 86400);
+
+    // Converts the days since the epoch to a date in the proleptic Gregorian
+    // calendar. See http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis(),
+    )
+}
+
+impl TimestampLogOptions {
+    pub unsafe fn parse(settings: *mut obs_data_t) -> Option<Self> {
+        unsafe {
+            if !obs_data_get_bool(settings, SETTINGS_TIMESTAMP_LOG) {
+                return None;
+            }
+
+            let directory = CStr::from_ptr(
+                obs_data_get_string(settings, SETTINGS_TIMESTAMP_LOG_DIRECTORY).cast(),
+            );
+            if directory.is_empty() {
+                return None;
+            }
+
+            let format =
+                CStr::from_ptr(obs_data_get_string(settings, SETTINGS_TIMESTAMP_LOG_FORMAT).cast());
+            let format = if format == c"json" {
+                Format::Json
+            } else {
+                Format::Csv
+            };
+
+            Some(Self {
+                directory: PathBuf::from(directory.to_string_lossy().into_owned()),
+                format,
+            })
+        }
+    }
+}
+
+pub unsafe fn add_properties(props: *mut obs_properties_t) {
+    unsafe {
+        let lang = lang();
+
+        let group = obs_properties_create();
+        obs_properties_add_path(
+            group,
+            SETTINGS_TIMESTAMP_LOG_DIRECTORY,
+            Text::PropertyExportDirectory.resolve(lang),
+            OBS_PATH_DIRECTORY,
+            std::ptr::null(),
+            std::ptr::null(),
+        );
+        let format = obs_properties_add_list(
+            group,
+            SETTINGS_TIMESTAMP_LOG_FORMAT,
+            Text::PropertyFileFormat.resolve(lang),
+            OBS_COMBO_TYPE_LIST,
+            OBS_COMBO_FORMAT_STRING,
+        );
+        obs_property_list_add_string(format, cstr!(c"CSV"), cstr!(c"csv"));
+        obs_property_list_add_string(format, cstr!(c"JSON"), cstr!(c"json"));
+        obs_properties_add_group(
+            props,
+            SETTINGS_TIMESTAMP_LOG,
+            Text::PropertyTimestampLog.resolve(lang),
+            OBS_GROUP_CHECKABLE,
+            group,
+        );
+    }
+}
+
+pub unsafe fn get_defaults(settings: *mut obs_data_t) {
+    unsafe {
+        obs_data_set_default_string(settings, SETTINGS_TIMESTAMP_LOG_FORMAT, cstr!(c"csv"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::format_timestamp;
+
+    #[test]
+    fn formats_timestamps_as_rfc_3339() {
+        assert_eq!(format_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_millis(1_700_000_000_042)),
+            "2023-11-14T22:13:20.042Z",
+        );
+    }
+
+    #[test]
+    fn formats_leap_days_and_century_years() {
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_millis(951_782_400_123)),
+            "2000-02-29T00:00:00.123Z",
+        );
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_millis(4_107_542_399_999)),
+            "2100-02-28T23:59:59.999Z",
+        );
+    }
+}
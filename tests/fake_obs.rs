@@ -0,0 +1,246 @@
+//! Drives the plugin through the fake OBS that lives in the `obs` crate. The
+//! fake is only used on platforms where we link against our own `libobs`, so
+//! run `cargo build -p obs` first and make sure the linker can find it. The
+//! frontend API is looked up at runtime, so the fake provides that as well and
+//! nothing else needs to be built.
+//!
+//! The tests run in parallel, so each of them uses its own sources and only
+//! inspects state that the fake keeps for each source.
+
+#![cfg(not(any(target_os = "macos", windows)))]
+
+use std::{
+    ffi::{c_void, CStr},
+    os::raw::{c_char, c_longlong},
+    ptr, slice,
+    sync::Once,
+};
+
+const OBS_MEDIA_STATE_PLAYING: u32 = 1;
+const OBS_MEDIA_STATE_PAUSED: u32 = 4;
+const OBS_MEDIA_STATE_STOPPED: u32 = 5;
+const OBS_MEDIA_STATE_ENDED: u32 = 6;
+
+#[link(name = "obs", kind = "dylib")]
+unsafe extern "C" {
+    fn obs_data_create() -> *mut c_void;
+    fn obs_data_release(data: *mut c_void);
+    fn obs_data_set_int(data: *mut c_void, name: *const c_char, val: c_longlong);
+    fn obs_data_set_string(data: *mut c_void, name: *const c_char, val: *const c_char);
+    fn obs_source_get_display_name(id: *const c_char) -> *const c_char;
+    fn obs_source_create(
+        id: *const c_char,
+        name: *const c_char,
+        settings: *mut c_void,
+        hotkey_data: *mut c_void,
+    ) -> *mut c_void;
+    fn obs_source_release(source: *mut c_void);
+    fn obs_source_properties(source: *mut c_void) -> *mut c_void;
+    fn obs_source_video_render(source: *mut c_void);
+    fn obs_source_media_get_state(source: *mut c_void) -> u32;
+    fn obs_source_get_width(source: *mut c_void) -> u32;
+    fn obs_source_get_height(source: *mut c_void) -> u32;
+    fn obs_source_video_tick(source: *mut c_void, seconds: f32);
+    fn obs_source_get_proc_handler(source: *mut c_void) -> *mut c_void;
+    fn proc_handler_call(handler: *mut c_void, name: *const c_char, params: *mut c_void) -> bool;
+    fn calldata_get_string(
+        data: *const c_void,
+        name: *const c_char,
+        str_: *mut *const c_char,
+    ) -> bool;
+    fn obs_properties_get(props: *mut c_void, name: *const c_char) -> *mut c_void;
+    fn obs_properties_destroy(props: *mut c_void);
+    fn gs_texture_get_width(tex: *const c_void) -> u32;
+    fn gs_texture_get_height(tex: *const c_void) -> u32;
+
+    fn fake_obs_source_set_active(source: *mut c_void, active: bool);
+    fn fake_obs_hotkey_press(source: *mut c_void, name: *const c_char) -> bool;
+    fn fake_obs_last_drawn_texture(source: *mut c_void) -> *mut c_void;
+    fn fake_obs_texture_data(tex: *const c_void, len: *mut usize) -> *const u8;
+    fn fake_obs_signal_count(source: *mut c_void, signal: *const c_char) -> usize;
+    fn fake_obs_calldata_create() -> *mut c_void;
+    fn fake_obs_calldata_free(data: *mut c_void);
+}
+
+fn load_module() {
+    static LOAD: Once = Once::new();
+    LOAD.call_once(|| {
+        assert!(obs_livesplit_one::obs_module_load());
+    });
+}
+
+struct Source(*mut c_void);
+
+impl Source {
+    // Sources that use the same splits share a timer, so every test uses its
+    // own splits path. The files don't exist, so the default run is used.
+    fn new(splits: &CStr, size: Option<[u32; 2]>) -> Self {
+        load_module();
+        unsafe {
+            let settings = obs_data_create();
+            obs_data_set_string(settings, c"splits_path".as_ptr(), splits.as_ptr());
+            if let Some([width, height]) = size {
+                obs_data_set_int(settings, c"width".as_ptr(), width.into());
+                obs_data_set_int(settings, c"height".as_ptr(), height.into());
+            }
+            let source = obs_source_create(
+                c"livesplit-one".as_ptr(),
+                c"Test".as_ptr(),
+                settings,
+                ptr::null_mut(),
+            );
+            obs_data_release(settings);
+            assert!(!source.is_null());
+            Self(source)
+        }
+    }
+
+    fn press(&self, hotkey: &CStr) {
+        unsafe { assert!(fake_obs_hotkey_press(self.0, hotkey.as_ptr())) }
+    }
+
+    fn set_active(&self, active: bool) {
+        unsafe { fake_obs_source_set_active(self.0, active) }
+    }
+
+    fn media_state(&self) -> u32 {
+        unsafe { obs_source_media_get_state(self.0) }
+    }
+
+    /// Calls the procedure and returns the string parameter of the call data
+    /// with the given name.
+    fn call(&self, procedure: &CStr, out: Option<&CStr>) -> Option<String> {
+        unsafe {
+            let calldata = fake_obs_calldata_create();
+            let handler = obs_source_get_proc_handler(self.0);
+            assert!(proc_handler_call(handler, procedure.as_ptr(), calldata));
+
+            let value = out.and_then(|name| {
+                let mut value = ptr::null();
+                calldata_get_string(calldata, name.as_ptr(), &mut value)
+                    .then(|| CStr::from_ptr(value).to_string_lossy().into_owned())
+            });
+            fake_obs_calldata_free(calldata);
+            value
+        }
+    }
+}
+
+impl Drop for Source {
+    fn drop(&mut self) {
+        unsafe { obs_source_release(self.0) }
+    }
+}
+
+#[test]
+fn registers_source() {
+    load_module();
+    let name = unsafe { CStr::from_ptr(obs_source_get_display_name(c"livesplit-one".as_ptr())) };
+    assert_eq!(name, c"LiveSplit One");
+}
+
+#[test]
+fn hotkeys_control_the_timer() {
+    let source = Source::new(c"/nonexistent/hotkeys.lss", None);
+    source.set_active(true);
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_STOPPED);
+
+    source.press(c"hotkey_split");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_PLAYING);
+
+    source.press(c"hotkey_pause");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_PAUSED);
+
+    source.press(c"hotkey_pause");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_PLAYING);
+
+    // The default run only has a single segment.
+    source.press(c"hotkey_split");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_ENDED);
+
+    source.press(c"hotkey_reset");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_STOPPED);
+}
+
+#[test]
+fn hotkeys_are_ignored_while_inactive() {
+    let source = Source::new(c"/nonexistent/inactive.lss", None);
+    source.press(c"hotkey_split");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_STOPPED);
+
+    source.set_active(true);
+    source.press(c"hotkey_split");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_PLAYING);
+
+    source.set_active(false);
+    source.press(c"hotkey_reset");
+    assert_eq!(source.media_state(), OBS_MEDIA_STATE_PLAYING);
+}
+
+#[test]
+fn exposes_properties() {
+    let source = Source::new(c"/nonexistent/properties.lss", None);
+    unsafe {
+        let props = obs_source_properties(source.0);
+        assert!(!props.is_null());
+        for name in [
+            c"splits_path",
+            c"layout_path",
+            c"width",
+            c"height",
+            c"transparent_background",
+            c"drop_shadow",
+            c"drop_shadow_blur_radius",
+        ] {
+            assert!(
+                !obs_properties_get(props, name.as_ptr()).is_null(),
+                "missing property {name:?}",
+            );
+        }
+        obs_properties_destroy(props);
+    }
+}
+
+#[test]
+fn renders_at_configured_size() {
+    let source = Source::new(c"/nonexistent/render.lss", Some([200, 120]));
+    unsafe {
+        assert_eq!(obs_source_get_width(source.0), 200);
+        assert_eq!(obs_source_get_height(source.0), 120);
+
+        obs_source_video_render(source.0);
+
+        let texture = fake_obs_last_drawn_texture(source.0);
+        assert!(!texture.is_null());
+        assert_eq!(gs_texture_get_width(texture), 200);
+        assert_eq!(gs_texture_get_height(texture), 120);
+
+        let mut len = 0;
+        let data = fake_obs_texture_data(texture, &mut len);
+        let pixels = slice::from_raw_parts(data, len);
+        assert_eq!(pixels.len(), 200 * 120 * 4);
+        assert!(pixels.chunks_exact(4).any(|pixel| pixel[3] != 0));
+    }
+}
+
+#[test]
+fn scripts_control_the_timer() {
+    let source = Source::new(c"/nonexistent/scripting.lss", None);
+    let phase = || source.call(c"get_state", Some(c"phase")).unwrap();
+    assert_eq!(phase(), "not_running");
+
+    // Unlike the hotkeys, the procedures also work while inactive.
+    source.call(c"start", None);
+    assert_eq!(phase(), "running");
+
+    source.call(c"toggle_pause", None);
+    assert_eq!(phase(), "paused");
+
+    unsafe {
+        obs_source_video_tick(source.0, 0.0);
+        assert_eq!(fake_obs_signal_count(source.0, c"timer_event".as_ptr()), 2);
+    }
+
+    source.call(c"reset", None);
+    assert_eq!(phase(), "not_running");
+}